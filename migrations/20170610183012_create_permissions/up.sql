@@ -0,0 +1,7 @@
+CREATE TABLE permissions (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    subject_kind VARCHAR(255) NOT NULL,
+    subject_id VARCHAR(255) NOT NULL,
+    command VARCHAR(255) NOT NULL
+);
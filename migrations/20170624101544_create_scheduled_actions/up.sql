@@ -0,0 +1,8 @@
+CREATE TABLE scheduled_actions (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    due_at BIGINT NOT NULL,
+    interval_ms BIGINT NOT NULL DEFAULT 0,
+    kind VARCHAR(255) NOT NULL,
+    payload TEXT NOT NULL
+);
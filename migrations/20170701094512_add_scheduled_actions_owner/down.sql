@@ -0,0 +1,11 @@
+CREATE TABLE scheduled_actions_backup (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    due_at BIGINT NOT NULL,
+    interval_ms BIGINT NOT NULL DEFAULT 0,
+    kind VARCHAR(255) NOT NULL,
+    payload TEXT NOT NULL
+);
+INSERT INTO scheduled_actions_backup SELECT id, server_id, due_at, interval_ms, kind, payload FROM scheduled_actions;
+DROP TABLE scheduled_actions;
+ALTER TABLE scheduled_actions_backup RENAME TO scheduled_actions;
@@ -0,0 +1,2 @@
+ALTER TABLE scheduled_actions ADD COLUMN owner_id VARCHAR(255) NOT NULL DEFAULT '';
+ALTER TABLE scheduled_actions ADD COLUMN label VARCHAR(255) NOT NULL DEFAULT '';
@@ -0,0 +1,7 @@
+CREATE TABLE playlists (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    creator VARCHAR(255) NOT NULL DEFAULT '',
+    effect_names TEXT NOT NULL
+);
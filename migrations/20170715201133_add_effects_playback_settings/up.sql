@@ -0,0 +1,5 @@
+ALTER TABLE effects ADD COLUMN volume_db FLOAT NOT NULL DEFAULT 0;
+ALTER TABLE effects ADD COLUMN trim_start_ms INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE effects ADD COLUMN trim_end_ms INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE effects ADD COLUMN fade_in_ms INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE effects ADD COLUMN fade_out_ms INTEGER NOT NULL DEFAULT 0;
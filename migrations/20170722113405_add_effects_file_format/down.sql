@@ -0,0 +1,19 @@
+CREATE TABLE effects_backup (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    uploader VARCHAR(255) NOT NULL,
+    peak_db FLOAT NOT NULL DEFAULT 0,
+    duration_ms INTEGER NOT NULL DEFAULT 0,
+    silent_start_samples INTEGER NOT NULL DEFAULT 0,
+    silent_end_samples INTEGER NOT NULL DEFAULT 0,
+    transcript TEXT NOT NULL,
+    volume_db FLOAT NOT NULL DEFAULT 0,
+    trim_start_ms INTEGER NOT NULL DEFAULT 0,
+    trim_end_ms INTEGER NOT NULL DEFAULT 0,
+    fade_in_ms INTEGER NOT NULL DEFAULT 0,
+    fade_out_ms INTEGER NOT NULL DEFAULT 0
+);
+INSERT INTO effects_backup SELECT id, server_id, name, uploader, peak_db, duration_ms, silent_start_samples, silent_end_samples, transcript, volume_db, trim_start_ms, trim_end_ms, fade_in_ms, fade_out_ms FROM effects;
+DROP TABLE effects;
+ALTER TABLE effects_backup RENAME TO effects;
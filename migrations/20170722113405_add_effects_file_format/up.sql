@@ -0,0 +1 @@
+ALTER TABLE effects ADD COLUMN file_format VARCHAR(255) NOT NULL DEFAULT 'flac';
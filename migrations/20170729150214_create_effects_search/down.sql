@@ -0,0 +1,6 @@
+DROP TRIGGER IF EXISTS aliases_search_delete;
+DROP TRIGGER IF EXISTS aliases_search_insert;
+DROP TRIGGER IF EXISTS effects_search_delete;
+DROP TRIGGER IF EXISTS effects_search_update;
+DROP TRIGGER IF EXISTS effects_search_insert;
+DROP TABLE effects_search;
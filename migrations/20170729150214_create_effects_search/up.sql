@@ -0,0 +1,38 @@
+CREATE VIRTUAL TABLE effects_search USING fts5(
+    server_id UNINDEXED,
+    kind UNINDEXED,
+    name,
+    tags,
+    content
+);
+
+INSERT INTO effects_search (server_id, kind, name, tags, content)
+    SELECT server_id, 'effect', name, '', transcript FROM effects;
+
+INSERT INTO effects_search (server_id, kind, name, tags, content)
+    SELECT server_id, 'alias', name, '', effect_names FROM aliases;
+
+CREATE TRIGGER effects_search_insert AFTER INSERT ON effects BEGIN
+    INSERT INTO effects_search (server_id, kind, name, tags, content)
+        VALUES (new.server_id, 'effect', new.name, '', new.transcript);
+END;
+
+CREATE TRIGGER effects_search_update AFTER UPDATE OF name, transcript ON effects BEGIN
+    UPDATE effects_search SET name = new.name, content = new.transcript
+        WHERE server_id = old.server_id AND kind = 'effect' AND name = old.name;
+END;
+
+CREATE TRIGGER effects_search_delete AFTER DELETE ON effects BEGIN
+    DELETE FROM effects_search
+        WHERE server_id = old.server_id AND kind = 'effect' AND name = old.name;
+END;
+
+CREATE TRIGGER aliases_search_insert AFTER INSERT ON aliases BEGIN
+    INSERT INTO effects_search (server_id, kind, name, tags, content)
+        VALUES (new.server_id, 'alias', new.name, '', new.effect_names);
+END;
+
+CREATE TRIGGER aliases_search_delete AFTER DELETE ON aliases BEGIN
+    DELETE FROM effects_search
+        WHERE server_id = old.server_id AND kind = 'alias' AND name = old.name;
+END;
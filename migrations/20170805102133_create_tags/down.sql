@@ -0,0 +1,5 @@
+DROP TRIGGER IF EXISTS aliases_tags_delete;
+DROP TRIGGER IF EXISTS effects_tags_delete;
+DROP TRIGGER IF EXISTS tags_search_delete;
+DROP TRIGGER IF EXISTS tags_search_insert;
+DROP TABLE tags;
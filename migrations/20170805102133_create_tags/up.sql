@@ -0,0 +1,32 @@
+CREATE TABLE tags (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    kind VARCHAR(16) NOT NULL DEFAULT 'effect',
+    effect_name VARCHAR(255) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    UNIQUE(server_id, effect_name, name)
+);
+
+CREATE TRIGGER tags_search_insert AFTER INSERT ON tags BEGIN
+    UPDATE effects_search SET tags = (
+        SELECT group_concat(name, ' ') FROM tags
+            WHERE server_id = new.server_id AND kind = new.kind AND effect_name = new.effect_name
+    ) WHERE server_id = new.server_id AND kind = new.kind AND name = new.effect_name;
+END;
+
+CREATE TRIGGER tags_search_delete AFTER DELETE ON tags BEGIN
+    UPDATE effects_search SET tags = COALESCE((
+        SELECT group_concat(name, ' ') FROM tags
+            WHERE server_id = old.server_id AND kind = old.kind AND effect_name = old.effect_name
+    ), '') WHERE server_id = old.server_id AND kind = old.kind AND name = old.effect_name;
+END;
+
+CREATE TRIGGER effects_tags_delete AFTER DELETE ON effects BEGIN
+    DELETE FROM tags
+        WHERE server_id = old.server_id AND kind = 'effect' AND effect_name = old.name;
+END;
+
+CREATE TRIGGER aliases_tags_delete AFTER DELETE ON aliases BEGIN
+    DELETE FROM tags
+        WHERE server_id = old.server_id AND kind = 'alias' AND effect_name = old.name;
+END;
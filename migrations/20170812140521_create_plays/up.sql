@@ -0,0 +1,12 @@
+CREATE TABLE plays (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    effect_name VARCHAR(255) NOT NULL,
+    user_id VARCHAR(255) NOT NULL,
+    channel_id VARCHAR(255) NOT NULL,
+    played_at BIGINT NOT NULL,
+    queued BOOLEAN NOT NULL DEFAULT 0,
+    source VARCHAR(255) NOT NULL
+);
+
+CREATE INDEX plays_server_effect ON plays (server_id, effect_name);
@@ -0,0 +1 @@
+DROP TABLE mixer_settings;
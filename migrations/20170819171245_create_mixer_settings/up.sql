@@ -0,0 +1,9 @@
+CREATE TABLE mixer_settings (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    max_parallel_sources INTEGER NOT NULL DEFAULT 2,
+    delay_ms INTEGER NOT NULL DEFAULT 5000,
+    compressor_threshold FLOAT NOT NULL DEFAULT 0.6,
+    master_volume_db FLOAT NOT NULL DEFAULT 0,
+    limiter BOOLEAN NOT NULL DEFAULT 0
+);
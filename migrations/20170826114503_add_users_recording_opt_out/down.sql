@@ -0,0 +1,11 @@
+CREATE TABLE users_backup (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    nickname VARCHAR(255) NOT NULL,
+    is_admin BOOLEAN NOT NULL DEFAULT false,
+    is_uploader BOOLEAN NOT NULL DEFAULT false,
+    is_banned BOOLEAN NOT NULL DEFAULT false
+);
+INSERT INTO users_backup SELECT id, server_id, nickname, is_admin, is_uploader, is_banned FROM users;
+DROP TABLE users;
+ALTER TABLE users_backup RENAME TO users;
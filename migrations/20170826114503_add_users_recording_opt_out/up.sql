@@ -0,0 +1 @@
+ALTER TABLE users ADD COLUMN is_recording_opt_out BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,7 @@
+CREATE TABLE synced_users (
+    id INTEGER PRIMARY KEY NOT NULL,
+    server_id VARCHAR(255) NOT NULL,
+    flag VARCHAR(32) NOT NULL,
+    nickname VARCHAR(255) NOT NULL,
+    UNIQUE(server_id, flag, nickname)
+);
@@ -0,0 +1,70 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::effect::{Effect, EffectSettings};
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    effect: Effect,
+    settings: EffectSettings
+}
+
+impl Action {
+    pub fn new(message: Message, effect: &Effect, settings: EffectSettings) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            effect: effect.clone(),
+            settings: settings
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            if let Err(err) = server.update_effect_settings(&self.effect, &self.settings) {
+                warn!("{} Adjusting failed: {}", self, err);
+                MessageActions::Send::public(
+                    &self.message,
+                    format!(
+                        "Failed to adjust sound effect `{}`.",
+                        self.effect.name
+                    )
+                )
+
+            } else {
+                MessageActions::Send::public(
+                    &self.message,
+                    format!(
+                        "Sound effect `{}` now plays with {:+.1}dB volume, {}ms / {}ms trim and {}ms / {}ms fade.",
+                        self.effect.name,
+                        self.settings.volume_db,
+                        self.settings.trim_start_ms,
+                        self.settings.trim_end_ms,
+                        self.settings.fade_in_ms,
+                        self.settings.fade_out_ms
+                    )
+                )
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [AdjustEffect] {} to {:?}", self.effect, self.settings)
+    }
+}
+
@@ -0,0 +1,89 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    effect_name: String,
+    duration_ms: u64,
+    uploader: String
+}
+
+impl Action {
+    pub fn new(
+        message: Message,
+        effect_name: String,
+        duration_ms: u64,
+        uploader: String
+
+    ) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            effect_name: effect_name,
+            duration_ms: duration_ms,
+            uploader: uploader
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+
+            if server.has_effect(&self.effect_name) {
+                MessageActions::Send::public(
+                    &self.message,
+                    format!(
+                        "A sound effect with the name `{}` already exists on the server.",
+                        self.effect_name
+                    )
+                )
+
+            } else if let Err(err) = server.clip_effect(
+                &self.effect_name,
+                self.duration_ms,
+                &self.uploader
+            ) {
+                warn!("{} Failed: {}", self, err);
+                MessageActions::Send::public(
+                    &self.message,
+                    format!("Failed to clip the sound effect `{}`: {}", self.effect_name, err)
+                )
+
+            } else {
+                info!("{} Clip successful.", self);
+                MessageActions::Send::public(
+                    &self.message,
+                    format!(
+                        "The last {} second(s) of voice audio are now available as `{}`!",
+                        self.duration_ms / 1000,
+                        self.effect_name
+                    )
+                )
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[Action] [ClipEffect] \"{}\" ({}ms) by {} on Server#{}",
+            self.effect_name, self.duration_ms, self.uploader, self.message.server_id
+        )
+    }
+}
+
@@ -4,8 +4,9 @@ use std::fmt;
 
 // Internal Dependencies ------------------------------------------------------
 use ::effect::Effect;
+use ::server::Server;
 use ::bot::{Bot, BotConfig};
-use ::text_util::list_words;
+use ::text_util::{list_lines, list_words};
 use ::core::{EventQueue, Message};
 use ::action::{ActionHandler, ActionGroup, MessageActions};
 
@@ -13,7 +14,8 @@ use ::action::{ActionHandler, ActionGroup, MessageActions};
 // Action Implementation ------------------------------------------------------
 pub struct Action {
     message: Message,
-    patterns: Option<Vec<String>>
+    patterns: Option<Vec<String>>,
+    phrase: Option<String>
 }
 
 impl Action {
@@ -21,31 +23,66 @@ impl Action {
     pub fn all(message: Message) -> Box<Action> {
         Box::new(Action {
             message: message,
-            patterns: None
+            patterns: None,
+            phrase: None
         })
     }
 
     pub fn matching(message: Message, patterns: Vec<String>) -> Box<Action> {
         Box::new(Action {
             message: message,
-            patterns: Some(patterns)
+            patterns: Some(patterns),
+            phrase: None
+        })
+    }
+
+    pub fn search(message: Message, phrase: String) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            patterns: None,
+            phrase: Some(phrase)
         })
     }
 
 }
 
 impl ActionHandler for Action {
-    fn run(&mut self, bot: &mut Bot, config: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
 
         if let Some(server) = bot.get_server(&self.message.server_id) {
 
-            if let Some(ref patterns) = self.patterns {
+            if let Some(ref phrase) = self.phrase {
+                let effects = server.search_effect_transcripts(phrase);
+                if effects.is_empty() {
+                    MessageActions::Send::private(
+                        &self.message,
+                        format!(
+                            "There are no sound effects with a transcript matching \"{}\" on {}.",
+                            phrase,
+                            server.name
+                        )
+                    )
+
+                } else {
+                    let title = format!("Sound Effects matching \"{}\"", phrase);
+                    let lines: Vec<String> = effects.iter().map(|effect| {
+                        format!("`{}` - *{}*", effect.name, effect.transcript())
+
+                    }).collect();
+
+                    list_lines(&title, &lines, 25).into_iter().map(|text| {
+                        MessageActions::Send::single_private(&self.message, text) as Box<ActionHandler>
+
+                    }).collect()
+                }
+
+            } else if let Some(ref patterns) = self.patterns {
                 let title = format!(
                     "Sound Effect matching `{}`",
                     patterns.join("`, `")
                 );
 
-                let effects = server.map_effects(&patterns[..], true, config);
+                let effects = server.map_effects(&patterns[..], true);
                 if effects.is_empty() {
                     MessageActions::Send::private(
                         &self.message,
@@ -57,12 +94,12 @@ impl ActionHandler for Action {
                     )
 
                 } else {
-                    list_effects(&self.message, title.as_str(), effects.as_slice())
+                    list_effects(&self.message, title.as_str(), effects.as_slice(), server)
                 }
 
             } else {
                 let patterns = vec![String::from("*")];
-                let effects = server.map_effects(&patterns[..], true, config);
+                let effects = server.map_effects(&patterns[..], true);
                 if effects.is_empty() {
                     MessageActions::Send::private(
                         &self.message,
@@ -73,7 +110,7 @@ impl ActionHandler for Action {
                     )
 
                 } else {
-                    list_effects(&self.message, "Sound Effects", effects.as_slice())
+                    list_effects(&self.message, "Sound Effects", effects.as_slice(), server)
                 }
             }
 
@@ -95,17 +132,26 @@ impl fmt::Display for Action {
 fn list_effects(
     message: &Message,
     title: &str,
-    effects: &[&Effect]
+    effects: &[&Effect],
+    server: &Server
 
 ) -> ActionGroup {
 
-    let mut effects_names: Vec<&str> = effects.iter().map(|effect| {
-        effect.name.as_str()
+    let mut effects: Vec<&Effect> = effects.to_vec();
+    effects.sort_by(|a, b| a.name.cmp(&b.name));
 
-    }).collect();
+    let effects_names: Vec<String> = effects.iter().map(|effect| {
+        let tags = server.get_effect_tags(&effect.name);
+        if tags.is_empty() {
+            effect.name.to_string()
+
+        } else {
+            format!("{} (#{})", effect.name, tags.join(", #"))
+        }
 
-    effects_names.sort();
+    }).collect();
 
+    let effects_names: Vec<&str> = effects_names.iter().map(|name| name.as_str()).collect();
     list_words(title, &effects_names, 100, 4).into_iter().map(|text| {
         MessageActions::Send::single_private(message, text) as Box<ActionHandler>
 
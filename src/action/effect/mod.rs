@@ -1,15 +1,21 @@
 // Modules --------------------------------------------------------------------
+mod adjust;
+mod clip;
 mod delete;
 mod list;
 mod play;
 mod rename;
+mod search;
 mod silence;
 
 
 // Re-Exports -----------------------------------------------------------------
+pub use self::adjust::Action as Adjust;
+pub use self::clip::Action as Clip;
 pub use self::delete::Action as Delete;
 pub use self::list::Action as List;
 pub use self::play::Action as Play;
 pub use self::rename::Action as Rename;
+pub use self::search::Action as Search;
 pub use self::silence::Action as Silence;
 
@@ -2,52 +2,124 @@
 use std::fmt;
 
 
+// External Dependencies ------------------------------------------------------
+use serde_json;
+use serde_json::Value;
+
+
 // Discord Dependencies -------------------------------------------------------
-use discord::model::{ChannelId, ServerId};
+use discord::model::{ChannelId, ServerId, UserId};
 
 
 // Internal Dependencies ------------------------------------------------------
 use ::effect::Effect;
 use ::core::EventQueue;
 use ::bot::{Bot, BotConfig};
+use ::server::PlaybackSource;
 use ::action::{ActionHandler, ActionGroup};
 
 
+// Persisted Payload ----------------------------------------------------------
+#[derive(Serialize, Deserialize)]
+struct Payload {
+    server_id: u64,
+    channel_id: u64,
+    effects: Vec<String>,
+    queued: bool,
+    #[serde(default)]
+    user_id: u64
+}
+
+
 // Action Implementation ------------------------------------------------------
 pub struct Action {
     server_id: ServerId,
     channel_id: ChannelId,
     effects: Vec<Effect>,
+    patterns: Vec<String>,
     queued: bool,
+    source: PlaybackSource,
     complete_action: Option<Box<ActionHandler>>
 }
 
 impl Action {
+
+    pub const KIND: &'static str = "effect_play";
+
     pub fn new(
         server_id: ServerId,
         channel_id: ChannelId,
         effects: Vec<&Effect>,
         queued: bool,
+        source: PlaybackSource,
         complete_action: Option<Box<ActionHandler>>
 
     ) -> Box<Action> {
         Box::new(Action {
             server_id: server_id,
             channel_id: channel_id,
+            patterns: effects.iter().map(|e| e.name.to_string()).collect(),
             effects: effects.iter().map(|e| (*e).clone()).collect(),
             queued: queued,
+            source: source,
             complete_action: complete_action
         })
     }
+
+    pub fn from_patterns(
+        server_id: ServerId,
+        channel_id: ChannelId,
+        patterns: Vec<String>,
+        queued: bool,
+        source: PlaybackSource
+
+    ) -> Box<Action> {
+        Box::new(Action {
+            server_id: server_id,
+            channel_id: channel_id,
+            effects: Vec::new(),
+            patterns: patterns,
+            queued: queued,
+            source: source,
+            complete_action: None
+        })
+    }
+
+    pub fn restore(payload: Value) -> Option<Box<ActionHandler>> {
+        serde_json::from_value::<Payload>(payload).ok().map(|payload| {
+            Action::from_patterns(
+                ServerId(payload.server_id),
+                ChannelId(payload.channel_id),
+                payload.effects,
+                payload.queued,
+                PlaybackSource::Schedule(UserId(payload.user_id))
+
+            ) as Box<ActionHandler>
+        })
+    }
+
 }
 
 impl ActionHandler for Action {
+
     fn run(&mut self, bot: &mut Bot, _: &BotConfig, queue: &mut EventQueue) -> ActionGroup {
         if let Some(server) = bot.get_server(&self.server_id) {
+
+            // Pattern based actions are resolved against the server's current
+            // effects every time they are run
+            let effects: Vec<Effect> = if self.effects.is_empty() {
+                server.map_effects(&self.patterns, false).into_iter().cloned().collect()
+
+            } else {
+                self.effects.clone()
+            };
+
             server.play_effects(
                 &self.channel_id,
-                &self.effects,
+                &effects,
                 self.queued,
+                0,
+                self.source,
                 queue,
                 self.complete_action.take()
             );
@@ -57,6 +129,18 @@ impl ActionHandler for Action {
             vec![]
         }
     }
+
+    fn persist(&self) -> Option<(&'static str, Value)> {
+        serde_json::to_value(Payload {
+            server_id: self.server_id.0,
+            channel_id: self.channel_id.0,
+            effects: self.patterns.clone(),
+            queued: self.queued,
+            user_id: self.source.user_id().0
+
+        }).ok().map(|payload| (Action::KIND, payload))
+    }
+
 }
 
 impl fmt::Display for Action {
@@ -64,4 +148,3 @@ impl fmt::Display for Action {
         write!(f, "[Action] [PlayEffects]")
     }
 }
-
@@ -0,0 +1,76 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::text_util::list_lines;
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Statics --------------------------------------------------------------------
+static MAX_RESULTS: usize = 100;
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    query: String
+}
+
+impl Action {
+    pub fn new(message: Message, query: String) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            query: query
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+
+            let results: Vec<String> = server.search_effects(&self.query, MAX_RESULTS).into_iter().enumerate().map(|(index, m)| {
+                if m.is_alias {
+                    format!("{}. `{}` (alias)", index + 1, m.name)
+
+                } else {
+                    format!("{}. `{}`", index + 1, m.name)
+                }
+
+            }).collect();
+
+            if results.is_empty() {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!(
+                        "No sound effects matching \"{}\" were found on {}.",
+                        self.query,
+                        server.name
+                    )
+                )
+
+            } else {
+                let title = format!("Search results for \"{}\"", self.query);
+                list_lines(&title, &results, 25).into_iter().map(|text| {
+                    MessageActions::Send::single_private(&self.message, text) as Box<ActionHandler>
+
+                }).collect()
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [SearchEffects] \"{}\"", self.query)
+    }
+}
+
@@ -2,6 +2,11 @@
 use std::fmt;
 
 
+// External Dependencies ------------------------------------------------------
+use serde_json;
+use serde_json::Value;
+
+
 // Discord Dependencies -------------------------------------------------------
 use discord::model::{ChannelId, UserId};
 
@@ -12,6 +17,15 @@ use ::core::{EventQueue, Message};
 use ::action::{ActionHandler, ActionGroup};
 
 
+// Persisted Payload ----------------------------------------------------------
+#[derive(Serialize, Deserialize)]
+struct Payload {
+    user_id: Option<u64>,
+    channel_id: Option<u64>,
+    content: String
+}
+
+
 // Action Implementation ------------------------------------------------------
 pub struct Action {
     user_id: Option<UserId>,
@@ -21,6 +35,19 @@ pub struct Action {
 
 impl Action {
 
+    pub const KIND: &'static str = "message_send";
+
+    pub fn restore(payload: Value) -> Option<Box<ActionHandler>> {
+        serde_json::from_value::<Payload>(payload).ok().map(|payload| {
+            Box::new(Action {
+                user_id: payload.user_id.map(UserId),
+                channel_id: payload.channel_id.map(ChannelId),
+                content: payload.content
+
+            }) as Box<ActionHandler>
+        })
+    }
+
     pub fn private(message: &Message, content: String) -> ActionGroup {
         vec![Action::single_private(message, content)]
     }
@@ -76,6 +103,16 @@ impl ActionHandler for Action {
         vec![]
 
     }
+
+    fn persist(&self) -> Option<(&'static str, Value)> {
+        serde_json::to_value(Payload {
+            user_id: self.user_id.map(|id| id.0),
+            channel_id: self.channel_id.map(|id| id.0),
+            content: self.content.clone()
+
+        }).ok().map(|payload| (Action::KIND, payload))
+    }
+
 }
 
 impl fmt::Display for Action {
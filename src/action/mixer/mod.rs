@@ -0,0 +1,8 @@
+// Modules --------------------------------------------------------------------
+mod show;
+mod update;
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::show::Action as Show;
+pub use self::update::Action as Update;
@@ -0,0 +1,56 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message
+}
+
+impl Action {
+    pub fn new(message: Message) -> Box<Action> {
+        Box::new(Action {
+            message: message
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            let settings = server.get_mixer_settings();
+            MessageActions::Send::private(&self.message, format!(
+                "__Mixer settings on {}:__\n\n - Parallel sources: {}\n - Join delay: {}ms\n - Compressor threshold: {:.2}\n - Master volume: {:+.1}dB\n - Limiter: {}",
+                server.name,
+                settings.max_parallel_sources,
+                settings.delay_ms,
+                settings.compressor_threshold,
+                settings.master_volume_db,
+                if settings.limiter {
+                    "on"
+
+                } else {
+                    "off"
+                }
+            ))
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [ShowMixerSettings]")
+    }
+}
+
@@ -0,0 +1,54 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::audio::MixerSettings;
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions, MixerActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    settings: MixerSettings
+}
+
+impl Action {
+    pub fn new(message: Message, settings: MixerSettings) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            settings: settings
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            if let Err(err) = server.update_mixer_settings(self.settings) {
+                warn!("{} Failed: {}", self, err);
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("Failed to update the mixer settings on {}.", server.name)
+                )
+
+            } else {
+                vec![MixerActions::Show::new(self.message)]
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [UpdateMixerSettings]")
+    }
+}
+
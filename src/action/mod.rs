@@ -2,6 +2,10 @@
 use std::fmt;
 
 
+// External Dependencies ------------------------------------------------------
+use serde_json::Value;
+
+
 // Internal Dependencies ------------------------------------------------------
 use ::core::EventQueue;
 use ::bot::{Bot, BotConfig};
@@ -14,8 +18,15 @@ pub mod ban;
 pub mod effect;
 pub mod greeting;
 pub mod message;
+pub mod mixer;
+pub mod permission;
+pub mod playlist;
 pub mod recording;
+pub mod registry;
+pub mod schedule;
 pub mod server;
+pub mod stats;
+pub mod tag;
 pub mod uploader;
 pub mod timed;
 pub mod twitch;
@@ -28,8 +39,14 @@ pub use self::ban as BanActions;
 pub use self::effect as EffectActions;
 pub use self::greeting as GreetingActions;
 pub use self::message as MessageActions;
+pub use self::mixer as MixerActions;
+pub use self::permission as PermissionActions;
+pub use self::playlist as PlaylistActions;
 pub use self::recording as RecordingActions;
+pub use self::schedule as ScheduleActions;
 pub use self::server as ServerActions;
+pub use self::stats as StatsActions;
+pub use self::tag as TagActions;
 pub use self::uploader as UploaderActions;
 pub use self::timed as TimedActions;
 pub use self::twitch as TwitchActions;
@@ -47,5 +64,9 @@ pub trait ActionHandler: fmt::Display + Send {
 
     fn run(&mut self, &mut Bot, &BotConfig, &mut EventQueue) -> ActionGroup;
 
+    fn persist(&self) -> Option<(&'static str, Value)> {
+        None
+    }
+
 }
 
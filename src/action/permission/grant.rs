@@ -0,0 +1,69 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::server::PermissionSubject;
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    subject: PermissionSubject,
+    command: String
+}
+
+impl Action {
+    pub fn new(
+        message: Message,
+        subject: PermissionSubject,
+        command: String
+
+    ) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            subject: subject,
+            command: command
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            let name = server.permission_subject_name(&self.subject);
+            if server.add_permission(&self.subject, &self.command) {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!(
+                        "`{}` is now permitted to use `{}` on {}.",
+                        name, self.command, server.name
+                    )
+                )
+
+            } else {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!(
+                        "`{}` is already permitted to use `{}` on {}.",
+                        name, self.command, server.name
+                    )
+                )
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [GrantPermission] {} {}", self.subject, self.command)
+    }
+}
@@ -0,0 +1,60 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::text_util::list_lines;
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message
+}
+
+impl Action {
+    pub fn new(message: Message) -> Box<Action> {
+        Box::new(Action {
+            message: message
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+
+            let permissions: Vec<String> = server.list_permissions().into_iter().map(|(command, subject)| {
+                format!("`{}` -> `{}`", command, server.permission_subject_name(&subject))
+
+            }).collect();
+
+            if permissions.is_empty() {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("No command permissions found on {}.", server.name)
+                )
+
+            } else {
+                let title = format!("Command Permissions on {}", server.name);
+                list_lines(&title, &permissions, 25).into_iter().map(|text| {
+                    MessageActions::Send::single_private(&self.message, text) as Box<ActionHandler>
+
+                }).collect()
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [ListPermissions]")
+    }
+}
@@ -0,0 +1,11 @@
+// Modules --------------------------------------------------------------------
+mod grant;
+mod list;
+mod revoke;
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::grant::Action as Grant;
+pub use self::list::Action as List;
+pub use self::revoke::Action as Revoke;
+
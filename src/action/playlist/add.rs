@@ -0,0 +1,75 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::UserId;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    name: String,
+    creator: UserId,
+    effect_names: Vec<String>
+}
+
+impl Action {
+    pub fn new(
+        message: Message,
+        name: String,
+        creator: UserId,
+        effect_names: Vec<String>
+
+    ) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            name: name,
+            creator: creator,
+            effect_names: effect_names
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            if let Err(err) = server.add_playlist(&self.name, &self.creator, &self.effect_names) {
+                warn!("{} Failed: {}", self, err);
+                MessageActions::Send::private(&self.message, format!(
+                    "Failed to store playlist `{}` on {}.",
+                    self.name, server.name
+                ))
+
+            } else {
+                MessageActions::Send::private(&self.message, format!(
+                    "Playlist `{}` now contains `{}` on {}.",
+                    self.name, self.effect_names.join("`, `"), server.name
+                ))
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[Action] [AddPlaylist] {} with \"{}\"",
+            self.name,
+            self.effect_names.join("\", \"")
+        )
+    }
+}
+
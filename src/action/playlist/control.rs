@@ -0,0 +1,56 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::audio::MixerCommand;
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    command: Option<MixerCommand>,
+    announcement: String
+}
+
+impl Action {
+    pub fn new(message: Message, command: MixerCommand, announcement: String) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            command: Some(command),
+            announcement: announcement
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            if let Some(command) = self.command.take() {
+                return if server.control_effects(command) {
+                    MessageActions::Send::public(&self.message, self.announcement.clone())
+
+                } else {
+                    MessageActions::Send::private(
+                        &self.message,
+                        format!("Nothing is currently being played on {}.", server.name)
+                    )
+                };
+            }
+        }
+
+        vec![]
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [ControlPlayback] Server #{}", self.message.server_id)
+    }
+}
+
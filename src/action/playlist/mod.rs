@@ -0,0 +1,15 @@
+// Modules --------------------------------------------------------------------
+mod add;
+mod control;
+mod list;
+mod play;
+mod remove;
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::add::Action as Add;
+pub use self::control::Action as Control;
+pub use self::list::Action as List;
+pub use self::play::Action as Play;
+pub use self::remove::Action as Remove;
+
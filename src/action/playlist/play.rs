@@ -0,0 +1,101 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::ChannelId;
+
+
+// External Dependencies ------------------------------------------------------
+use rand::{thread_rng, Rng};
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::effect::Effect;
+use ::bot::{Bot, BotConfig};
+use ::server::PlaybackSource;
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    channel_id: ChannelId,
+    name: String,
+    shuffle: bool,
+    loops: usize
+}
+
+impl Action {
+    pub fn new(
+        message: Message,
+        channel_id: ChannelId,
+        name: String,
+        shuffle: bool,
+        loops: usize
+
+    ) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            channel_id: channel_id,
+            name: name,
+            shuffle: shuffle,
+            loops: loops
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, queue: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+
+            let mut effects: Vec<Effect> = server.get_playlist(&self.name).map(|patterns| {
+                server.map_effects(&patterns, false).into_iter().cloned().collect()
+
+            }).unwrap_or_else(Vec::new);
+
+            if effects.is_empty() {
+                MessageActions::Send::private(&self.message, format!(
+                    "Playlist `{}` contains no playable effects on {}.",
+                    self.name, server.name
+                ))
+
+            } else {
+
+                if self.shuffle {
+                    thread_rng().shuffle(&mut effects[..]);
+                }
+
+                server.play_effects(
+                    &self.channel_id,
+                    &effects,
+                    true,
+                    self.loops,
+                    PlaybackSource::Playlist(self.message.user_id),
+                    queue,
+                    None
+                );
+
+                vec![]
+
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[Action] [PlayPlaylist] {} (shuffle: {}, loops: {})",
+            self.name, self.shuffle, self.loops
+        )
+    }
+}
+
@@ -0,0 +1,48 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::ServerId;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::EventQueue;
+use ::action::{ActionHandler, ActionGroup};
+
+
+// Recording Retention Implementation -----------------------------------------
+pub struct Action {
+    server_id: ServerId
+}
+
+impl Action {
+    pub fn new(server_id: ServerId) -> Box<Action> {
+        Box::new(Action {
+            server_id: server_id
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.server_id) {
+            let deleted = server.enforce_recording_retention();
+            if !deleted.is_empty() {
+                info!("{} Removed {} expired recording(s)", self, deleted.len());
+            }
+        }
+
+        vec![]
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [CleanupRecordings] Server #{}", self.server_id)
+    }
+}
+
@@ -0,0 +1,82 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::text_util::{format_duration, format_size};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    name: String
+}
+
+impl Action {
+    pub fn new(message: Message, name: String) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            name: name
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            if let Some(recording) = server.get_recording(&self.name) {
+
+                let mut lines = vec![
+                    format!("__Audio Recording `{}` on {}:__\n", recording.name, server.name),
+                    format!(" - Size: {}", format_size(recording.size))
+                ];
+
+                if let Some(manifest) = recording.manifest {
+                    lines.push(format!(" - Started: {}", manifest.started));
+                    lines.push(format!(" - Channel: #{}", manifest.channel_name));
+                    lines.push(format!(" - Duration: {}", format_duration(u64::from(manifest.duration))));
+                    lines.push(format!(" - Mixdown: `{}`", manifest.mixdown));
+                    lines.push(" - Tracks:".to_string());
+                    for track in manifest.tracks {
+                        lines.push(format!(
+                            "    - {} `{}` @ {} for {}",
+                            track.nickname.unwrap_or_else(|| format!("User#{}", track.user_id)),
+                            track.file,
+                            format_duration(u64::from(track.offset)),
+                            format_duration(u64::from(track.duration))
+                        ));
+                    }
+
+                } else {
+                    if server.is_active_recording(&recording.name) {
+                        lines.push(" - *Recording in progress*".to_string());
+                    }
+                    lines.push(format!(" - Files: `{}`", recording.files.join("`, `")));
+                }
+
+                MessageActions::Send::private(&self.message, lines.join("\n"))
+
+            } else {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("No audio recording named `{}` exists on {}.", self.name, server.name)
+                )
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [RecordingInfo] \"{}\"", self.name)
+    }
+}
+
@@ -0,0 +1,80 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::text_util::{format_duration, format_size, list_lines};
+use ::action::{ActionHandler, ActionGroup, MessageActions, TimedActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message
+}
+
+impl Action {
+    pub fn new(message: Message) -> Box<Action> {
+        Box::new(Action {
+            message: message
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+
+            let recordings = server.list_recordings();
+            if recordings.is_empty() {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("There are no audio recordings on {}.", server.name)
+                )
+
+            } else {
+                let now = TimedActions::timestamp_ms();
+                let lines: Vec<String> = recordings.iter().map(|recording| {
+                    let age = format_duration((now - recording.modified).max(0) as u64);
+                    if let Some(ref manifest) = recording.manifest {
+                        format!(
+                            "`{}` - {} in #{}, {} track(s), {}, {} ago",
+                            recording.name,
+                            format_duration(u64::from(manifest.duration)),
+                            manifest.channel_name,
+                            manifest.tracks.len(),
+                            format_size(recording.size),
+                            age
+                        )
+
+                    } else if server.is_active_recording(&recording.name) {
+                        format!("`{}` - *in progress*, {}", recording.name, format_size(recording.size))
+
+                    } else {
+                        format!("`{}` - {}, {} ago", recording.name, format_size(recording.size), age)
+                    }
+
+                }).collect();
+
+                let title = format!("Audio Recordings on {}", server.name);
+                list_lines(&title, &lines, 25).into_iter().map(|text| {
+                    MessageActions::Send::single_private(&self.message, text) as Box<ActionHandler>
+
+                }).collect()
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [ListRecordings]")
+    }
+}
+
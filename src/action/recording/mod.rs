@@ -1,9 +1,23 @@
 // Modules --------------------------------------------------------------------
+mod cleanup;
+mod delete;
+mod info;
+mod list;
+mod opt_out;
 mod start;
 mod stop;
+mod transcribe;
+mod transcript;
 
 
 // Re-Exports -----------------------------------------------------------------
+pub use self::cleanup::Action as Cleanup;
+pub use self::delete::Action as Delete;
+pub use self::info::Action as Info;
+pub use self::list::Action as List;
+pub use self::opt_out::Action as OptOut;
 pub use self::start::Action as Start;
 pub use self::stop::Action as Stop;
+pub use self::transcribe::Action as Transcribe;
+pub use self::transcript::Action as Transcript;
 
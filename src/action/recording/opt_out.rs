@@ -0,0 +1,62 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    opt_out: bool
+}
+
+impl Action {
+    pub fn new(message: Message, opt_out: bool) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            opt_out: opt_out
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            if let Err(err) = server.set_member_recording_opt_out(&self.message.user_id, self.opt_out) {
+                warn!("{} Failed: {}", self, err);
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("Failed to update your recording preference on {}.", server.name)
+                )
+
+            } else if self.opt_out {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("Your voice will no longer be recorded on {}.", server.name)
+                )
+
+            } else {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("Your voice will be included in future recordings on {} again.", server.name)
+                )
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [RecordingOptOut] {} for User#{}", self.opt_out, self.message.user_id)
+    }
+}
+
@@ -3,7 +3,7 @@ use std::fmt;
 
 
 // Discord Dependencies -------------------------------------------------------
-use discord::model::{ChannelId, ServerId};
+use discord::model::{ChannelId, ServerId, UserId};
 
 
 // Internal Dependencies ------------------------------------------------------
@@ -16,17 +16,23 @@ use ::action::{ActionHandler, ActionGroup, MessageActions};
 pub struct Action {
     server_id: ServerId,
     voice_channel_id: ChannelId,
+    user_id: UserId,
+    announce_channel_id: Option<ChannelId>
 }
 
 impl Action {
     pub fn new(
         server_id: ServerId,
-        voice_channel_id: ChannelId
+        voice_channel_id: ChannelId,
+        user_id: UserId,
+        announce_channel_id: Option<ChannelId>
 
     ) -> Box<Action> {
         Box::new(Action {
             server_id: server_id,
-            voice_channel_id: voice_channel_id
+            voice_channel_id: voice_channel_id,
+            user_id: user_id,
+            announce_channel_id: announce_channel_id
         })
     }
 }
@@ -42,18 +48,34 @@ impl ActionHandler for Action {
 
             if let Some(channel_name) = server.channel_name(&self.voice_channel_id) {
 
+                // Members are only notified once the recording actually
+                // started
+                if !server.start_recording_voice(&self.voice_channel_id, queue) {
+                    return actions;
+                }
+
                 // Notify all users in the current voice channel
                 for member in server.channel_voice_members(&self.voice_channel_id) {
                     actions.push(MessageActions::Send::user_private(
                         member.id,
                         format!(
-                            "Note: Audio recording has been **started** for your current voice channel {}.",
+                            "Note: Audio recording has been **started** for your current voice channel {}. Use `!record optout` in order to be excluded from all recordings.",
                             channel_name
                         )
                     ))
                 }
 
-                server.start_recording_voice(&self.voice_channel_id, queue);
+                if let Some(announce_channel_id) = self.announce_channel_id {
+                    actions.push(MessageActions::Send::single_public_channel(
+                        &announce_channel_id,
+                        format!(
+                            "Audio recording has been **started** in the voice channel {}. Members can use `!record optout` in order to be excluded.",
+                            channel_name
+                        )
+                    ));
+                }
+
+                server.play_recording_cue(&self.voice_channel_id, true, self.user_id, queue);
 
             }
 
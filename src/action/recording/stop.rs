@@ -3,7 +3,7 @@ use std::fmt;
 
 
 // Discord Dependencies -------------------------------------------------------
-use discord::model::{ChannelId, ServerId};
+use discord::model::{ChannelId, ServerId, UserId};
 
 
 // Internal Dependencies ------------------------------------------------------
@@ -16,17 +16,23 @@ use ::action::{ActionHandler, ActionGroup, MessageActions};
 pub struct Action {
     server_id: ServerId,
     voice_channel_id: ChannelId,
+    user_id: UserId,
+    announce_channel_id: Option<ChannelId>
 }
 
 impl Action {
     pub fn new(
         server_id: ServerId,
-        voice_channel_id: ChannelId
+        voice_channel_id: ChannelId,
+        user_id: UserId,
+        announce_channel_id: Option<ChannelId>
 
     ) -> Box<Action> {
         Box::new(Action {
             server_id: server_id,
-            voice_channel_id: voice_channel_id
+            voice_channel_id: voice_channel_id,
+            user_id: user_id,
+            announce_channel_id: announce_channel_id
         })
     }
 }
@@ -53,6 +59,17 @@ impl ActionHandler for Action {
                     ))
                 }
 
+                if let Some(announce_channel_id) = self.announce_channel_id {
+                    actions.push(MessageActions::Send::single_public_channel(
+                        &announce_channel_id,
+                        format!(
+                            "Audio recording has been **stopped** in the voice channel {}.",
+                            channel_name
+                        )
+                    ));
+                }
+
+                server.play_recording_cue(&self.voice_channel_id, false, self.user_id, queue);
                 server.stop_recording_voice(queue);
             }
 
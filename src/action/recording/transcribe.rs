@@ -0,0 +1,47 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::ServerId;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::EventQueue;
+use ::action::{ActionHandler, ActionGroup};
+
+
+// Recording Transcription Implementation -------------------------------------
+pub struct Action {
+    server_id: ServerId
+}
+
+impl Action {
+    pub fn new(server_id: ServerId) -> Box<Action> {
+        Box::new(Action {
+            server_id: server_id
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.server_id) {
+            for name in server.transcribe_recordings() {
+                info!("{} Transcribed recording \"{}\"", self, name);
+            }
+        }
+
+        vec![]
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [TranscribeRecordings] Server #{}", self.server_id)
+    }
+}
+
@@ -0,0 +1,61 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::text_util::list_lines;
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    name: String
+}
+
+impl Action {
+    pub fn new(message: Message, name: String) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            name: name
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            match server.get_recording_transcript(&self.name) {
+                Ok(ref lines) if lines.is_empty() => MessageActions::Send::private(
+                    &self.message,
+                    format!("The transcript of `{}` does not contain any speech.", self.name)
+                ),
+                Ok(lines) => {
+                    let title = format!("Transcript of `{}`", self.name);
+                    list_lines(&title, &lines, 20).into_iter().map(|text| {
+                        MessageActions::Send::single_public(&self.message, text) as Box<ActionHandler>
+
+                    }).collect()
+                },
+                Err(err) => MessageActions::Send::private(
+                    &self.message,
+                    format!("No transcript available for `{}`: {}", self.name, err)
+                )
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [RecordingTranscript] \"{}\"", self.name)
+    }
+}
+
@@ -0,0 +1,42 @@
+// STD Dependencies -----------------------------------------------------------
+use std::collections::HashMap;
+
+
+// External Dependencies ------------------------------------------------------
+use serde_json::Value;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::action::{ActionHandler, EffectActions, MessageActions, TwitchActions};
+
+
+// Statics --------------------------------------------------------------------
+type RestoreFn = fn(Value) -> Option<Box<ActionHandler>>;
+
+lazy_static! {
+    static ref ACTION_KINDS: HashMap<&'static str, RestoreFn> = {
+        let mut m: HashMap<&'static str, RestoreFn> = HashMap::new();
+        m.insert(EffectActions::Play::KIND, EffectActions::Play::restore);
+        m.insert(MessageActions::Send::KIND, MessageActions::Send::restore);
+        m.insert(TwitchActions::OnlineCheck::KIND, TwitchActions::OnlineCheck::restore);
+        m
+    };
+}
+
+
+// Action Restoration ---------------------------------------------------------
+pub fn restore(kind: &str, payload: &str) -> Option<Box<ActionHandler>> {
+    if let Some(restore) = ACTION_KINDS.get(kind) {
+        match ::serde_json::from_str::<Value>(payload) {
+            Ok(value) => restore(value),
+            Err(err) => {
+                warn!("[ActionRegistry] Invalid payload for action kind \"{}\": {}", kind, err);
+                None
+            }
+        }
+
+    } else {
+        warn!("[ActionRegistry] Unknown action kind \"{}\"", kind);
+        None
+    }
+}
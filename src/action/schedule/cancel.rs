@@ -0,0 +1,89 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, EffectActions, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    id: i32,
+    is_admin: bool
+}
+
+impl Action {
+    pub fn new(message: Message, id: i32, is_admin: bool) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            id: id,
+            is_admin: is_admin
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            // Only sound effects scheduled by members can be canceled
+            let row = server.get_scheduled_action(self.id).and_then(|row| {
+                if row.kind == EffectActions::Play::KIND {
+                    Some(row)
+
+                } else {
+                    None
+                }
+            });
+
+            if let Some(row) = row {
+
+                // Only the owner or bot admins may cancel a scheduled action
+                if !self.is_admin && row.owner_id != self.message.user_id.0.to_string() {
+                    MessageActions::Send::private(
+                        &self.message,
+                        format!(
+                            "Scheduled sound effect #{} on {} can only be canceled by its owner or bot admins.",
+                            self.id, server.name
+                        )
+                    )
+
+                } else if server.remove_scheduled_action(self.id) {
+                    MessageActions::Send::private(
+                        &self.message,
+                        format!(
+                            "Scheduled sound effect #{} ({}) on {} has been canceled.",
+                            self.id, row.label, server.name
+                        )
+                    )
+
+                } else {
+                    vec![]
+                }
+
+            } else {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!(
+                        "Scheduled sound effect #{} does not exist on {}.",
+                        self.id, server.name
+                    )
+                )
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [CancelScheduled] #{}", self.id)
+    }
+}
+
@@ -0,0 +1,88 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::UserId;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::text_util::{format_duration, list_lines};
+use ::action::{ActionHandler, ActionGroup, EffectActions, MessageActions, TimedActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message
+}
+
+impl Action {
+    pub fn new(message: Message) -> Box<Action> {
+        Box::new(Action {
+            message: message
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+
+            let now = TimedActions::timestamp_ms();
+            let scheduled: Vec<String> = server.list_scheduled_actions().into_iter().filter(|row| {
+                row.kind == EffectActions::Play::KIND
+
+            }).map(|row| {
+
+                let owner = row.owner_id.parse::<u64>().ok().and_then(|id| {
+                    server.get_member(&UserId(id))
+
+                }).map(|member| member.nickname.to_string()).unwrap_or_else(|| {
+                    "unknown".to_string()
+                });
+
+                format!(
+                    "#{} - {} by {} (next in {})",
+                    row.id,
+                    row.label,
+                    owner,
+                    format_duration(if row.due_at > now {
+                        (row.due_at - now) as u64
+
+                    } else {
+                        0
+                    })
+                )
+
+            }).collect();
+
+            if scheduled.is_empty() {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("No scheduled sound effects found on {}.", server.name)
+                )
+
+            } else {
+                let title = format!("Scheduled Sound Effects on {}", server.name);
+                list_lines(&title, &scheduled, 25).into_iter().map(|text| {
+                    MessageActions::Send::single_private(&self.message, text) as Box<ActionHandler>
+
+                }).collect()
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [ListScheduled]")
+    }
+}
+
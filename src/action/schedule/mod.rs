@@ -0,0 +1,9 @@
+// Modules --------------------------------------------------------------------
+mod cancel;
+mod list;
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::cancel::Action as Cancel;
+pub use self::list::Action as List;
+
@@ -3,6 +3,7 @@ use std::fmt;
 
 
 // Internal Dependencies ------------------------------------------------------
+use ::audio::AudioFormat;
 use ::bot::{Bot, BotConfig};
 use ::core::{EventQueue, Message};
 use ::action::{ActionHandler, ActionGroup, MessageActions};
@@ -13,7 +14,8 @@ pub struct Action {
     message: Message,
     effect_name: String,
     upload_url: String,
-    uploader: String
+    uploader: String,
+    format: AudioFormat
 }
 
 impl Action {
@@ -21,14 +23,16 @@ impl Action {
         message: Message,
         effect_name: String,
         upload_url: String,
-        uploader: String
+        uploader: String,
+        format: AudioFormat
 
     ) -> Box<Action> {
         Box::new(Action {
             message: message,
             effect_name: effect_name,
             upload_url: upload_url,
-            uploader: uploader
+            uploader: uploader,
+            format: format
         })
     }
 }
@@ -54,7 +58,8 @@ impl ActionHandler for Action {
                 if let Err(err) = server.download_effect(
                     &self.effect_name,
                     &self.upload_url,
-                    &self.uploader
+                    &self.uploader,
+                    self.format
                 ) {
                     warn!("{} Download failed: {}", self, err);
                     MessageActions::Send::public(
@@ -89,8 +94,8 @@ impl fmt::Display for Action {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "[Action] [DownloadFlac] \"{}\" from {} on Server#{}",
-            self.upload_url, self.uploader, self.message.server_id
+            "[Action] [DownloadEffect] {} \"{}\" from {} on Server#{}",
+            self.format, self.upload_url, self.uploader, self.message.server_id
         )
     }
 }
@@ -1,12 +1,12 @@
 // Modules --------------------------------------------------------------------
-mod download_flac;
+mod download_effect;
 mod download_transcript;
 mod leave_voice;
 mod pin_voice;
 
 
 // Re-Exports -----------------------------------------------------------------
-pub use self::download_flac::Action as DownloadFlac;
+pub use self::download_effect::Action as DownloadEffect;
 pub use self::download_transcript::Action as DownloadTranscript;
 pub use self::leave_voice::Action as LeaveVoice;
 pub use self::pin_voice::Action as PinVoice;
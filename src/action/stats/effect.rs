@@ -0,0 +1,88 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::text_util::{format_duration, list_lines};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions, TimedActions};
+
+
+// Statics --------------------------------------------------------------------
+static TOP_LIMIT: usize = 5;
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    name: String
+}
+
+impl Action {
+    pub fn new(message: Message, name: String) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            name: name
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+
+            if let (plays, Some(last_played_at)) = server.effect_play_summary(&self.name) {
+
+                let now = TimedActions::timestamp_ms();
+                let mut lines = vec![format!(
+                    "Played {} time(s), last played {} ago",
+                    plays,
+                    format_duration(if now > last_played_at {
+                        (now - last_played_at) as u64
+
+                    } else {
+                        0
+                    })
+                )];
+
+                lines.extend(server.most_active_members(Some(&self.name), TOP_LIMIT).into_iter().map(|(user_id, plays)| {
+                    let nickname = server.get_member(&user_id).map(|member| {
+                        member.nickname.to_string()
+
+                    }).unwrap_or_else(|| "unknown".to_string());
+
+                    format!("{} - {} play(s)", nickname, plays)
+
+                }));
+
+                let title = format!("Statistics for `{}`", self.name);
+                list_lines(&title, &lines, 25).into_iter().map(|text| {
+                    MessageActions::Send::single_private(&self.message, text) as Box<ActionHandler>
+
+                }).collect()
+
+            } else {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!(
+                        "The sound effect `{}` has never been played on {}.",
+                        self.name, server.name
+                    )
+                )
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [EffectStats] for \"{}\"", self.name)
+    }
+}
+
@@ -0,0 +1,69 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::text_util::list_lines;
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Statics --------------------------------------------------------------------
+static TOP_LIMIT: usize = 10;
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message
+}
+
+impl Action {
+    pub fn new(message: Message) -> Box<Action> {
+        Box::new(Action {
+            message: message
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+
+            let effects: Vec<String> = server.most_played_effects(
+                Some(&self.message.user_id),
+                TOP_LIMIT
+
+            ).into_iter().enumerate().map(|(index, (name, plays))| {
+                format!("{}. `{}` - {} play(s)", index + 1, name, plays)
+
+            }).collect();
+
+            if effects.is_empty() {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("You have not played any sound effects on {} yet.", server.name)
+                )
+
+            } else {
+                let title = format!("Your most played Sound Effects on {}", server.name);
+                list_lines(&title, &effects, 25).into_iter().map(|text| {
+                    MessageActions::Send::single_private(&self.message, text) as Box<ActionHandler>
+
+                }).collect()
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [MemberStats] for User#{}", self.message.user_id)
+    }
+}
+
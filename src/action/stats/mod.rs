@@ -0,0 +1,10 @@
+// Modules --------------------------------------------------------------------
+mod effect;
+mod member;
+mod top;
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::effect::Action as Effect;
+pub use self::member::Action as Member;
+pub use self::top::Action as Top;
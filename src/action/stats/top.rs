@@ -0,0 +1,85 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::text_util::list_lines;
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Statics --------------------------------------------------------------------
+static TOP_LIMIT: usize = 10;
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message
+}
+
+impl Action {
+    pub fn new(message: Message) -> Box<Action> {
+        Box::new(Action {
+            message: message
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+
+            let effects: Vec<String> = server.most_played_effects(None, TOP_LIMIT).into_iter().enumerate().map(|(index, (name, plays))| {
+                format!("{}. `{}` - {} play(s)", index + 1, name, plays)
+
+            }).collect();
+
+            let members: Vec<String> = server.most_active_members(None, TOP_LIMIT).into_iter().enumerate().map(|(index, (user_id, plays))| {
+                let nickname = server.get_member(&user_id).map(|member| {
+                    member.nickname.to_string()
+
+                }).unwrap_or_else(|| "unknown".to_string());
+
+                format!("{}. {} - {} play(s)", index + 1, nickname, plays)
+
+            }).collect();
+
+            let never_played: Vec<String> = server.never_played_effects().into_iter().map(|name| {
+                format!("`{}`", name)
+
+            }).collect();
+
+            if effects.is_empty() {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("No sound effects have been played on {} yet.", server.name)
+                )
+
+            } else {
+                let mut texts = list_lines("Most played Sound Effects", &effects, 25);
+                texts.extend(list_lines("Most active Members", &members, 25));
+                if !never_played.is_empty() {
+                    texts.extend(list_lines("Never played Sound Effects", &never_played, 25));
+                }
+
+                texts.into_iter().map(|text| {
+                    MessageActions::Send::single_private(&self.message, text) as Box<ActionHandler>
+
+                }).collect()
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [TopStats]")
+    }
+}
+
@@ -0,0 +1,58 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::{EventQueue, Message};
+use ::action::{ActionHandler, ActionGroup, MessageActions};
+
+
+// Action Implementation ------------------------------------------------------
+pub struct Action {
+    message: Message,
+    name: String,
+    tag: String
+}
+
+impl Action {
+    pub fn new(message: Message, name: String, tag: String) -> Box<Action> {
+        Box::new(Action {
+            message: message,
+            name: name,
+            tag: tag
+        })
+    }
+}
+
+impl ActionHandler for Action {
+    fn run(&mut self, bot: &mut Bot, _: &BotConfig, _: &mut EventQueue) -> ActionGroup {
+
+        if let Some(server) = bot.get_server(&self.message.server_id) {
+            if let Err(err) = server.add_effect_tag(&self.name, &self.tag) {
+                warn!("{} Failed: {}", self, err);
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("Failed to tag `{}` with `#{}`.", self.name, self.tag)
+                )
+
+            } else {
+                MessageActions::Send::private(
+                    &self.message,
+                    format!("`{}` is now tagged with `#{}` on {}.", self.name, self.tag, server.name)
+                )
+            }
+
+        } else {
+            vec![]
+        }
+
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[Action] [AddTag] #{} for \"{}\"", self.tag, self.name)
+    }
+}
+
@@ -0,0 +1,8 @@
+// Modules --------------------------------------------------------------------
+mod add;
+mod remove;
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::add::Action as Add;
+pub use self::remove::Action as Remove;
@@ -1,9 +1,22 @@
+// External Dependencies ------------------------------------------------------
+use chrono;
+
+
 // Modules --------------------------------------------------------------------
 mod delayed;
 mod periodic;
+mod scheduled;
 
 
 // Re-Exports -----------------------------------------------------------------
 pub use self::delayed::Action as Delayed;
 pub use self::periodic::Action as Periodic;
+pub use self::scheduled::Action as Scheduled;
+
+
+// Helpers --------------------------------------------------------------------
+pub fn timestamp_ms() -> i64 {
+    let now = chrono::UTC::now();
+    now.timestamp() * 1000 + now.timestamp_subsec_millis() as i64
+}
 
@@ -0,0 +1,208 @@
+// STD Dependencies -----------------------------------------------------------
+use std::cmp;
+use std::fmt;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::{ServerId, UserId};
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::bot::{Bot, BotConfig};
+use ::core::EventQueue;
+use ::action::{ActionHandler, ActionGroup, TimedActions};
+use super::timestamp_ms;
+
+
+// Statics --------------------------------------------------------------------
+static SERVER_RETRY_MILLIS: u64 = 1000;
+
+
+// Scheduled Action Implementation --------------------------------------------
+pub struct Action {
+    server_id: ServerId,
+    id: Option<i32>,
+    stored: bool,
+    due_at: i64,
+    interval_millis: u64,
+    owner_id: UserId,
+    label: String,
+    action: Option<Box<ActionHandler>>
+}
+
+
+impl Action {
+
+    pub fn new(
+        server_id: ServerId,
+        delay_millis: u64,
+        interval_millis: u64,
+        owner_id: UserId,
+        label: String,
+        action: Box<ActionHandler>
+
+    ) -> Box<Action> {
+        Action::at(
+            server_id,
+            timestamp_ms().saturating_add(cmp::min(delay_millis, i64::max_value() as u64) as i64),
+            interval_millis,
+            owner_id,
+            label,
+            action
+        )
+    }
+
+    pub fn at(
+        server_id: ServerId,
+        due_at: i64,
+        interval_millis: u64,
+        owner_id: UserId,
+        label: String,
+        action: Box<ActionHandler>
+
+    ) -> Box<Action> {
+        Box::new(Action {
+            server_id: server_id,
+            id: None,
+            stored: false,
+            due_at: due_at,
+            interval_millis: interval_millis,
+            owner_id: owner_id,
+            label: label,
+            action: Some(action)
+        })
+    }
+
+    pub fn restore(
+        server_id: ServerId,
+        id: i32,
+        due_at: i64,
+        interval_millis: u64,
+        owner_id: UserId,
+        label: String,
+        action: Box<ActionHandler>
+
+    ) -> Box<Action> {
+        Box::new(Action {
+            server_id: server_id,
+            id: Some(id),
+            stored: true,
+            due_at: due_at,
+            interval_millis: interval_millis,
+            owner_id: owner_id,
+            label: label,
+            action: Some(action)
+        })
+    }
+
+    fn pending(&mut self) -> Box<ActionHandler> {
+        Box::new(Action {
+            server_id: self.server_id,
+            id: self.id,
+            stored: self.stored,
+            due_at: self.due_at,
+            interval_millis: self.interval_millis,
+            owner_id: self.owner_id,
+            label: self.label.clone(),
+            action: self.action.take()
+        })
+    }
+
+}
+
+impl ActionHandler for Action {
+
+    fn ready(&self) -> bool {
+        !self.stored || timestamp_ms() >= self.due_at
+    }
+
+    fn run(&mut self, bot: &mut Bot, bot_config: &BotConfig, queue: &mut EventQueue) -> ActionGroup {
+
+        let (server_id, due_at, interval_millis) = (self.server_id, self.due_at, self.interval_millis);
+        let is_due = if let Some(server) = bot.get_server(&server_id) {
+
+            // Store newly scheduled actions so they survive restarts
+            if !self.stored {
+                self.stored = true;
+                if let Some((kind, payload)) = self.action.as_ref().and_then(|a| a.persist()) {
+                    self.id = server.add_scheduled_action(
+                        due_at,
+                        interval_millis,
+                        &self.owner_id,
+                        &self.label,
+                        kind,
+                        payload.to_string()
+                    );
+
+                } else {
+                    warn!("{} Action cannot be persisted", self);
+                }
+                None
+
+            // Actions removed from the store have been canceled
+            } else if self.id.map_or(false, |id| !server.has_scheduled_action(id)) {
+                info!("{} Canceled", self);
+                Some(false)
+
+            } else if interval_millis > 0 {
+                let now = timestamp_ms();
+                while self.due_at <= now {
+                    self.due_at += interval_millis as i64;
+                }
+                if let Some(id) = self.id {
+                    server.update_scheduled_action(id, self.due_at);
+                }
+                Some(true)
+
+            } else {
+                if let Some(id) = self.id {
+                    server.remove_scheduled_action(id);
+                }
+                Some(true)
+            }
+
+        // Wait for the server to become available
+        } else {
+            None
+        };
+
+        match is_due {
+            Some(true) => if interval_millis > 0 {
+                let mut actions = if let Some(action) = self.action.as_mut() {
+                    action.run(bot, bot_config, queue)
+
+                } else {
+                    vec![]
+                };
+                actions.push(self.pending());
+                actions
+
+            } else if let Some(action) = self.action.take() {
+                vec![action]
+
+            } else {
+                vec![]
+            },
+            Some(false) => vec![],
+            None => if self.stored {
+                vec![self.pending()]
+
+            } else {
+                vec![TimedActions::Delayed::new(SERVER_RETRY_MILLIS, self.pending())]
+            }
+        }
+
+    }
+
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref action) = self.action {
+            write!(f, "[ScheduledAction #{}] {}", self.id.unwrap_or(-1), action)
+
+        } else {
+            write!(f, "[ScheduledAction #{}]", self.id.unwrap_or(-1))
+        }
+    }
+}
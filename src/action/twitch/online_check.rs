@@ -4,6 +4,8 @@ use std::fmt;
 
 // External Dependencies ------------------------------------------------------
 use chrono;
+use serde_json;
+use serde_json::Value;
 use rayon::iter::{ParallelIterator, IntoParallelIterator};
 
 
@@ -18,17 +20,34 @@ use ::action::{ActionHandler, ActionGroup, MessageActions};
 use ::db::models::Streamer;
 
 
+// Persisted Payload ----------------------------------------------------------
+#[derive(Serialize, Deserialize)]
+struct Payload {
+    server_id: u64
+}
+
+
 // Stream Online Check Implementation -----------------------------------------
 pub struct Action {
     server_id: ServerId
 }
 
 impl Action {
+
+    pub const KIND: &'static str = "twitch_online_check";
+
     pub fn new(server_id: ServerId) -> Box<Action> {
         Box::new(Action {
             server_id: server_id
         })
     }
+
+    pub fn restore(payload: Value) -> Option<Box<ActionHandler>> {
+        serde_json::from_value::<Payload>(payload).ok().map(|payload| {
+            Action::new(ServerId(payload.server_id)) as Box<ActionHandler>
+        })
+    }
+
 }
 
 impl ActionHandler for Action {
@@ -62,6 +81,13 @@ impl ActionHandler for Action {
         }
     }
 
+    fn persist(&self) -> Option<(&'static str, Value)> {
+        serde_json::to_value(Payload {
+            server_id: self.server_id.0
+
+        }).ok().map(|payload| (Action::KIND, payload))
+    }
+
 }
 
 impl fmt::Display for Action {
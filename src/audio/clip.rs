@@ -0,0 +1,237 @@
+// STD Dependencies -----------------------------------------------------------
+use std::sync::{Arc, Mutex};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::UserId;
+use discord::voice::AudioReceiver;
+
+
+// External Dependencies ------------------------------------------------------
+use clock_ticks;
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::mixer::Compressor;
+use super::timestamp::TimestampExtender;
+
+
+// Statics --------------------------------------------------------------------
+static SAMPLES_PER_MILLI: i64 = 48;
+static RESYNC_MILLIS: i64 = 1000;
+static DUPLICATE_PACKETS: usize = 32;
+
+
+// Clip Buffer State ----------------------------------------------------------
+struct ClipPacket {
+    received: u64,
+
+    // Start of the packet in samples on the local clock
+    position: i64,
+    samples: Vec<i16>
+}
+
+struct ClipStream {
+    timestamps: TimestampExtender,
+    origin: i64,
+    packets: VecDeque<ClipPacket>
+}
+
+impl ClipStream {
+
+    fn new() -> ClipStream {
+        ClipStream {
+            timestamps: TimestampExtender::new(0),
+            origin: 0,
+            packets: VecDeque::new()
+        }
+    }
+
+    fn position(&mut self, timestamp: u32, now: u64) -> i64 {
+
+        let extended = self.timestamps.extend(timestamp);
+
+        // Packets are placed by their timestamp relative to the first one,
+        // the mapping onto the local clock is renewed after longer pauses or
+        // once the remote clock drifted away
+        let local = now as i64 * SAMPLES_PER_MILLI;
+        let position = extended + self.origin;
+        if self.packets.is_empty() || (position - local).abs() > RESYNC_MILLIS * SAMPLES_PER_MILLI {
+            self.origin = local - extended;
+            local
+
+        } else {
+            position
+        }
+
+    }
+
+}
+
+struct ClipState {
+    duration_ms: u64,
+    users: HashMap<u32, UserId>,
+    streams: HashMap<UserId, ClipStream>
+}
+
+
+// Voice Clip Ring Buffer -----------------------------------------------------
+//
+// Keeps the most recent mono voice packets of every member in memory. The
+// buffer is shared between the receiver on the voice thread and the server,
+// so it survives receivers being swapped when recordings start or stop.
+#[derive(Clone)]
+pub struct ClipBuffer {
+    state: Arc<Mutex<ClipState>>
+}
+
+impl ClipBuffer {
+
+    pub fn new(duration_ms: u64) -> ClipBuffer {
+        ClipBuffer {
+            state: Arc::new(Mutex::new(ClipState {
+                duration_ms: duration_ms,
+                users: HashMap::new(),
+                streams: HashMap::new()
+            }))
+        }
+    }
+
+    pub fn receiver(&self, inner: Option<Box<AudioReceiver>>) -> ClipReceiver {
+        ClipReceiver {
+            buffer: self.clone(),
+            inner: inner
+        }
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        self.state.lock().map(|state| state.duration_ms).unwrap_or(0)
+    }
+
+    pub fn capture(&self, duration_ms: u64, excluded: &HashSet<UserId>) -> Option<Vec<i16>> {
+
+        let state = match self.state.lock() {
+            Ok(state) => state,
+            Err(_) => return None
+        };
+
+        let start = clock_ticks::precise_time_ms().saturating_sub(duration_ms) as i64 * SAMPLES_PER_MILLI;
+        let packets: Vec<&ClipPacket> = state.streams.iter().filter(|&(user_id, _)| {
+            !excluded.contains(user_id)
+
+        }).flat_map(|(_, stream)| stream.packets.iter()).filter(|packet| {
+            packet.position >= start
+
+        }).collect();
+
+        // Only keep the part of the window which actually contains speech
+        let first = match packets.iter().map(|p| p.position).min() {
+            Some(first) => first,
+            None => return None
+        };
+
+        let last = packets.iter().map(|p| p.position + p.samples.len() as i64).max().unwrap_or(first);
+
+        let mut mixed = vec![0.0f32; (last - first) as usize];
+        let max_sample_value = f32::from(i16::max_value());
+        for packet in packets {
+            let offset = (packet.position - first) as usize;
+            for (target, sample) in mixed.iter_mut().skip(offset).zip(packet.samples.iter()) {
+                *target += f32::from(*sample) / max_sample_value;
+            }
+        }
+
+        let compressor = Compressor::new(0.6);
+        Some(mixed.into_iter().map(|s| {
+            (compressor.apply(s).max(-1.0).min(1.0) * max_sample_value) as i16
+
+        }).collect())
+
+    }
+
+    fn set_user(&self, source_id: u32, user_id: UserId) {
+        if let Ok(mut state) = self.state.lock() {
+            state.users.insert(source_id, user_id);
+        }
+    }
+
+    fn add_packet(&self, source_id: u32, timestamp: u32, stereo: bool, data: &[i16]) {
+        if let Ok(mut state) = self.state.lock() {
+
+            let user_id = match state.users.get(&source_id) {
+                Some(user_id) => *user_id,
+                None => return
+            };
+
+            let samples: Vec<i16> = if stereo {
+                data.chunks(2).map(|frame| {
+                    ((i32::from(frame[0]) + i32::from(frame[frame.len() - 1])) / 2) as i16
+
+                }).collect()
+
+            } else {
+                data.to_vec()
+            };
+
+            let now = clock_ticks::precise_time_ms();
+            let expired = now.saturating_sub(state.duration_ms);
+
+            // Drop everything which has fallen out of the window
+            for stream in state.streams.values_mut() {
+                while stream.packets.front().map_or(false, |packet| packet.received < expired) {
+                    stream.packets.pop_front();
+                }
+            }
+
+            let stream = state.streams.entry(user_id).or_insert_with(ClipStream::new);
+            let position = stream.position(timestamp, now);
+
+            // Duplicated packets would otherwise be mixed in twice
+            if stream.packets.iter().rev().take(DUPLICATE_PACKETS).any(|packet| packet.position == position) {
+                return;
+            }
+
+            stream.packets.push_back(ClipPacket {
+                received: now,
+                position: position,
+                samples: samples
+            });
+
+        }
+    }
+
+}
+
+
+// Receiver Implementation ----------------------------------------------------
+pub struct ClipReceiver {
+    buffer: ClipBuffer,
+    inner: Option<Box<AudioReceiver>>
+}
+
+impl AudioReceiver for ClipReceiver {
+
+    fn speaking_update(&mut self, source_id: u32, user_id: UserId, speaking: bool) {
+        self.buffer.set_user(source_id, user_id);
+        if let Some(inner) = self.inner.as_mut() {
+            inner.speaking_update(source_id, user_id, speaking);
+        }
+    }
+
+    fn voice_packet(
+        &mut self,
+        source_id: u32,
+        sequence: u16,
+        timestamp: u32,
+        stereo: bool,
+        data: &[i16]
+    ) {
+        self.buffer.add_packet(source_id, timestamp, stereo, data);
+        if let Some(inner) = self.inner.as_mut() {
+            inner.voice_packet(source_id, sequence, timestamp, stereo, data);
+        }
+    }
+
+}
+
@@ -0,0 +1,73 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fs::File;
+use std::path::Path;
+
+
+// External Dependencies ------------------------------------------------------
+use flac::{ByteStream, ReadStream, Stream, StreamIter, StreamReader};
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::{AudioFormat, AudioInfo, Decoder as DecoderTrait};
+
+
+// FLAC Decoder ---------------------------------------------------------------
+pub struct Decoder {
+    info: AudioInfo,
+    samples: StreamIter<ReadStream<File>, i64>
+}
+
+impl Decoder {
+
+    pub fn open(path: &Path) -> Result<Decoder, String> {
+        StreamReader::<File>::from_file(path.to_str().unwrap_or("")).map_err(|err| {
+            format!("Failed to open FLAC file: {:?}", err)
+
+        }).map(|stream| {
+            let info = stream.info();
+            Decoder {
+                info: AudioInfo {
+                    format: AudioFormat::Flac,
+                    sample_rate: info.sample_rate,
+                    channels: info.channels,
+                    bits_per_sample: info.bits_per_sample,
+                    total_samples: info.total_samples
+                },
+                samples: StreamIter::new(stream)
+            }
+        })
+    }
+
+}
+
+impl Iterator for Decoder {
+    type Item = i64;
+    fn next(&mut self) -> Option<i64> {
+        self.samples.next()
+    }
+}
+
+impl DecoderTrait for Decoder {
+    fn info(&self) -> AudioInfo {
+        self.info
+    }
+}
+
+
+// Helpers --------------------------------------------------------------------
+pub fn probe(header: &[u8]) -> Result<AudioInfo, String> {
+    Stream::<ByteStream>::from_buffer(header).map_err(|err| {
+        format!("Failed to parse FLAC header: {:?}", err)
+
+    }).map(|stream| {
+        let info = stream.info();
+        AudioInfo {
+            format: AudioFormat::Flac,
+            sample_rate: info.sample_rate,
+            channels: info.channels,
+            bits_per_sample: info.bits_per_sample,
+            total_samples: info.total_samples
+        }
+    })
+}
+
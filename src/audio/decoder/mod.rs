@@ -0,0 +1,109 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+use std::path::Path;
+
+
+// Modules --------------------------------------------------------------------
+mod flac;
+mod ogg;
+mod opus;
+mod vorbis;
+mod wav;
+
+
+// Audio Formats --------------------------------------------------------------
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AudioFormat {
+    Flac,
+    Vorbis,
+    Opus,
+    Wav
+}
+
+impl AudioFormat {
+
+    pub fn from_extension(ext: &str) -> Option<AudioFormat> {
+        match ext {
+            "flac" => Some(AudioFormat::Flac),
+            "ogg" | "oga" => Some(AudioFormat::Vorbis),
+            "opus" => Some(AudioFormat::Opus),
+            "wav" => Some(AudioFormat::Wav),
+            _ => None
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            AudioFormat::Flac => "flac",
+            AudioFormat::Vorbis => "ogg",
+            AudioFormat::Opus => "opus",
+            AudioFormat::Wav => "wav"
+        }
+    }
+
+}
+
+impl fmt::Display for AudioFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match *self {
+            AudioFormat::Flac => "FLAC",
+            AudioFormat::Vorbis => "Ogg Vorbis",
+            AudioFormat::Opus => "Ogg Opus",
+            AudioFormat::Wav => "WAV"
+        })
+    }
+}
+
+
+// Audio Stream Information ---------------------------------------------------
+#[derive(Debug, Copy, Clone)]
+pub struct AudioInfo {
+    pub format: AudioFormat,
+    pub sample_rate: u32,
+    pub channels: u8,
+    pub bits_per_sample: u8,
+
+    // Number of samples per channel, zero if the length is unknown
+    pub total_samples: u64
+}
+
+
+// Decoder Abstraction --------------------------------------------------------
+pub trait Decoder: Iterator<Item = i64> + Send {
+    fn info(&self) -> AudioInfo;
+}
+
+pub fn open(path: &Path, format: AudioFormat) -> Result<Box<Decoder>, String> {
+    match format {
+        AudioFormat::Flac => flac::Decoder::open(path).map(|d| Box::new(d) as Box<Decoder>),
+        AudioFormat::Vorbis => vorbis::Decoder::open(path).map(|d| Box::new(d) as Box<Decoder>),
+        AudioFormat::Opus => opus::Decoder::open(path).map(|d| Box::new(d) as Box<Decoder>),
+        AudioFormat::Wav => wav::Decoder::open(path).map(|d| Box::new(d) as Box<Decoder>)
+    }
+}
+
+pub fn probe(header: &[u8]) -> Result<AudioInfo, String> {
+    if header.starts_with(b"fLaC") {
+        flac::probe(header)
+
+    } else if header.starts_with(b"RIFF") {
+        wav::probe(header)
+
+    } else if header.starts_with(b"OggS") {
+        ogg::first_packet(header).and_then(|packet| {
+            if packet.starts_with(b"\x01vorbis") {
+                vorbis::probe(packet)
+
+            } else if packet.starts_with(b"OpusHead") {
+                opus::probe(packet)
+
+            } else {
+                Err("Unsupported Ogg stream.".to_string())
+            }
+        })
+
+    } else {
+        Err("Unknown audio format.".to_string())
+    }
+}
+
@@ -0,0 +1,39 @@
+// Ogg Container Helpers ------------------------------------------------------
+pub fn first_packet(header: &[u8]) -> Result<&[u8], String> {
+
+    // Page header is followed by a segment table describing the packet sizes
+    if header.len() < 27 {
+        return Err("Ogg page header is incomplete.".to_string());
+    }
+
+    let segments = header[26] as usize;
+    let offset = 27 + segments;
+    if header.len() < offset {
+        return Err("Ogg segment table is incomplete.".to_string());
+    }
+
+    let mut length = 0;
+    for size in &header[27..offset] {
+        length += *size as usize;
+        if *size < 255 {
+            break;
+        }
+    }
+
+    if header.len() < offset + length {
+        Err("Ogg packet is incomplete.".to_string())
+
+    } else {
+        Ok(&header[offset..offset + length])
+    }
+
+}
+
+pub fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from(bytes[offset]) | u16::from(bytes[offset + 1]) << 8
+}
+
+pub fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from(read_u16(bytes, offset)) | u32::from(read_u16(bytes, offset + 2)) << 16
+}
+
@@ -0,0 +1,127 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fs::File;
+use std::path::Path;
+use std::collections::VecDeque;
+
+
+// External Dependencies ------------------------------------------------------
+use ogg::PacketReader;
+use opus::{Channels, Decoder as OpusDecoder};
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::{AudioFormat, AudioInfo, Decoder as DecoderTrait};
+use super::ogg::read_u16;
+
+
+// Statics --------------------------------------------------------------------
+static OPUS_SAMPLE_RATE: u32 = 48000;
+static MAX_FRAME_SAMPLES: usize = 5760;
+
+
+// Ogg Opus Decoder -----------------------------------------------------------
+pub struct Decoder {
+    info: AudioInfo,
+    reader: PacketReader<File>,
+    decoder: OpusDecoder,
+    pre_skip: usize,
+    frame: Vec<i16>,
+    buffer: VecDeque<i16>
+}
+
+impl Decoder {
+
+    pub fn open(path: &Path) -> Result<Decoder, String> {
+
+        let mut reader = PacketReader::new(try!(File::open(path).map_err(|err| {
+            err.to_string()
+        })));
+
+        // The first packet is the identification header, the second one
+        // contains comments which we do not need
+        let head = try!(reader.read_packet().map_err(|err| {
+            format!("Failed to read Opus header: {:?}", err)
+        }));
+
+        let info = try!(probe(&head.data));
+        try!(reader.read_packet().map_err(|err| {
+            format!("Failed to read Opus tags: {:?}", err)
+        }));
+
+        let decoder = try!(OpusDecoder::new(OPUS_SAMPLE_RATE, if info.channels == 1 {
+            Channels::Mono
+
+        } else {
+            Channels::Stereo
+
+        }).map_err(|err| {
+            format!("Failed to create Opus decoder: {:?}", err)
+        }));
+
+        Ok(Decoder {
+            info: info,
+            reader: reader,
+            decoder: decoder,
+            pre_skip: read_u16(&head.data, 10) as usize * info.channels as usize,
+            frame: vec![0; MAX_FRAME_SAMPLES * info.channels as usize],
+            buffer: VecDeque::new()
+        })
+
+    }
+
+}
+
+impl Iterator for Decoder {
+    type Item = i64;
+    fn next(&mut self) -> Option<i64> {
+
+        while self.buffer.is_empty() {
+
+            let packet = match self.reader.read_packet() {
+                Ok(packet) => packet,
+                Err(_) => return None
+            };
+
+            let decoded = match self.decoder.decode(&packet.data, &mut self.frame[..], false) {
+                Ok(decoded) => decoded * self.info.channels as usize,
+                Err(_) => return None
+            };
+
+            // Drop the encoder delay at the start of the stream
+            let skip = ::std::cmp::min(self.pre_skip, decoded);
+            self.pre_skip -= skip;
+            self.buffer.extend(self.frame[skip..decoded].iter().cloned());
+
+        }
+
+        self.buffer.pop_front().map(i64::from)
+
+    }
+}
+
+impl DecoderTrait for Decoder {
+    fn info(&self) -> AudioInfo {
+        self.info
+    }
+}
+
+
+// Helpers --------------------------------------------------------------------
+pub fn probe(packet: &[u8]) -> Result<AudioInfo, String> {
+    if packet.len() < 19 {
+        Err("Opus identification header is incomplete.".to_string())
+
+    } else if packet[9] == 0 || packet[9] > 2 {
+        Err("Only mono and stereo Opus streams are supported.".to_string())
+
+    } else {
+        Ok(AudioInfo {
+            format: AudioFormat::Opus,
+            sample_rate: OPUS_SAMPLE_RATE,
+            channels: packet[9],
+            bits_per_sample: 16,
+            total_samples: 0
+        })
+    }
+}
+
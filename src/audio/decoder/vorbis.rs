@@ -0,0 +1,86 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fs::File;
+use std::path::Path;
+use std::collections::VecDeque;
+
+
+// External Dependencies ------------------------------------------------------
+use lewton::inside_ogg::OggStreamReader;
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::{AudioFormat, AudioInfo, Decoder as DecoderTrait};
+use super::ogg::read_u32;
+
+
+// Ogg Vorbis Decoder ---------------------------------------------------------
+pub struct Decoder {
+    info: AudioInfo,
+    reader: OggStreamReader<File>,
+    buffer: VecDeque<i16>
+}
+
+impl Decoder {
+
+    pub fn open(path: &Path) -> Result<Decoder, String> {
+        File::open(path).map_err(|err| {
+            err.to_string()
+
+        }).and_then(|file| {
+            OggStreamReader::new(file).map_err(|err| {
+                format!("Failed to open Ogg Vorbis file: {:?}", err)
+            })
+
+        }).map(|reader| {
+            Decoder {
+                info: AudioInfo {
+                    format: AudioFormat::Vorbis,
+                    sample_rate: reader.ident_hdr.audio_sample_rate,
+                    channels: reader.ident_hdr.audio_channels,
+                    bits_per_sample: 16,
+                    total_samples: 0
+                },
+                reader: reader,
+                buffer: VecDeque::new()
+            }
+        })
+    }
+
+}
+
+impl Iterator for Decoder {
+    type Item = i64;
+    fn next(&mut self) -> Option<i64> {
+        while self.buffer.is_empty() {
+            match self.reader.read_dec_packet_itl() {
+                Ok(Some(samples)) => self.buffer.extend(samples),
+                _ => return None
+            }
+        }
+        self.buffer.pop_front().map(i64::from)
+    }
+}
+
+impl DecoderTrait for Decoder {
+    fn info(&self) -> AudioInfo {
+        self.info
+    }
+}
+
+
+// Helpers --------------------------------------------------------------------
+pub fn probe(packet: &[u8]) -> Result<AudioInfo, String> {
+    if packet.len() < 16 {
+        Err("Vorbis identification header is incomplete.".to_string())
+
+    } else {
+        Ok(AudioInfo {
+            format: AudioFormat::Vorbis,
+            sample_rate: read_u32(packet, 12),
+            channels: packet[11],
+            bits_per_sample: 16,
+            total_samples: 0
+        })
+    }
+}
+
@@ -0,0 +1,105 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fs::File;
+use std::path::Path;
+use std::io::BufReader;
+
+
+// External Dependencies ------------------------------------------------------
+use hound::{SampleFormat, WavIntoSamples, WavReader};
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::{AudioFormat, AudioInfo, Decoder as DecoderTrait};
+use super::ogg::{read_u16, read_u32};
+
+
+// WAV Decoder ----------------------------------------------------------------
+pub struct Decoder {
+    info: AudioInfo,
+    samples: WavIntoSamples<BufReader<File>, i32>
+}
+
+impl Decoder {
+
+    pub fn open(path: &Path) -> Result<Decoder, String> {
+        WavReader::open(path).map_err(|err| {
+            format!("Failed to open WAV file: {}", err)
+
+        }).and_then(|reader| {
+            let spec = reader.spec();
+            if spec.sample_format != SampleFormat::Int {
+                Err("Floating point WAV files are not supported.".to_string())
+
+            } else {
+                Ok(Decoder {
+                    info: AudioInfo {
+                        format: AudioFormat::Wav,
+                        sample_rate: spec.sample_rate,
+                        channels: spec.channels as u8,
+                        bits_per_sample: spec.bits_per_sample as u8,
+                        total_samples: u64::from(reader.duration())
+                    },
+                    samples: reader.into_samples()
+                })
+            }
+        })
+    }
+
+}
+
+impl Iterator for Decoder {
+    type Item = i64;
+    fn next(&mut self) -> Option<i64> {
+        // Decoding errors end the stream instead of producing noise
+        match self.samples.next() {
+            Some(Ok(sample)) => Some(i64::from(sample)),
+            _ => None
+        }
+    }
+}
+
+impl DecoderTrait for Decoder {
+    fn info(&self) -> AudioInfo {
+        self.info
+    }
+}
+
+
+// Helpers --------------------------------------------------------------------
+pub fn probe(header: &[u8]) -> Result<AudioInfo, String> {
+
+    if header.len() < 12 || &header[8..12] != b"WAVE" {
+        return Err("Not a WAVE file.".to_string());
+    }
+
+    // Walk the RIFF chunks until we find the format description
+    let mut offset = 12;
+    while offset + 8 <= header.len() {
+
+        let size = read_u32(header, offset + 4) as usize;
+        if &header[offset..offset + 4] == b"fmt " {
+            return if offset + 24 > header.len() {
+                Err("WAV format chunk is incomplete.".to_string())
+
+            } else if read_u16(header, offset + 8) != 1 {
+                Err("Only PCM WAV files are supported.".to_string())
+
+            } else {
+                Ok(AudioInfo {
+                    format: AudioFormat::Wav,
+                    sample_rate: read_u32(header, offset + 12),
+                    channels: read_u16(header, offset + 10) as u8,
+                    bits_per_sample: read_u16(header, offset + 22) as u8,
+                    total_samples: 0
+                })
+            };
+        }
+
+        offset += 8 + size + size % 2;
+
+    }
+
+    Err("WAV format chunk not found.".to_string())
+
+}
+
@@ -0,0 +1,110 @@
+// STD Dependencies -----------------------------------------------------------
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::UserId;
+use discord::voice::AudioReceiver;
+
+
+// External Dependencies ------------------------------------------------------
+use clock_ticks;
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::MixerCommand;
+
+
+// Statics --------------------------------------------------------------------
+static SPEAKING_TIMEOUT_MILLIS: u64 = 1000;
+static DUCK_REFRESH_MILLIS: u64 = 250;
+
+
+// Voice Activity Ducking -----------------------------------------------------
+//
+// Tracks which members are currently speaking and lowers the level of the
+// mixer while any of them are. Any other receiver, e.g. a Recorder, can be
+// wrapped so both can be attached to the same voice connection.
+//
+// Members who disconnect while speaking never send a final speaking update, so
+// sources expire once their packets stop and the mixer releases the ducking
+// on its own unless it keeps being refreshed.
+pub struct Ducker {
+    gain: f32,
+    speaking: HashMap<u32, u64>,
+    refreshed: u64,
+    mixer_commands: Sender<MixerCommand>,
+    inner: Option<Box<AudioReceiver>>
+}
+
+impl Ducker {
+
+    pub fn new(
+        level_db: f32,
+        mixer_commands: Sender<MixerCommand>,
+        inner: Option<Box<AudioReceiver>>
+
+    ) -> Ducker {
+        Ducker {
+            gain: 10.0f32.powf(level_db.min(0.0) / 20.0),
+            speaking: HashMap::new(),
+            refreshed: 0,
+            mixer_commands: mixer_commands,
+            inner: inner
+        }
+    }
+
+    fn set_speaking(&mut self, source_id: u32, speaking: bool) {
+
+        let now = clock_ticks::precise_time_ms();
+        let was_ducked = !self.speaking.is_empty();
+        if speaking {
+            self.speaking.insert(source_id, now);
+
+        } else {
+            self.speaking.remove(&source_id);
+        }
+
+        self.speaking.retain(|_, active| now.saturating_sub(*active) < SPEAKING_TIMEOUT_MILLIS);
+
+        let ducked = !self.speaking.is_empty();
+        if ducked && (!was_ducked || now.saturating_sub(self.refreshed) >= DUCK_REFRESH_MILLIS) {
+            self.refreshed = now;
+            self.mixer_commands.send(MixerCommand::Duck(self.gain)).ok();
+
+        } else if !ducked && was_ducked {
+            self.mixer_commands.send(MixerCommand::Duck(1.0)).ok();
+        }
+
+    }
+
+}
+
+
+// Receiver Implementation ----------------------------------------------------
+impl AudioReceiver for Ducker {
+
+    fn speaking_update(&mut self, source_id: u32, user_id: UserId, speaking: bool) {
+        self.set_speaking(source_id, speaking);
+        if let Some(inner) = self.inner.as_mut() {
+            inner.speaking_update(source_id, user_id, speaking);
+        }
+    }
+
+    fn voice_packet(
+        &mut self,
+        source_id: u32,
+        sequence: u16,
+        timestamp: u32,
+        stereo: bool,
+        data: &[i16]
+    ) {
+        self.set_speaking(source_id, true);
+        if let Some(inner) = self.inner.as_mut() {
+            inner.voice_packet(source_id, sequence, timestamp, stereo, data);
+        }
+    }
+
+}
+
@@ -0,0 +1,467 @@
+// STD Dependencies -----------------------------------------------------------
+use std::cmp;
+use std::fs::File;
+use std::path::Path;
+use std::io::{Seek, SeekFrom, Write};
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::Encoder;
+
+
+// Statics --------------------------------------------------------------------
+static BLOCK_SIZE: usize = 4096;
+static BITS_PER_SAMPLE: u32 = 16;
+static MAX_FIXED_ORDER: usize = 4;
+static MAX_RICE_PARAMETER: u32 = 14;
+
+
+// FLAC Stream Encoder --------------------------------------------------------
+pub struct FlacEncoder {
+    file: File,
+    channels: usize,
+    sample_rate: u32,
+    buffer: Vec<i16>,
+    frame_number: u32,
+    total_samples: u64
+}
+
+impl FlacEncoder {
+
+    pub fn create(path: &Path, channels: usize, sample_rate: u32) -> Result<FlacEncoder, String> {
+
+        let file = try!(File::create(path).map_err(|err| err.to_string()));
+        let mut encoder = FlacEncoder {
+            file: file,
+            channels: channels,
+            sample_rate: sample_rate,
+            buffer: Vec::with_capacity(BLOCK_SIZE * channels),
+            frame_number: 0,
+            total_samples: 0
+        };
+
+        try!(encoder.write_header());
+        Ok(encoder)
+
+    }
+
+    pub fn write_samples(&mut self, samples: &[i16]) -> Result<(), String> {
+
+        let block_samples = BLOCK_SIZE * self.channels;
+        for chunk in samples.chunks(block_samples) {
+
+            let available = cmp::min(block_samples - self.buffer.len(), chunk.len());
+            self.buffer.extend_from_slice(&chunk[..available]);
+            if self.buffer.len() == block_samples {
+                try!(self.write_frame());
+            }
+
+            self.buffer.extend_from_slice(&chunk[available..]);
+
+        }
+
+        Ok(())
+
+    }
+
+    pub fn finish(mut self) -> Result<(), String> {
+
+        if !self.buffer.is_empty() {
+            try!(self.write_frame());
+        }
+
+        // The total number of samples is only known once all frames are written
+        try!(self.file.seek(SeekFrom::Start(8)).map_err(|err| err.to_string()));
+        let info = self.stream_info();
+        self.file.write_all(&info).map_err(|err| err.to_string())
+
+    }
+
+}
+
+
+// Encoder Implementation -----------------------------------------------------
+impl Encoder for FlacEncoder {
+
+    fn write_samples(&mut self, samples: &[i16]) -> Result<(), String> {
+        FlacEncoder::write_samples(self, samples)
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        FlacEncoder::finish(*self)
+    }
+
+}
+
+
+// Internal Interface ---------------------------------------------------------
+impl FlacEncoder {
+
+    fn write_header(&mut self) -> Result<(), String> {
+
+        let mut header = Vec::with_capacity(42);
+        header.extend_from_slice(b"fLaC");
+
+        // Last metadata block of type STREAMINFO with 34 bytes
+        header.extend_from_slice(&[0x80, 0x00, 0x00, 34]);
+        header.extend_from_slice(&self.stream_info());
+
+        self.file.write_all(&header).map_err(|err| err.to_string())
+
+    }
+
+    fn stream_info(&self) -> Vec<u8> {
+
+        let mut writer = BitWriter::new();
+        writer.write(16, BLOCK_SIZE as u64);
+        writer.write(16, BLOCK_SIZE as u64);
+
+        // Unknown min / max frame sizes
+        writer.write(24, 0);
+        writer.write(24, 0);
+
+        writer.write(20, u64::from(self.sample_rate));
+        writer.write(3, self.channels as u64 - 1);
+        writer.write(5, u64::from(BITS_PER_SAMPLE) - 1);
+        writer.write(36, self.total_samples);
+
+        // No MD5 signature of the unencoded audio
+        writer.write(64, 0);
+        writer.write(64, 0);
+
+        writer.into_bytes()
+
+    }
+
+    fn write_frame(&mut self) -> Result<(), String> {
+
+        let block_size = self.buffer.len() / self.channels;
+        let mut writer = BitWriter::new();
+
+        // Frame header with a fixed blocking strategy and the block size
+        // stored as a 16 bit value at the end of the header
+        writer.write(16, 0xFFF8);
+        writer.write(4, 0b0111);
+        writer.write(4, 0b0000);
+        writer.write(4, self.channels as u64 - 1);
+        writer.write(3, 0b100);
+        writer.write(1, 0);
+        writer.write_utf8(self.frame_number);
+        writer.write(16, block_size as u64 - 1);
+
+        let crc = crc8(writer.bytes());
+        writer.write(8, u64::from(crc));
+
+        // Channels are encoded independently
+        for channel in 0..self.channels {
+            let samples: Vec<i32> = self.buffer.chunks(self.channels).map(|frame| {
+                i32::from(frame[channel])
+
+            }).collect();
+            write_subframe(&mut writer, &samples);
+        }
+
+        writer.align();
+
+        let crc = crc16(writer.bytes());
+        writer.write(16, u64::from(crc));
+
+        try!(self.file.write_all(writer.bytes()).map_err(|err| err.to_string()));
+
+        self.frame_number += 1;
+        self.total_samples += block_size as u64;
+        self.buffer.clear();
+
+        Ok(())
+
+    }
+
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn write_subframe(writer: &mut BitWriter, samples: &[i32]) {
+
+    // Pick the fixed predictor which produces the smallest residual
+    let order = (0..cmp::min(MAX_FIXED_ORDER, samples.len() - 1) + 1).min_by_key(|order| {
+        fixed_residual(samples, *order).iter().map(|r| i64::from(*r).abs()).sum::<i64>()
+
+    }).unwrap_or(0);
+
+    let residual = fixed_residual(samples, order);
+
+    // Subframe header, FIXED predictor without any wasted bits
+    writer.write(1, 0);
+    writer.write(6, 0b001_000 | order as u64);
+    writer.write(1, 0);
+
+    for sample in &samples[..order] {
+        writer.write_signed(BITS_PER_SAMPLE, i64::from(*sample));
+    }
+
+    // Residual with a single rice coded partition
+    let parameter = rice_parameter(&residual);
+    writer.write(2, 0b00);
+    writer.write(4, 0);
+    writer.write(4, u64::from(parameter));
+
+    for r in residual {
+        let folded = ((r << 1) ^ (r >> 31)) as u32;
+        writer.write_unary(folded >> parameter);
+        writer.write(parameter, u64::from(folded) & ((1 << parameter) - 1));
+    }
+
+}
+
+fn fixed_residual(samples: &[i32], order: usize) -> Vec<i32> {
+    samples.iter().enumerate().skip(order).map(|(i, s)| {
+        let s = *s;
+        match order {
+            0 => s,
+            1 => s - samples[i - 1],
+            2 => s - 2 * samples[i - 1] + samples[i - 2],
+            3 => s - 3 * samples[i - 1] + 3 * samples[i - 2] - samples[i - 3],
+            _ => s - 4 * samples[i - 1] + 6 * samples[i - 2] - 4 * samples[i - 3] + samples[i - 4]
+        }
+
+    }).collect()
+}
+
+fn rice_parameter(residual: &[i32]) -> u32 {
+    if residual.is_empty() {
+        0
+
+    } else {
+        let sum: u64 = residual.iter().map(|r| ((r << 1) ^ (r >> 31)) as u32 as u64).sum();
+        let mean = sum / residual.len() as u64;
+        cmp::min(64 - mean.leading_zeros(), MAX_RICE_PARAMETER)
+    }
+}
+
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |mut crc, byte| {
+        crc ^= *byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+fn crc16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0u16, |mut crc, byte| {
+        crc ^= u16::from(*byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+
+// Bit Writer -----------------------------------------------------------------
+struct BitWriter {
+    bytes: Vec<u8>,
+    current: u8,
+    bits: u32
+}
+
+impl BitWriter {
+
+    fn new() -> BitWriter {
+        BitWriter {
+            bytes: Vec::new(),
+            current: 0,
+            bits: 0
+        }
+    }
+
+    fn write(&mut self, count: u32, value: u64) {
+        for i in (0..count).rev() {
+            self.current = (self.current << 1) | ((value >> i) & 1) as u8;
+            self.bits += 1;
+            if self.bits == 8 {
+                self.bytes.push(self.current);
+                self.current = 0;
+                self.bits = 0;
+            }
+        }
+    }
+
+    fn write_signed(&mut self, count: u32, value: i64) {
+        self.write(count, (value as u64) & ((1 << count) - 1));
+    }
+
+    fn write_unary(&mut self, zeros: u32) {
+        for _ in 0..zeros {
+            self.write(1, 0);
+        }
+        self.write(1, 1);
+    }
+
+    fn write_utf8(&mut self, value: u32) {
+        if value < 0x80 {
+            self.write(8, u64::from(value));
+
+        } else {
+
+            // Number of continuation bytes needed for the value
+            let extra = match value {
+                0x80...0x7FF => 1,
+                0x800...0xFFFF => 2,
+                0x1_0000...0x1F_FFFF => 3,
+                0x20_0000...0x3FF_FFFF => 4,
+                _ => 5
+            };
+
+            let marker = (0xFF00u32 >> (extra + 1)) & 0xFF;
+            self.write(8, u64::from(marker | (value >> (6 * extra))) & 0xFF);
+
+            for i in (0..extra).rev() {
+                self.write(8, u64::from(0x80 | ((value >> (6 * i)) & 0x3F)));
+            }
+
+        }
+    }
+
+    fn align(&mut self) {
+        while self.bits != 0 {
+            self.write(1, 0);
+        }
+    }
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+}
+
+
+
+// Tests ----------------------------------------------------------------------
+#[cfg(test)]
+mod test {
+
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use ::audio::{AudioFormat, open_decoder};
+    use super::{FlacEncoder, BLOCK_SIZE};
+
+    // Encodes the samples and decodes them again via the same library which
+    // is used for effect playback
+    fn round_trip(name: &str, channels: usize, samples: &[i16]) {
+
+        let path: PathBuf = env::temp_dir().join(format!("flac_encoder_test_{}.flac", name));
+
+        let mut encoder = FlacEncoder::create(&path, channels, 48_000).unwrap();
+
+        // Feed the encoder with uneven writes
+        for chunk in samples.chunks(1234 * channels) {
+            encoder.write_samples(chunk).unwrap();
+        }
+        encoder.finish().unwrap();
+
+        let decoded: Result<(Vec<i64>, u8, u64), String> = open_decoder(&path, AudioFormat::Flac).map(|stream| {
+            let info = stream.info();
+            assert_eq!(info.sample_rate, 48_000);
+            assert_eq!(info.bits_per_sample, 16);
+            (stream.collect(), info.channels, info.total_samples)
+        });
+
+        fs::remove_file(&path).ok();
+
+        let (decoded, decoded_channels, total_samples) = decoded.unwrap();
+        assert_eq!(decoded_channels as usize, channels);
+        assert_eq!(total_samples as usize, samples.len() / channels);
+        assert_eq!(decoded.len(), samples.len());
+
+        for (index, (a, b)) in samples.iter().zip(decoded.iter()).enumerate() {
+            assert_eq!(i64::from(*a), *b, "sample {} differs", index);
+        }
+
+    }
+
+    fn noise(count: usize, amplitude: i32) -> Vec<i16> {
+        let mut seed: u32 = 0x1234_5678;
+        (0..count).map(|_| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            ((seed >> 16) as i32 % (amplitude * 2 + 1) - amplitude) as i16
+
+        }).collect()
+    }
+
+    fn sine(count: usize, period: f32, amplitude: f32) -> Vec<i16> {
+        (0..count).map(|i| {
+            ((i as f32 * 2.0 * ::std::f32::consts::PI / period).sin() * amplitude) as i16
+
+        }).collect()
+    }
+
+    #[test]
+    fn test_mono() {
+        round_trip("mono", 1, &sine(BLOCK_SIZE * 3, 109.0, 12000.0));
+    }
+
+    #[test]
+    fn test_stereo() {
+        let left = sine(BLOCK_SIZE * 2 + 500, 80.0, 20000.0);
+        let right = noise(left.len(), 3000);
+        let samples: Vec<i16> = left.iter().zip(right.iter()).flat_map(|(l, r)| vec![*l, *r]).collect();
+        round_trip("stereo", 2, &samples);
+    }
+
+    #[test]
+    fn test_partial_last_block() {
+        round_trip("partial", 1, &sine(BLOCK_SIZE * 2 + 1808, 64.0, 8000.0));
+        round_trip("partial_short", 2, &noise((BLOCK_SIZE + 5) * 2, 500));
+        round_trip("single_block", 1, &sine(100, 30.0, 1000.0));
+    }
+
+    #[test]
+    fn test_multi_byte_frame_numbers() {
+        // Frame numbers of 128 and above are stored as multi byte UTF-8
+        round_trip("frame_numbers", 1, &noise(BLOCK_SIZE * 130 + 77, 64));
+    }
+
+    #[test]
+    fn test_full_scale() {
+
+        let square: Vec<i16> = (0..BLOCK_SIZE * 2 + 3).map(|i| {
+            if (i / 7) % 2 == 0 { i16::max_value() } else { i16::min_value() }
+
+        }).collect();
+        round_trip("square", 1, &square);
+
+        let alternating: Vec<i16> = (0..BLOCK_SIZE * 2).map(|i| {
+            if i % 2 == 0 { i16::max_value() } else { i16::min_value() }
+
+        }).collect();
+        round_trip("alternating", 2, &alternating);
+
+        round_trip("full_noise", 2, &noise(BLOCK_SIZE * 4, 32767));
+
+    }
+
+    #[test]
+    fn test_silence() {
+        round_trip("silence", 1, &vec![0; BLOCK_SIZE * 2 + 10]);
+        round_trip("silence_stereo", 2, &vec![0; BLOCK_SIZE * 2]);
+    }
+
+}
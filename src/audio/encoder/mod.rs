@@ -0,0 +1,67 @@
+// STD Dependencies -----------------------------------------------------------
+use std::path::Path;
+
+
+// Modules --------------------------------------------------------------------
+mod flac;
+mod opus;
+mod vorbis;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::audio::AudioFormat;
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::flac::FlacEncoder;
+use self::opus::OpusEncoder;
+use self::vorbis::VorbisEncoder;
+
+
+// Encoder Settings -----------------------------------------------------------
+#[derive(Debug, Copy, Clone)]
+pub struct EncoderSettings {
+    pub format: AudioFormat,
+    pub channels: usize,
+
+    // Between 0.0 and 1.0, ignored by lossless formats
+    pub quality: f32
+}
+
+impl Default for EncoderSettings {
+    fn default() -> EncoderSettings {
+        EncoderSettings {
+            format: AudioFormat::Vorbis,
+            channels: 1,
+            quality: 0.2
+        }
+    }
+}
+
+
+// Encoder Abstraction --------------------------------------------------------
+pub trait Encoder: Send {
+
+    // Samples are interleaved according to the encoder's channel count
+    fn write_samples(&mut self, samples: &[i16]) -> Result<(), String>;
+
+    fn finish(self: Box<Self>) -> Result<(), String>;
+
+}
+
+pub fn create(
+    path: &Path,
+    format: AudioFormat,
+    channels: usize,
+    quality: f32
+
+) -> Result<Box<Encoder>, String> {
+    let quality = quality.max(0.0).min(1.0);
+    match format {
+        AudioFormat::Flac => FlacEncoder::create(path, channels, 48_000).map(|e| Box::new(e) as Box<Encoder>),
+        AudioFormat::Vorbis => VorbisEncoder::create(path, channels, quality).map(|e| Box::new(e) as Box<Encoder>),
+        AudioFormat::Opus => OpusEncoder::create(path, channels, quality).map(|e| Box::new(e) as Box<Encoder>),
+        AudioFormat::Wav => Err("WAV is not supported for encoding.".to_string())
+    }
+}
+
@@ -0,0 +1,153 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fs::File;
+use std::path::Path;
+use std::io::{BufWriter, Write};
+
+
+// External Dependencies ------------------------------------------------------
+use ogg::{PacketWriter, PacketWriteEndInfo};
+use opus::{Application, Bitrate, Channels, Encoder as RawEncoder};
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::Encoder;
+
+
+// Statics --------------------------------------------------------------------
+static FRAME_SAMPLES: usize = 960;
+static MAX_PACKET_BYTES: usize = 4000;
+static PRE_SKIP: u16 = 312;
+static STREAM_SERIAL: u32 = 0x4F505553;
+static MIN_BITRATE: f32 = 16_000.0;
+static MAX_BITRATE: f32 = 128_000.0;
+
+
+// Ogg Opus Encoder -----------------------------------------------------------
+pub struct OpusEncoder {
+    writer: PacketWriter<BufWriter<File>>,
+    encoder: RawEncoder,
+    channels: usize,
+    buffer: Vec<i16>,
+    packet: Vec<u8>,
+    granule: u64
+}
+
+impl OpusEncoder {
+
+    pub fn create(path: &Path, channels: usize, quality: f32) -> Result<OpusEncoder, String> {
+
+        let mut encoder = try!(RawEncoder::new(48_000, if channels == 1 {
+            Channels::Mono
+
+        } else {
+            Channels::Stereo
+
+        }, Application::Audio).map_err(|err| {
+            format!("Failed to create Opus encoder: {:?}", err)
+        }));
+
+        // Quality maps linearly onto the bitrate of each channel
+        let bitrate = (MIN_BITRATE + (MAX_BITRATE - MIN_BITRATE) * quality) as i32 * channels as i32;
+        try!(encoder.set_bitrate(Bitrate::Bits(bitrate)).map_err(|err| {
+            format!("Failed to set Opus bitrate: {:?}", err)
+        }));
+
+        let file = try!(File::create(path).map_err(|err| err.to_string()));
+        let mut encoder = OpusEncoder {
+            writer: PacketWriter::new(BufWriter::new(file)),
+            encoder: encoder,
+            channels: channels,
+            buffer: Vec::with_capacity(FRAME_SAMPLES * channels),
+            packet: vec![0; MAX_PACKET_BYTES],
+            granule: u64::from(PRE_SKIP)
+        };
+
+        try!(encoder.write_headers());
+        Ok(encoder)
+
+    }
+
+}
+
+impl Encoder for OpusEncoder {
+
+    fn write_samples(&mut self, samples: &[i16]) -> Result<(), String> {
+
+        let frame_samples = FRAME_SAMPLES * self.channels;
+        for sample in samples {
+            self.buffer.push(*sample);
+            if self.buffer.len() == frame_samples {
+                try!(self.write_frame(PacketWriteEndInfo::NormalPacket));
+            }
+        }
+
+        Ok(())
+
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), String> {
+        // Pad the final frame with silence, the granule position of the last
+        // page tells decoders where the actual audio ends
+        try!(self.write_frame(PacketWriteEndInfo::EndStream));
+
+        // Dropping the buffer would silently discard any write errors
+        self.writer.into_inner().flush().map_err(|err| {
+            format!("Failed to flush Ogg stream: {}", err)
+        })
+    }
+
+}
+
+
+// Internal Interface ---------------------------------------------------------
+impl OpusEncoder {
+
+    fn write_headers(&mut self) -> Result<(), String> {
+
+        let mut head = Vec::with_capacity(19);
+        head.extend_from_slice(b"OpusHead");
+        head.push(1);
+        head.push(self.channels as u8);
+        head.extend_from_slice(&[PRE_SKIP as u8, (PRE_SKIP >> 8) as u8]);
+        head.extend_from_slice(&[0x80, 0xBB, 0x00, 0x00]);
+        head.extend_from_slice(&[0, 0, 0]);
+
+        let vendor = b"discord-bot";
+        let mut tags = Vec::with_capacity(16 + vendor.len());
+        tags.extend_from_slice(b"OpusTags");
+        tags.extend_from_slice(&[vendor.len() as u8, 0, 0, 0]);
+        tags.extend_from_slice(vendor);
+        tags.extend_from_slice(&[0, 0, 0, 0]);
+
+        // Both headers need to be placed on their own pages
+        try!(self.write_packet(head, PacketWriteEndInfo::EndPage, 0));
+        self.write_packet(tags, PacketWriteEndInfo::EndPage, 0)
+
+    }
+
+    fn write_frame(&mut self, info: PacketWriteEndInfo) -> Result<(), String> {
+
+        let frames = self.buffer.len() / self.channels;
+        self.buffer.resize(FRAME_SAMPLES * self.channels, 0);
+
+        let size = try!(self.encoder.encode(&self.buffer, &mut self.packet).map_err(|err| {
+            format!("Failed to encode Opus frame: {:?}", err)
+        }));
+
+        self.buffer.clear();
+        self.granule += frames as u64;
+
+        let packet = self.packet[..size].to_vec();
+        let granule = self.granule;
+        self.write_packet(packet, info, granule)
+
+    }
+
+    fn write_packet(&mut self, data: Vec<u8>, info: PacketWriteEndInfo, granule: u64) -> Result<(), String> {
+        self.writer.write_packet(data.into_boxed_slice(), STREAM_SERIAL, info, granule).map_err(|err| {
+            format!("Failed to write Ogg page: {}", err)
+        })
+    }
+
+}
+
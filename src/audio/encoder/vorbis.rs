@@ -0,0 +1,54 @@
+// STD Dependencies -----------------------------------------------------------
+use std::path::Path;
+
+
+// External Dependencies ------------------------------------------------------
+use vorbis_enc::OggVorbisEncoder;
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::Encoder;
+
+
+// Ogg Vorbis Encoder ---------------------------------------------------------
+pub struct VorbisEncoder {
+    encoder: OggVorbisEncoder
+}
+
+impl VorbisEncoder {
+
+    pub fn create(path: &Path, channels: usize, quality: f32) -> Result<VorbisEncoder, String> {
+
+        let filename = try!(path.to_str().ok_or_else(|| "Invalid file path.".to_string()));
+        let mut encoder = try!(OggVorbisEncoder::new(filename).map_err(|err| {
+            format!("Failed to open Ogg file: {:?}", err)
+        }));
+
+        try!(encoder.initialize_with_vbr(channels as u32, 48_000, quality).map_err(|err| {
+            format!("Failed to initialize Vorbis stream: {:?}", err)
+        }));
+
+        Ok(VorbisEncoder {
+            encoder: encoder
+        })
+
+    }
+
+}
+
+impl Encoder for VorbisEncoder {
+
+    fn write_samples(&mut self, samples: &[i16]) -> Result<(), String> {
+        self.encoder.write_samples(samples).map_err(|err| {
+            format!("Failed to encode Vorbis samples: {:?}", err)
+        })
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), String> {
+        self.encoder.close().map_err(|err| {
+            format!("Failed to close Vorbis stream: {:?}", err)
+        })
+    }
+
+}
+
@@ -0,0 +1,141 @@
+// STD Dependencies -----------------------------------------------------------
+use std::collections::VecDeque;
+
+
+// Statics --------------------------------------------------------------------
+static LIMITER_CEILING: f32 = 0.98;
+static LIMITER_RELEASE_MILLIS: f32 = 80.0;
+
+
+// Soft Knee Compressor -------------------------------------------------------
+//
+// Leaves samples below the threshold untouched and logarithmically compresses
+// everything above it into the remaining headroom.
+#[derive(Debug, Copy, Clone)]
+pub struct Compressor {
+    threshold: f32,
+    alpha: f32
+}
+
+impl Compressor {
+
+    pub fn new(threshold: f32) -> Compressor {
+        Compressor {
+            threshold: threshold,
+            alpha: compressor_alpha(threshold)
+        }
+    }
+
+    pub fn apply(&self, x: f32) -> f32 {
+        if x >= -self.threshold && x <= self.threshold {
+            x
+
+        } else {
+            let xa = x.abs();
+            let a = (1.0 + self.alpha * ((xa - self.threshold) / (2.0 - self.threshold))).ln();
+            let b = (1.0 + self.alpha).ln();
+            (x / xa) * (self.threshold + (1.0 - self.threshold) * (a / b))
+        }
+    }
+
+}
+
+
+// Look-ahead Limiter ---------------------------------------------------------
+//
+// Delays the stereo signal by the look-ahead window so that the gain can
+// already be reduced before a peak is reached, preventing any clipping.
+pub struct Limiter {
+    lookahead: usize,
+    delay: VecDeque<(f32, f32)>,
+    gain: f32,
+    release: f32
+}
+
+impl Limiter {
+
+    pub fn new(lookahead_ms: u32, sample_rate: u32) -> Limiter {
+        Limiter {
+            lookahead: (lookahead_ms * sample_rate / 1000) as usize,
+            delay: VecDeque::new(),
+            gain: 1.0,
+            release: 1.0 - (-1000.0 / (LIMITER_RELEASE_MILLIS * sample_rate as f32)).exp()
+        }
+    }
+
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) -> usize {
+
+        for frame in input.chunks(2) {
+            self.delay.push_back((frame[0], frame[frame.len() - 1]));
+        }
+
+        // Flush any delayed samples once there is no more input
+        let keep = if input.is_empty() {
+            0
+
+        } else {
+            self.lookahead
+        };
+
+        let mut written = 0;
+        while self.delay.len() > keep && written + 1 < output.len() {
+
+            // Ramp the gain down just far enough to reach every upcoming
+            // peak by the time it leaves the delay line
+            let gain = self.gain;
+            let (step, floor) = self.delay.iter().enumerate().fold((0.0f32, 1.0f32), |(step, floor), (index, &(l, r))| {
+                let peak = l.abs().max(r.abs());
+                if peak > LIMITER_CEILING {
+                    let required = LIMITER_CEILING / peak;
+                    (step.max((gain - required) / (index + 1) as f32), floor.min(required))
+
+                } else {
+                    (step, floor)
+                }
+            });
+
+            // Otherwise release, but never above what upcoming peaks allow
+            if step > 0.0 {
+                self.gain -= step;
+
+            } else {
+                self.gain = (self.gain + (1.0 - self.gain) * self.release).min(floor);
+            }
+
+            let (l, r) = self.delay.pop_front().unwrap();
+            output[written] = l * self.gain;
+            output[written + 1] = r * self.gain;
+            written += 2;
+
+        }
+
+        written
+
+    }
+
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn compressor_alpha(threshold: f32) -> f32 {
+
+    // Find the alpha at which the compressed curve continues with a slope of
+    // one at the threshold, i.e. alpha / ln(1 + alpha) = (2 - t) / (1 - t)
+    let threshold = threshold.max(0.0).min(0.99);
+    let target = (2.0 - threshold) / (1.0 - threshold);
+
+    let (mut low, mut high) = (0.0001f32, 10000.0f32);
+    for _ in 0..60 {
+        let alpha = (low + high) * 0.5;
+        if alpha / (1.0 + alpha).ln() < target {
+            low = alpha;
+
+        } else {
+            high = alpha;
+        }
+    }
+
+    (low + high) * 0.5
+
+}
+
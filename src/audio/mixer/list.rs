@@ -11,15 +11,19 @@ use super::MixerSource;
 // Mixer Source List Implementation -------------------------------------------
 pub struct MixerList {
     effects: Vec<(Effect, ActionOption)>,
+    playlist: Vec<Effect>,
+    loops: usize,
     source: Option<MixerSource>
 }
 
 impl MixerList {
 
-    pub fn new(effects: Vec<(Effect, ActionOption)>) -> MixerList {
+    pub fn new(effects: Vec<(Effect, ActionOption)>, loops: usize) -> MixerList {
 
         let mut list = MixerList {
+            playlist: effects.iter().map(|e| e.0.clone()).collect(),
             effects: effects,
+            loops: loops,
             source: None
         };
 
@@ -30,9 +34,18 @@ impl MixerList {
     }
 
     pub fn clear(&mut self) -> Vec<(Effect, ActionOption)> {
+        self.loops = 0;
         self.effects.drain(0..).collect()
     }
 
+    pub fn set_loops(&mut self, loops: usize) {
+        self.loops = loops;
+    }
+
+    pub fn skip(&mut self) -> Option<(Effect, ActionOption)> {
+        self.source.take().map(|source| source.into_effect())
+    }
+
     pub fn get_active_source(&mut self) -> Option<&mut MixerSource> {
         self.source.as_mut()
     }
@@ -50,6 +63,16 @@ impl MixerList {
                 completed_effect = Some(source.into_effect());
             }
 
+            // Restart the list once it has been played through and should
+            // be looped. Completion actions only ever run for the first pass.
+            if self.effects.is_empty() && self.loops > 0 {
+                self.loops -= 1;
+                self.effects = self.playlist.iter().rev().map(|effect| {
+                    (effect.clone(), None)
+
+                }).collect();
+            }
+
             // Check if there is another, valid effect which should be played
             if let Some(effect) = self.effects.pop() {
 
@@ -88,7 +111,7 @@ impl MixerList {
 
 impl fmt::Display for MixerList {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[MixerList {} effect(s)]", self.effects.len())
+        write!(f, "[MixerList {} effect(s), {} loop(s)]", self.effects.len(), self.loops)
     }
 }
 
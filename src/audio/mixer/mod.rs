@@ -14,6 +14,7 @@ use rand::{thread_rng, Rng};
 
 
 // Modules --------------------------------------------------------------------
+mod dynamics;
 mod list;
 mod source;
 
@@ -22,24 +23,58 @@ mod source;
 use ::effect::Effect;
 use ::action::ActionOption;
 use self::list::MixerList;
+use self::dynamics::Limiter;
 
 
 // Re-Exports -----------------------------------------------------------------
 pub use self::source::MixerSource;
+pub use self::dynamics::Compressor;
 
 
 // Statics --------------------------------------------------------------------
-static MAX_PARALLEL_SOURCES: usize = 2;
-static MIXER_DELAY_MILLIS: u64 = 5000;
+static DUCK_ATTACK_MILLIS: f32 = 30.0;
+static DUCK_RELEASE_MILLIS: f32 = 400.0;
+static DUCK_TIMEOUT_FRAMES: u32 = 50;
+static LIMITER_LOOKAHEAD_MILLIS: u32 = 5;
+static OUTPUT_SAMPLE_RATE: u32 = 48000;
+
+
+// Mixer Settings -------------------------------------------------------------
+#[derive(Debug, Copy, Clone)]
+pub struct MixerSettings {
+    pub max_parallel_sources: usize,
+    pub delay_ms: u64,
+    pub compressor_threshold: f32,
+    pub master_volume_db: f32,
+    pub limiter: bool
+}
+
+impl Default for MixerSettings {
+    fn default() -> MixerSettings {
+        MixerSettings {
+            max_parallel_sources: 2,
+            delay_ms: 5000,
+            compressor_threshold: 0.6,
+            master_volume_db: 0.0,
+            limiter: false
+        }
+    }
+}
 
 
 // Mixer Commands -------------------------------------------------------------
 pub enum MixerCommand {
-    PlayEffects(Vec<(Effect, ActionOption)>),
-    QueueEffects(Vec<(Effect, ActionOption)>),
+    PlayEffects(Vec<(Effect, ActionOption)>, usize),
+    QueueEffects(Vec<(Effect, ActionOption)>, usize),
     SetBitrate(u64),
     ClearDelay,
-    ClearQueue
+    ClearQueue,
+    Skip,
+    Pause,
+    Resume,
+    Loop(usize),
+    Duck(f32),
+    Configure(MixerSettings)
 }
 
 
@@ -59,8 +94,18 @@ pub struct Mixer {
     active_source_lists: Vec<MixerList>,
     queued_source_lists: VecDeque<MixerList>,
     audio_buffer: [i16; 960 * 2],
+    mix_buffer: [f32; 960 * 2],
+    limit_buffer: [f32; 960 * 2],
+    settings: MixerSettings,
+    compressor: Compressor,
+    limiter: Limiter,
+    master_gain: f32,
     delay: u64,
-    bitrate: u64
+    bitrate: u64,
+    paused: bool,
+    duck_gain: f32,
+    duck_target: f32,
+    duck_frames: u32
 }
 
 
@@ -69,7 +114,8 @@ impl Mixer {
 
     pub fn new(
         command_queue: Receiver<MixerCommand>,
-        event_queue: Sender<MixerEvent>
+        event_queue: Sender<MixerEvent>,
+        settings: MixerSettings
 
     ) -> Mixer {
 
@@ -82,8 +128,18 @@ impl Mixer {
             active_source_lists: Vec::new(),
             queued_source_lists: VecDeque::new(),
             audio_buffer: [0; 960 * 2],
-            delay: MIXER_DELAY_MILLIS,
-            bitrate: 96
+            mix_buffer: [0.0; 960 * 2],
+            limit_buffer: [0.0; 960 * 2],
+            settings: settings,
+            compressor: Compressor::new(settings.compressor_threshold),
+            limiter: Limiter::new(LIMITER_LOOKAHEAD_MILLIS, OUTPUT_SAMPLE_RATE),
+            master_gain: volume_gain(settings.master_volume_db),
+            delay: settings.delay_ms,
+            bitrate: 96,
+            paused: false,
+            duck_gain: 1.0,
+            duck_target: 1.0,
+            duck_frames: 0
         };
 
         info!("{} Created", mixer);
@@ -100,19 +156,20 @@ impl Mixer {
 
     fn update_sources(&mut self) {
 
-        if self.active_source_lists.len() < MAX_PARALLEL_SOURCES {
+        if self.active_source_lists.len() < self.settings.max_parallel_sources {
 
             // Pop the next available command from the queue
             if let Some(command) = self.command_buffer.pop_front() {
                 match command {
-                    MixerCommand::PlayEffects(effects) => {
+                    MixerCommand::PlayEffects(effects, loops) => {
                         info!("{} Playing effects list...", self);
-                        self.active_source_lists.push(MixerList::new(effects));
+                        self.active_source_lists.push(MixerList::new(effects, loops));
                     },
-                    MixerCommand::QueueEffects(effects) => {
+                    MixerCommand::QueueEffects(effects, loops) => {
                         info!("{} Queueing effects list...", self);
-                        self.queued_source_lists.push_back(MixerList::new(effects));
+                        self.queued_source_lists.push_back(MixerList::new(effects, loops));
                     },
+                    MixerCommand::Loop(count) => self.set_loops(count),
                     _ => unreachable!()
                 }
 
@@ -149,13 +206,60 @@ impl Mixer {
                 // Always clear queue if requested
                 MixerCommand::ClearQueue => self.clear(),
 
+                // Skip the currently playing effect of all active lists
+                MixerCommand::Skip => self.skip(),
+
+                // Pausing keeps all sources in place but stops mixing them
+                MixerCommand::Pause => {
+                    info!("{} Paused", self);
+                    self.paused = true;
+                },
+                MixerCommand::Resume => {
+                    info!("{} Resumed", self);
+                    self.paused = false;
+                },
+
+                // Lower the level of all sources while members are speaking,
+                // the ducking needs to be refreshed in order to persist
+                MixerCommand::Duck(gain) => {
+                    if (gain - self.duck_target).abs() > ::std::f32::EPSILON {
+                        info!("{} Ducking to {:.2}", self, gain);
+                        self.duck_target = gain;
+                    }
+                    self.duck_frames = if gain < 1.0 { DUCK_TIMEOUT_FRAMES } else { 0 };
+                },
+
+                // Apply updated settings, the initial delay is only used once
+                MixerCommand::Configure(settings) => self.configure(settings),
+
+                // Loops apply to the most recent list, so they need to wait
+                // for any buffered list commands
+                MixerCommand::Loop(count) => if self.command_buffer.is_empty() {
+                    self.set_loops(count);
+
+                } else {
+                    self.command_buffer.push_back(command);
+                },
+
                 // Push other commands into the buffer
                 _ => self.command_buffer.push_back(command)
 
             }
         }
 
-        if self.delay == 0 {
+        // Release the ducking once it is no longer being refreshed
+        if self.duck_frames > 0 {
+            self.duck_frames -= 1;
+            if self.duck_frames == 0 {
+                info!("{} Ducking expired", self);
+                self.duck_target = 1.0;
+            }
+        }
+
+        if self.paused {
+            0
+
+        } else if self.delay == 0 {
             self.update_sources();
             self.mix_sources(buffer)
 
@@ -173,8 +277,8 @@ impl Mixer {
 
         // Clear buffer
         let samples = buffer.len();
-        for item in buffer.iter_mut().take(samples) {
-            *item = 0;
+        for item in self.mix_buffer.iter_mut().take(samples) {
+            *item = 0.0;
         }
 
         // Mix Samples from all active sources into the buffer
@@ -196,15 +300,14 @@ impl Mixer {
 
                         // Double interval when mixing mono
                         let i = e * channel_offset;
+                        let s = f32::from(self.audio_buffer[e]) / max_sample_value;
 
                         // Left / Mono Sample
-                        let s = f32::from(buffer[i]) + f32::from(self.audio_buffer[e]);
-                        buffer[i] = (compress(s / max_sample_value, 0.6) * max_sample_value) as i16;
+                        self.mix_buffer[i] += s;
 
                         // Right Sample
                         if channels == 1 {
-                            let s = f32::from(buffer[i + 1]) + f32::from(self.audio_buffer[e]);
-                            buffer[i + 1] = (compress(s / max_sample_value, 0.6) * max_sample_value) as i16;
+                            self.mix_buffer[i + 1] += s;
                         }
 
                     }
@@ -227,13 +330,98 @@ impl Mixer {
         // Remove inactive sources once they have completed playing
         self.active_source_lists.retain(|list| list.is_active());
 
-        mixed
+        self.apply_gain(mixed);
+
+        // Either limit the output or softly compress any peaks
+        if self.settings.limiter {
+            let written = self.limiter.process(&self.mix_buffer[..mixed], &mut self.limit_buffer[..samples]);
+            for (target, s) in buffer.iter_mut().zip(self.limit_buffer[..written].iter()) {
+                *target = (s * max_sample_value) as i16;
+            }
+            written
+
+        } else {
+            for (target, s) in buffer.iter_mut().zip(self.mix_buffer[..mixed].iter()) {
+                *target = (self.compressor.apply(*s).max(-1.0).min(1.0) * max_sample_value) as i16;
+            }
+            mixed
+        }
+
+    }
+
+    fn apply_gain(&mut self, mixed: usize) {
+
+        // Without any output there is nothing to smooth
+        if mixed == 0 {
+            self.duck_gain = self.duck_target;
+            return;
+        }
+
+        // Ramp the ducking gain per stereo frame, lowering it faster than
+        // restoring it
+        let millis = if self.duck_target < self.duck_gain {
+            DUCK_ATTACK_MILLIS
+
+        } else {
+            DUCK_RELEASE_MILLIS
+        };
+
+        let coefficient = 1.0 - (-1000.0 / (millis * OUTPUT_SAMPLE_RATE as f32)).exp();
+        for frame in self.mix_buffer[..mixed].chunks_mut(2) {
+            self.duck_gain += (self.duck_target - self.duck_gain) * coefficient;
+            for sample in frame {
+                *sample *= self.duck_gain * self.master_gain;
+            }
+        }
+
+    }
+
+    fn configure(&mut self, settings: MixerSettings) {
+
+        info!("{} Configured with {:?}", self, settings);
+
+        if settings.limiter != self.settings.limiter {
+            self.limiter = Limiter::new(LIMITER_LOOKAHEAD_MILLIS, OUTPUT_SAMPLE_RATE);
+        }
+
+        self.compressor = Compressor::new(settings.compressor_threshold);
+        self.master_gain = volume_gain(settings.master_volume_db);
+        self.settings = settings;
+
+    }
+
+    fn skip(&mut self) {
+
+        info!("{} Skipping active effects...", self);
+
+        for list in &mut self.active_source_lists {
+            if let Some(effect) = list.skip() {
+                self.event_queue.send(
+                    MixerEvent::Canceled(effect.0, effect.1)
+
+                ).ok();
+            }
+        }
+
+    }
+
+    fn set_loops(&mut self, count: usize) {
+
+        info!("{} Looping most recent list {} more time(s)", self, count);
+
+        if let Some(list) = self.queued_source_lists.back_mut() {
+            list.set_loops(count);
+
+        } else if let Some(list) = self.active_source_lists.last_mut() {
+            list.set_loops(count);
+        }
 
     }
 
     fn clear(&mut self) {
 
         info!("{} Clearing list queues...", self);
+        self.paused = false;
 
         for mut list in self.active_source_lists.drain(0..) {
             for effect in list.clear() {
@@ -287,40 +475,7 @@ impl fmt::Display for Mixer {
 
 
 // Helpers --------------------------------------------------------------------
-pub fn compress(x: f32, threshold: f32) -> f32 {
-
-    // threshold    alpha
-    // ------------------
-    // 0            2.51
-    // 0.05         2.67
-    // 0.1          2.84
-    // 0.15         3.04
-    // 0.2          3.26
-    // 0.25         3.52
-    // 0.3          3.82
-    // 0.35         4.17
-    // 0.4          4.59
-    // 0.45         5.09
-    // 0.5          5.71
-    // 0.55         6.49
-    // 0.6          7.48
-    // 0.65         8.81
-    // 0.7          10.63
-    // 0.75         13.3
-    // 0.8          17.51
-    // 0.85         24.97
-    // 0.9          41.15
-    // 0.95         96.09
-    if x >= -threshold && x <= threshold {
-        x
-
-    } else {
-        let alpha = 7.48; // for threshold=0.6
-        let xa = x.abs();
-        let a = (1.0 + alpha * ((xa - threshold) / (2.0 - threshold))).ln();
-        let b = (1.0 + alpha).ln();
-        (x / xa) * (threshold + (1.0 - threshold) * (a / b))
-    }
-
+fn volume_gain(db: f32) -> f32 {
+    10.0f32.powf(db / 20.0)
 }
 
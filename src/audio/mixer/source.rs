@@ -1,25 +1,83 @@
 // STD Dependencies -----------------------------------------------------------
-use std::fs::File;
-use std::f32::consts::PI;
-
-
-// External Dependencies ------------------------------------------------------
-use flac;
+use std::cmp;
+use std::path::Path;
 
 
 // Internal Dependencies ------------------------------------------------------
 use ::action::ActionOption;
 use ::effect::Effect;
+use ::audio::{Decoder, Resampler, mix_to_stereo, open_decoder};
+
+
+// Statics --------------------------------------------------------------------
+static OUTPUT_SAMPLE_RATE: u32 = 48000;
+static OUTPUT_CHANNELS: usize = 2;
+static READ_FRAMES: usize = 480;
+
+
+// Playback Envelope ----------------------------------------------------------
+#[derive(Copy, Clone)]
+struct Envelope {
+    start: u64,
+    end: u64,
+    fade_in: u64,
+    fade_out: u64
+}
+
+impl Envelope {
+
+    fn from_effect(effect: &Effect, sample_rate: u64, channels: u64, total: u64) -> Envelope {
+
+        let settings = effect.settings();
+        let samples_per_ms = |ms: u64| (ms.saturating_mul(sample_rate) / 1000).saturating_mul(channels);
+
+        let start = cmp::min(samples_per_ms(settings.trim_start_ms), total);
+        let end = cmp::max(total.saturating_sub(samples_per_ms(settings.trim_end_ms)), start);
+
+        Envelope {
+            start: start,
+            end: end,
+            fade_in: cmp::min(samples_per_ms(settings.fade_in_ms), end - start),
+            fade_out: cmp::min(samples_per_ms(settings.fade_out_ms), end - start)
+        }
+
+    }
+
+    fn gain(&self, position: u64) -> f32 {
+
+        let mut gain = 1.0;
+
+        if position < self.start + self.fade_in {
+            gain *= (position - self.start) as f32 / self.fade_in as f32;
+        }
+
+        if position + self.fade_out > self.end {
+            gain *= (self.end - position) as f32 / self.fade_out as f32;
+        }
+
+        gain
+
+    }
+
+}
 
 
 // Mixer Source Implementation ------------------------------------------------
 pub struct MixerSource {
     active: bool,
+    exhausted: bool,
     channels: usize,
     gain: f32,
+    scale: f32,
+    envelope: Envelope,
+    position: u64,
+    samples: Vec<f32>,
+    stereo: Vec<f32>,
+    pending: Vec<f32>,
+    resampler: Resampler,
     effect: Option<Effect>,
     action: ActionOption,
-    stream: flac::StreamIter<flac::ReadStream<File>, i64>,
+    stream: Box<Decoder>
 }
 
 impl MixerSource {
@@ -30,19 +88,68 @@ impl MixerSource {
 
     ) -> Result<MixerSource, (Effect, ActionOption)> {
         let filename = effect.to_path_str().to_string();
-        if let Ok(stream) = flac::StreamReader::<File>::from_file(&filename) {
-            info!("[Mixer] Multiplier: {}", effect.auto_adjust_gain());
-            Ok(MixerSource {
-                active: true,
-                channels: stream.info().channels as usize,
-                gain: effect.auto_adjust_gain(),
-                effect: Some(effect),
-                action: action,
-                stream: flac::StreamIter::new(stream),
-            })
-
-        } else {
-            Err((effect, action))
+        match open_decoder(Path::new(&filename), effect.format()) {
+            Ok(mut stream) => {
+
+                info!("[Mixer] Multiplier: {}", effect.volume_gain());
+
+                let info = stream.info();
+                let channels = u64::from(info.channels);
+                let resampler = if channels == 0 {
+                    Err("Unsupported channel count of 0.".to_string())
+
+                } else {
+                    Resampler::new(info.sample_rate, OUTPUT_SAMPLE_RATE, OUTPUT_CHANNELS)
+                };
+
+                let resampler = match resampler {
+                    Ok(resampler) => resampler,
+                    Err(err) => {
+                        warn!("[Mixer] Failed to decode {}: {}", effect, err);
+                        return Err((effect, action));
+                    }
+                };
+
+                // Streams without a known length cannot be trimmed at the end
+                let total = if info.total_samples > 0 {
+                    info.total_samples * channels
+
+                } else {
+                    u64::max_value()
+                };
+
+                let envelope = Envelope::from_effect(
+                    &effect,
+                    u64::from(info.sample_rate),
+                    channels,
+                    total
+                );
+
+                // Skip over the trimmed start of the effect
+                stream.by_ref().take(envelope.start as usize).count();
+
+                Ok(MixerSource {
+                    active: true,
+                    exhausted: false,
+                    channels: channels as usize,
+                    gain: effect.volume_gain(),
+                    scale: 2.0f32.powi(16 - i32::from(info.bits_per_sample)),
+                    envelope: envelope,
+                    position: envelope.start,
+                    samples: Vec::with_capacity(READ_FRAMES * channels as usize),
+                    stereo: Vec::with_capacity(READ_FRAMES * OUTPUT_CHANNELS),
+                    pending: Vec::with_capacity(960 * OUTPUT_CHANNELS * 2),
+                    resampler: resampler,
+                    effect: Some(effect),
+                    action: action,
+                    stream: stream
+                })
+
+            },
+            Err(err) => {
+                warn!("[Mixer] Failed to decode {}: {}", effect, err);
+                Err((effect, action))
+            }
         }
     }
 
@@ -55,126 +162,73 @@ impl MixerSource {
     }
 
     pub fn channels(&self) -> usize {
-        self.channels
+        OUTPUT_CHANNELS
     }
 
     pub fn read_frame(&mut self, bitrate: u64, buffer: &mut [i16]) -> Option<usize> {
 
-        let gain = self.gain;
-        let (mut written, iter) = (0, &mut self.stream);
-
-        // Downsampling
-        // TODO reduce code required
-        if bitrate <= 16 {
-
-            buffer[0] = 0;
-
-            let rc = 1.0 / (4000.0 * 2.0 * PI);
-            let dt = 1.0 / 48000.0;
-            let alpha = dt / (rc + dt);
-
-            for s in iter.take(buffer.len()).map(|s| {
-                (s as f32) * gain
-
-            }) {
-                let b = f32::from(buffer[written]) + (alpha * (s - f32::from(buffer[written])));
-                let i = written / 12 * 12;
-                buffer[i] = b as i16;
-                buffer[i + 1] = b as i16;
-                buffer[i + 2] = b as i16;
-                buffer[i + 3] = b as i16;
-                buffer[i + 4] = b as i16;
-                buffer[i + 5] = b as i16;
-                buffer[i + 6] = b as i16;
-                buffer[i + 7] = b as i16;
-                buffer[i + 8] = b as i16;
-                buffer[i + 9] = b as i16;
-                buffer[i + 10] = b as i16;
-                buffer[i + 11] = b as i16;
-                written += 1;
-            }
+        // Lower bitrates cannot reproduce the full spectrum, so we limit the
+        // bandwidth of the resampled output accordingly
+        self.resampler.set_bandwidth(if bitrate <= 16 {
+            4000
 
         } else if bitrate <= 32 {
-
-            buffer[0] = 0;
-
-            let rc = 1.0 / (8000.0 * 2.0 * PI);
-            let dt = 1.0 / 48000.0;
-            let alpha = dt / (rc + dt);
-
-            for s in iter.take(buffer.len()).map(|s| {
-                (s as f32) * gain
-
-            }) {
-                let b = f32::from(buffer[written]) + (alpha * (s - f32::from(buffer[written])));
-                let i = written / 6 * 6;
-                buffer[i] = b as i16;
-                buffer[i + 1] = b as i16;
-                buffer[i + 2] = b as i16;
-                buffer[i + 3] = b as i16;
-                buffer[i + 4] = b as i16;
-                buffer[i + 5] = b as i16;
-                written += 1;
-            }
+            8000
 
         } else if bitrate <= 48 {
+            12000
 
-            buffer[0] = 0;
-
-            let rc = 1.0 / (12000.0 * 2.0 * PI);
-            let dt = 1.0 / 48000.0;
-            let alpha = dt / (rc + dt);
+        } else {
+            OUTPUT_SAMPLE_RATE / 2
+        });
 
-            for s in iter.take(buffer.len()).map(|s| {
-                (s as f32) * gain
+        while self.pending.len() < buffer.len() && !self.exhausted {
+            self.fill_pending();
+        }
 
-            }) {
-                let b = f32::from(buffer[written]) + (alpha * (s - f32::from(buffer[written])));
-                let i = written / 4 * 4;
-                buffer[i] = b as i16;
-                buffer[i + 1] = b as i16;
-                buffer[i + 2] = b as i16;
-                buffer[i + 3] = b as i16;
-                written += 1;
-            }
+        let written = cmp::min(buffer.len(), self.pending.len());
+        for (target, s) in buffer.iter_mut().zip(self.pending.drain(0..written)) {
+            *target = s.max(-32768.0).min(32767.0) as i16;
+        }
 
-        } else if bitrate <= 63 {
+        if written > 0 {
+            Some(written)
 
-            buffer[0] = 0;
+        } else {
+            self.active = false;
+            None
+        }
 
-            let rc = 1.0 / (24000.0 * 2.0 * PI);
-            let dt = 1.0 / 48000.0;
-            let alpha = dt / (rc + dt);
+    }
 
-            for s in iter.take(buffer.len()).map(|s| {
-                (s as f32) * gain
+    fn fill_pending(&mut self) {
 
-            }) {
-                let b = f32::from(buffer[written]) + (alpha * (s - f32::from(buffer[written])));
-                let i = written / 2 * 2;
-                buffer[i] = b as i16;
-                buffer[i + 1] = b as i16;
-                written += 1;
-            }
+        let count = self.read_samples(READ_FRAMES * self.channels);
 
-        } else {
-            for s in iter.take(buffer.len()).map(|s| {
-                ((s as f32) * gain) as i16
+        self.stereo.clear();
+        mix_to_stereo(&self.samples[..count], self.channels, &mut self.stereo);
+        self.resampler.process(&self.stereo, &mut self.pending);
 
-            }) {
-                buffer[written] = s;
-                written += 1;
-            }
+        if count < READ_FRAMES * self.channels {
+            self.resampler.flush(&mut self.pending);
+            self.exhausted = true;
         }
 
-        if written > 0 {
-            Some(written)
+    }
 
-        } else {
-            self.active = false;
-            None
+    fn read_samples(&mut self, count: usize) -> usize {
+
+        let (gain, envelope) = (self.gain * self.scale, self.envelope);
+        let remaining = envelope.end.saturating_sub(self.position);
+
+        self.samples.clear();
+        for s in self.stream.by_ref().take(cmp::min(count as u64, remaining) as usize) {
+            self.samples.push((s as f32) * gain * envelope.gain(self.position));
+            self.position += 1;
         }
 
+        self.samples.len()
+
     }
 
 }
@@ -1,9 +1,28 @@
 // Modules --------------------------------------------------------------------
+mod clip;
+mod decoder;
+mod ducker;
+mod encoder;
 mod mixer;
 mod recorder;
+mod resampler;
+mod timestamp;
+mod transcriber;
 
 
 // Re-Exports -----------------------------------------------------------------
-pub use self::mixer::{Mixer, MixerCommand, MixerEvent};
-pub use self::recorder::Recorder;
-
+pub use self::clip::ClipBuffer;
+pub use self::decoder::{AudioFormat, AudioInfo, Decoder};
+pub use self::decoder::{open as open_decoder, probe as probe_audio};
+pub use self::ducker::Ducker;
+pub use self::encoder::{Encoder, EncoderSettings, FlacEncoder, create as create_encoder};
+pub use self::mixer::{Mixer, MixerCommand, MixerEvent, MixerSettings};
+pub use self::recorder::{Recorder, RecorderCommand, RecorderEvent, RecordingSession};
+pub use self::recorder::{
+    Manifest as RecordingManifest,
+    ManifestTrack as RecordingTrack,
+    MANIFEST_FILENAME as RECORDING_MANIFEST_FILENAME
+};
+pub use self::resampler::{Resampler, mix_to_stereo};
+pub use self::transcriber::{CommandLineTranscriber, Transcriber, transcribe_session};
+pub use self::transcriber::{TRANSCRIPT_FILENAME, TRANSCRIPT_FAILED_FILENAME};
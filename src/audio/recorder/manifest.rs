@@ -0,0 +1,69 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::collections::{HashMap, HashSet};
+
+
+// External Dependencies ------------------------------------------------------
+use serde_json;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::{ChannelId, UserId};
+
+
+// Recording Session Information ----------------------------------------------
+pub struct RecordingSession {
+    pub channel_id: ChannelId,
+    pub channel_name: String,
+    pub nicknames: HashMap<UserId, String>,
+    pub opted_out: HashSet<UserId>
+}
+
+
+// Recording Manifest ---------------------------------------------------------
+#[derive(Serialize, Deserialize)]
+pub struct Manifest {
+    pub started: String,
+    pub duration: u32,
+    pub channel_id: u64,
+    pub channel_name: String,
+    pub mixdown: String,
+    pub tracks: Vec<ManifestTrack>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ManifestTrack {
+    pub user_id: u64,
+    pub nickname: Option<String>,
+    pub file: String,
+    pub offset: u32,
+    pub duration: u32
+}
+
+impl Manifest {
+
+    pub fn read(path: &Path) -> Result<Manifest, String> {
+
+        let mut text = String::new();
+        let mut file = try!(File::open(path).map_err(|err| err.to_string()));
+        try!(file.read_to_string(&mut text).map_err(|err| err.to_string()));
+
+        serde_json::from_str::<Manifest>(&text).map_err(|err| err.to_string())
+
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+
+        let json = try!(serde_json::to_string_pretty(self).map_err(|err| {
+            err.to_string()
+        }));
+
+        let mut file = try!(File::create(path).map_err(|err| err.to_string()));
+        file.write_all(json.as_bytes()).map_err(|err| err.to_string())
+
+    }
+
+}
+
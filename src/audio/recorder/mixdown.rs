@@ -0,0 +1,121 @@
+// STD Dependencies -----------------------------------------------------------
+use std::cmp;
+use std::path::Path;
+use std::collections::VecDeque;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::audio::{Encoder, EncoderSettings, create_encoder};
+use ::audio::mixer::Compressor;
+
+
+// Statics --------------------------------------------------------------------
+static MIXDOWN_LATENCY_MILLIS: usize = 5000;
+
+
+// Stereo Mixdown of all Tracks -----------------------------------------------
+pub struct Mixdown {
+    encoder: Box<Encoder>,
+    compressor: Compressor,
+    position: usize,
+    buffer: VecDeque<f32>
+}
+
+impl Mixdown {
+
+    pub fn new(path: &Path, settings: EncoderSettings) -> Result<Mixdown, String> {
+
+        info!("[AudioWriter] Creating mixdown \"{}\"...", path.display());
+
+        // The mixdown is always stereo regardless of the track settings
+        let encoder = try!(create_encoder(path, settings.format, 2, settings.quality));
+
+        Ok(Mixdown {
+            encoder: encoder,
+            compressor: Compressor::new(0.6),
+            position: 0,
+            buffer: VecDeque::new()
+        })
+
+    }
+
+    pub fn add_samples(&mut self, offset: u32, channels: usize, data: &[i16]) -> Result<(), String> {
+
+        let start = offset as usize * 48;
+        let frames = data.len() / channels;
+
+        // Tracks deliver their chunks with some delay, so only frames which
+        // are far enough in the past get encoded
+        try!(self.write_until((start + frames).saturating_sub(MIXDOWN_LATENCY_MILLIS * 48)));
+
+        if start + frames <= self.position {
+            warn!("[AudioWriter] Dropped late samples @ {}ms from mixdown", offset);
+            return Ok(());
+        }
+
+        let required = (start + frames - self.position) * 2;
+        if self.buffer.len() < required {
+            self.buffer.resize(required, 0.0);
+        }
+
+        let max_sample_value = f32::from(i16::max_value());
+        for f in cmp::max(start, self.position) - start..frames {
+
+            // Mono samples are written to both channels
+            let i = (start + f - self.position) * 2;
+            let left = f32::from(data[f * channels]) / max_sample_value;
+            let right = f32::from(data[f * channels + channels - 1]) / max_sample_value;
+
+            self.buffer[i] += left;
+            self.buffer[i + 1] += right;
+
+        }
+
+        Ok(())
+
+    }
+
+    pub fn close(mut self) -> Result<u32, String> {
+
+        let end = self.position + self.buffer.len() / 2;
+        try!(self.write_until(end));
+
+        let duration = (self.position / 48) as u32;
+        self.encoder.finish().map(|_| duration)
+
+    }
+
+    fn write_until(&mut self, frame: usize) -> Result<(), String> {
+
+        if frame <= self.position {
+            return Ok(());
+        }
+
+        let max_sample_value = f32::from(i16::max_value());
+        let samples = (frame - self.position) * 2;
+        let buffered = cmp::min(samples, self.buffer.len());
+
+        let compressor = &self.compressor;
+        let mixed: Vec<i16> = self.buffer.drain(..buffered).map(|s| {
+            (compressor.apply(s).max(-1.0).min(1.0) * max_sample_value) as i16
+
+        }).collect();
+
+        try!(self.encoder.write_samples(&mixed));
+
+        // Fill any remaining gap between the tracks with silence
+        let silence = [0i16; 960 * 2];
+        let mut remaining = samples - buffered;
+        while remaining > 0 {
+            let count = cmp::min(remaining, silence.len());
+            try!(self.encoder.write_samples(&silence[..count]));
+            remaining -= count;
+        }
+
+        self.position = frame;
+        Ok(())
+
+    }
+
+}
+
@@ -1,21 +1,23 @@
 // STD Dependencies -----------------------------------------------------------
-use std::fs;
-use std::iter;
 use std::thread;
 use std::path::PathBuf;
-use std::collections::HashMap;
-use std::sync::mpsc::{channel, Sender, Receiver};
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc::{channel, Receiver, Sender};
 
 
 // External Dependencies ------------------------------------------------------
 use chrono;
 use clock_ticks;
-use vorbis_enc::OggVorbisEncoder;
 
 
 // Modules --------------------------------------------------------------------
+mod manifest;
+mod mixdown;
 mod track;
+mod writer;
 pub use self::track::{Track, Chunk, VoicePacket};
+pub use self::manifest::{Manifest, ManifestTrack, RecordingSession};
+pub use self::writer::MANIFEST_FILENAME;
 
 
 // Discord Dependencies -------------------------------------------------------
@@ -23,11 +25,41 @@ use discord::model::UserId;
 use discord::voice::AudioReceiver;
 
 
+// Internal Dependencies ------------------------------------------------------
+use ::audio::EncoderSettings;
+
+
+// Statics --------------------------------------------------------------------
+static STALE_PACKET_MILLIS: u64 = 2000;
+static VOICE_ACTIVITY_INTERVAL_MILLIS: u64 = 1000;
+
+
+// Recorder Commands ----------------------------------------------------------
+pub enum RecorderCommand {
+    OptOut(UserId),
+    OptIn(UserId)
+}
+
+
+// Recorder Events ------------------------------------------------------------
+#[derive(PartialEq)]
+pub enum RecorderEvent {
+    EncoderError(String),
+    VoiceActivity
+}
+
+
 // Audio Recorder Abstraction -------------------------------------------------
 pub struct Recorder {
+    name: String,
     tracks: HashMap<u32, Track>,
+    opted_out: HashSet<UserId>,
+    command_queue: Receiver<RecorderCommand>,
     started: u64,
     chunk_duration: u32,
+    jitter_buffer_ms: u32,
+    event_queue: Sender<RecorderEvent>,
+    voice_activity: u64,
     write_queue: Sender<Option<Chunk>>,
     writer_thread: Option<thread::JoinHandle<()>>
 }
@@ -36,119 +68,123 @@ pub struct Recorder {
 // Public Interface -----------------------------------------------------------
 impl Recorder {
 
-    pub fn new(mut recording_path: PathBuf, chunk_duration: u32) -> Recorder {
+    pub fn new(
+        mut recording_path: PathBuf,
+        chunk_duration: u32,
+        jitter_buffer_ms: u32,
+        settings: EncoderSettings,
+        session: RecordingSession,
+        command_queue: Receiver<RecorderCommand>,
+        event_queue: Sender<RecorderEvent>
+
+    ) -> Recorder {
 
         let (sender, receiver) = channel::<Option<Chunk>>();
-        recording_path.push(format!("{}", chrono::Local::now()));
+        let now = chrono::Local::now();
+        let name = format!("{}", now.format("%Y-%m-%d_%H-%M-%S"));
+        recording_path.push(&name);
 
         Recorder {
+            name: name,
             tracks: HashMap::new(),
+            opted_out: session.opted_out.clone(),
+            command_queue: command_queue,
             started: clock_ticks::precise_time_ms(),
             chunk_duration: chunk_duration,
+            jitter_buffer_ms: jitter_buffer_ms,
+            event_queue: event_queue.clone(),
+            voice_activity: 0,
             write_queue: sender,
-            writer_thread: Some(Recorder::writer_thread(recording_path, receiver))
+            writer_thread: Some(writer::spawn(
+                recording_path,
+                now.to_rfc3339(),
+                session,
+                settings,
+                event_queue,
+                receiver
+            ))
         }
 
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
 }
 
 // Internal Interface ---------------------------------------------------------
 impl Recorder {
 
-    fn writer_thread(path: PathBuf, write_queue: Receiver<Option<Chunk>>) -> thread::JoinHandle<()> {
-
-        thread::spawn(move || {
-
-            fs::create_dir_all(path.clone()).expect("[AudioWiter] Failed to create recording directory.");
-
-            info!("[AudioWiter] Created");
-
-            let silence_buffer: Vec<i16> = iter::repeat(0).take(48_000).collect();
-            let mut streams: HashMap<UserId, OggVorbisEncoder> = HashMap::new();
-
-            fn write_silence(
-                stream: &mut OggVorbisEncoder,
-                silence_buffer: &[i16],
-                silence_millis: usize
-            ) {
-
-                let silence_samples = silence_millis * 48;
-                let buffers = silence_samples / 48_000;
-                let remainder = silence_samples % 48_000;
-
-                for _ in 0..buffers {
-                    stream.write_samples(silence_buffer).ok();
-                }
-
-                if remainder > 0 {
-                    stream.write_samples(&silence_buffer[0..remainder]).ok();
-                }
-
-            }
-
-            while let Ok(Some(chunk)) = write_queue.recv() {
-
-                let mut stream = streams.entry(chunk.user_id).or_insert_with(|| {
-
-                    let mut file_path = path.clone();
-                    file_path.push(format!("{}.ogg", chunk.user_id));
-
-                    let filename = file_path.to_str().unwrap();
-
-                    info!("[AudioWriter] Creating file \"{}\"...", filename);
-
-                    let mut encoder = OggVorbisEncoder::new(filename).expect("[AudioWiter] Failed to open ogg file for recording.");
-                    encoder.initialize_with_vbr(1, 48_000, 0.2).expect("[AudioWiter] Failed to initialize vorbis stream.");
-                    encoder
-
-                });
-
-                info!(
-                    "[AudioWriter] Received chunk for User#{} with {} packets @ {}ms with {}ms ({}ms silence infront)",
-                    chunk.user_id,
-                    chunk.packets.len(),
-                    chunk.offset,
-                    chunk.duration,
-                    chunk.silence
-                );
-
-                if chunk.silence > 0 {
-                    write_silence(&mut stream, &silence_buffer, chunk.silence as usize);
-                }
-
-                for mut packet in chunk.packets {
-                    if packet.silence > 0 {
-                        write_silence(&mut stream, &silence_buffer, packet.silence as usize);
-                    }
-                    stream.write_samples(packet.mix_to_mono()).ok();
-                }
-
-            }
-
-            info!("[AudioWriter] Closing streams...");
-            for stream in streams.values_mut() {
-                stream.close().expect("[AudioWriter] Failed to close vorbis stream.");
-            }
-
-            info!("[AudioWriter] Destroyed");
-
-        })
-    }
-
     fn get_track(&mut self, source_id: u32) -> &mut Track {
         let started = self.started;
         let chunk_duration = self.chunk_duration;
+        let jitter_buffer_ms = self.jitter_buffer_ms;
         let write_queue = self.write_queue.clone();
         self.tracks.entry(source_id).or_insert_with(|| {
             Track::new(
                 started,
                 chunk_duration,
+                jitter_buffer_ms,
                 write_queue
             )
         })
     }
 
+    fn update_opt_outs(&mut self) {
+
+        let mut changed = false;
+        while let Ok(command) = self.command_queue.try_recv() {
+            changed = true;
+            match command {
+                RecorderCommand::OptOut(user_id) => {
+                    info!("[AudioRecorder] User#{} opted out", user_id);
+                    self.opted_out.insert(user_id);
+                },
+                RecorderCommand::OptIn(user_id) => {
+                    info!("[AudioRecorder] User#{} opted in", user_id);
+                    self.opted_out.remove(&user_id);
+                }
+            }
+        }
+
+        if changed {
+            self.discard_opted_out();
+        }
+
+    }
+
+    fn discard_opted_out(&mut self) {
+        // Drop anything which was buffered before a member was identified or
+        // opted out
+        let opted_out = &self.opted_out;
+        for track in self.tracks.values_mut() {
+            if track.user_id().map_or(false, |id| opted_out.contains(&id)) {
+                track.clear();
+            }
+        }
+    }
+
+    fn is_opted_out(&self, source_id: u32) -> bool {
+        self.tracks.get(&source_id).and_then(|track| track.user_id()).map_or(false, |id| {
+            self.opted_out.contains(&id)
+        })
+    }
+
+    fn flush_stale(&mut self) {
+
+        // Tracks only write out chunks once enough packets have been
+        // buffered, so the tail of a member's speech would otherwise linger
+        // until they speak again
+        let now = clock_ticks::precise_time_ms();
+        for track in self.tracks.values_mut() {
+            if track.is_stale(now, STALE_PACKET_MILLIS) {
+                track.flush();
+            }
+        }
+
+    }
+
     fn flush(&mut self) {
         for track in self.tracks.values_mut() {
             track.flush();
@@ -173,24 +209,41 @@ impl AudioReceiver for Recorder {
 
     fn speaking_update(&mut self, source_id: u32, user_id: UserId, _: bool) {
         self.get_track(source_id).set_user_id(&user_id);
+        self.update_opt_outs();
+        self.discard_opted_out();
+        self.flush_stale();
     }
 
     fn voice_packet(
         &mut self,
         source_id: u32,
-        sequence: u16,
+        _: u16,
         timestamp: u32,
         stereo: bool,
         data: &[i16]
     ) {
-        let remainder = timestamp % 960;
+
+        // Members who opted out are never written to disk
+        self.update_opt_outs();
+        if self.is_opted_out(source_id) {
+            return;
+        }
+
+        // Let the bot know that members are still speaking, without sending
+        // an event for every single packet
+        let now = clock_ticks::precise_time_ms();
+        if now.saturating_sub(self.voice_activity) >= VOICE_ACTIVITY_INTERVAL_MILLIS {
+            self.voice_activity = now;
+            self.event_queue.send(RecorderEvent::VoiceActivity).ok();
+        }
+
         self.get_track(source_id).add_voice_packet(VoicePacket {
-            sequence: sequence,
-            timestamp: (timestamp - remainder) / 48,
-            received: clock_ticks::precise_time_ms(),
+            timestamp: timestamp,
+            received: now,
             channels: if stereo { 2 } else { 1 },
-            data: Some(data.to_vec())
+            data: data.to_vec()
         });
+        self.flush_stale();
     }
 
 }
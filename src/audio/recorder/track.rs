@@ -1,5 +1,6 @@
 // STD Dependencies -----------------------------------------------------------
 use std::cmp;
+use std::collections::BTreeMap;
 use std::sync::mpsc::Sender;
 
 
@@ -8,18 +9,42 @@ use discord::model::UserId;
 
 
 // Internal Dependencies ------------------------------------------------------
-use ::audio::mixer::compress;
+use ::audio::mixer::Compressor;
+use ::audio::timestamp::TimestampExtender;
+
+
+// Statics --------------------------------------------------------------------
+static FRAME_SAMPLES: i64 = 960;
+static FRAME_MILLIS: i64 = 20;
+static MAX_CONCEALED_FRAMES: i64 = 5;
+static CONCEALMENT_DECAY: f32 = 0.5;
+static RESYNC_MILLIS: i64 = 10_000;
+
+// Extended timestamps start far away from zero so that packets which arrive
+// before the very first one never produce negative frame numbers
+static TIMESTAMP_BASE: i64 = 1 << 40;
 
 
 // Audio Track Implementation -------------------------------------------------
+//
+// Buffers the voice packets of a single member for `latency` milliseconds in
+// order to put them back into order, drops duplicated or late packets and
+// conceals short gaps caused by packet loss before the audio is written out
+// in chunks.
 pub struct Track {
     user_id: Option<UserId>,
     chunk_duration: u32,
+    latency: u32,
     write_queue: Sender<Option<Chunk>>,
     started: u64,
-    voice_packets: Vec<VoicePacket>,
-    start_timestamp: Option<u32>,
-    last_chunk_timestamp: u32
+    voice_packets: BTreeMap<i64, VoicePacket>,
+    timestamps: TimestampExtender,
+    origin: Option<i64>,
+    next_frame: Option<i64>,
+    last_frame: Option<(usize, Vec<i16>)>,
+    pending: Option<PendingChunk>,
+    first_start: Option<u32>,
+    written_end: u32
 }
 
 impl Track {
@@ -27,17 +52,24 @@ impl Track {
     pub fn new(
         started: u64,
         chunk_duration: u32,
+        latency: u32,
         write_queue: Sender<Option<Chunk>>
 
     ) -> Track {
         Track {
             user_id: None,
             chunk_duration: chunk_duration,
+            latency: latency,
             write_queue: write_queue,
             started: started,
-            voice_packets: Vec::new(),
-            last_chunk_timestamp: 0,
-            start_timestamp: None
+            voice_packets: BTreeMap::new(),
+            timestamps: TimestampExtender::new(TIMESTAMP_BASE),
+            origin: None,
+            next_frame: None,
+            last_frame: None,
+            pending: None,
+            first_start: None,
+            written_end: 0
         }
     }
 
@@ -45,115 +77,216 @@ impl Track {
         self.user_id = Some(*user_id) ;
     }
 
-    pub fn add_voice_packet(&mut self, packet: VoicePacket) {
-        self.voice_packets.push(packet);
-        self.write_chunks(1000);
+    pub fn user_id(&self) -> Option<UserId> {
+        self.user_id
     }
 
-    pub fn flush(&mut self) {
-        self.write_chunks(0);
+    pub fn clear(&mut self) {
+        self.voice_packets.clear();
+        self.last_frame = None;
+        self.pending = None;
     }
 
-    fn write_chunks(&mut self, split_duration: u32) {
-        if self.user_id.is_some() {
-            while let Some(chunk) = self.get_chunk(split_duration) {
-                self.write_queue.send(Some(chunk)).ok();
+    pub fn add_voice_packet(&mut self, packet: VoicePacket) {
+
+        // Malformed packets are ignored instead of corrupting the track
+        if packet.data.is_empty() || packet.channels == 0 || packet.channels > 2
+            || packet.data.len() % packet.channels != 0 {
+            return;
+        }
+
+        let frame = self.timestamps.extend(packet.timestamp) / FRAME_SAMPLES;
+        let elapsed = packet.received.saturating_sub(self.started) as i64;
+
+        // Map the remote timestamps onto the local recording time once, and
+        // again whenever they drift too far away from it, e.g. after a client
+        // reset its RTP clock
+        let position = self.origin.map(|origin| frame * FRAME_MILLIS - origin);
+        if position.map_or(true, |position| (position - elapsed).abs() > RESYNC_MILLIS) {
+            if position.is_some() {
+                info!("[AudioTrack] Resynchronizing timestamps");
+                self.play_out(true);
             }
+            self.origin = Some(frame * FRAME_MILLIS - elapsed);
+            self.next_frame = None;
+        }
+
+        // Drop late packets for parts of the track which were already written
+        // as well as duplicates of buffered packets
+        if self.next_frame.map_or(false, |next| frame < next) || self.voice_packets.contains_key(&frame) {
+            return;
+        }
+
+        // Packets are kept until the member they belong to is known
+        self.voice_packets.insert(frame, packet);
+        if self.user_id.is_some() {
+            self.play_out(false);
         }
+
+    }
+
+    pub fn is_stale(&self, now: u64, max_age: u64) -> bool {
+        self.voice_packets.values().map(|p| p.received).min().map_or(false, |received| {
+            now.saturating_sub(received) > max_age
+        })
     }
 
-    fn get_chunk(&mut self, split_duration: u32) -> Option<Chunk> {
+    pub fn flush(&mut self) {
+        self.play_out(true);
+        self.write_chunk();
+    }
 
-        let min_split_packets = (((split_duration / 20) as f32) * 1.5) as usize;
-        if !self.voice_packets.is_empty() && self.voice_packets.len() > min_split_packets {
+}
 
-            // Sort voice packets
-            self.voice_packets.sort_by(|a, b| {
-                if a.sequence == b.sequence {
-                    cmp::Ordering::Equal
 
-                } else if seq_is_more_recent(a.sequence, b.sequence) {
-                    cmp::Ordering::Greater
+// Internal Interface ---------------------------------------------------------
+impl Track {
 
-                } else {
-                    cmp::Ordering::Less
-                }
-            });
+    fn play_out(&mut self, force: bool) {
 
-            // Extract timing from oldest voice packet in chunk
-            let (oldest_received, oldest_timestamp) = {
-                let oldest = self.voice_packets.first().unwrap();
-                (oldest.received, oldest.timestamp)
+        loop {
+
+            let (oldest, newest) = match (
+                self.voice_packets.keys().next(),
+                self.voice_packets.keys().next_back()
+            ) {
+                (Some(oldest), Some(newest)) => (*oldest, *newest),
+                _ => break
             };
-            assert!(oldest_received > self.started);
 
-            if let Some(start_timestamp) = self.start_timestamp {
-                assert!(start_timestamp < oldest_timestamp);
+            // Keep packets around until the buffer covers the full latency
+            if !force && (newest - oldest) * FRAME_MILLIS < i64::from(self.latency) {
+                break;
+            }
 
-            } else {
-                // Convert local u64 receival into remote timestamp value
-                self.last_chunk_timestamp = oldest_timestamp - (oldest_received - self.started) as u32;
-                self.start_timestamp = Some(oldest_timestamp);
+            let packet = match self.voice_packets.remove(&oldest) {
+                Some(packet) => packet,
+                None => break
+            };
+
+            // Short gaps are most likely lost packets and get filled with a
+            // fading copy of the previous frame, longer ones are silence
+            let gap = self.next_frame.map_or(0, |next| oldest - next);
+            if gap > 0 && gap <= MAX_CONCEALED_FRAMES {
+                self.conceal(oldest - gap, gap);
+
+            } else if gap > 0 {
+                self.last_frame = None;
             }
 
-            // Collect voice packets for the requested chunk duration
-            let mut last_packet_end = 0;
-            let mut chunk_packets = Vec::new();
-            for packet in &mut self.voice_packets {
-
-                // Calculate offset from initial packet
-                let offset = (packet.timestamp - oldest_timestamp) as u32;
-
-                // Break out once we collected `chunk_duration` packets
-                if offset >= self.chunk_duration {
-                    break;
-
-                } else {
-                    // There tends to be too much silence detected between
-                    // adjacents the packets which produces a lot of stutter
-                    // in the recording, so we want to "smooth" it out by removing
-                    // up to 80ms of additional silence
-                    let silence = cmp::max(((offset - last_packet_end) as i32) - 80, 0) as u32;
-                    last_packet_end = offset + 20;
-                    chunk_packets.push(ChunkPacket {
-                        silence: silence,
-                        offset: offset,
-                        channels: packet.channels,
-                        data: packet.data.take().expect("Missing sample data for voice packet.")
-                    });
-                }
+            self.push_frame(oldest, packet.channels, packet.data.clone());
+            self.last_frame = Some((packet.channels, packet.data));
+            self.next_frame = Some(oldest + 1);
+
+        }
+
+        if force {
+            self.last_frame = None;
+        }
+
+    }
+
+    fn conceal(&mut self, first_frame: i64, count: i64) {
 
+        let (channels, mut data) = match self.last_frame.take() {
+            Some(frame) => frame,
+            None => return
+        };
+
+        for i in 0..count {
+            for sample in &mut data {
+                *sample = (f32::from(*sample) * CONCEALMENT_DECAY) as i16;
             }
+            self.push_frame(first_frame + i, channels, data.clone());
+        }
+
+    }
+
+    fn push_frame(&mut self, frame: i64, channels: usize, data: Vec<i16>) {
+
+        let origin = match self.origin {
+            Some(origin) => origin,
+            None => return
+        };
+
+        // Frames from before the recording started or overlapping audio which
+        // was already written cannot be placed on the track
+        let position = frame * FRAME_MILLIS - origin;
+        let end = self.pending.as_ref().map_or(self.written_end, |chunk| chunk.end);
+        if position < 0 || position > i64::from(u32::max_value()) - FRAME_MILLIS || (position as u32) < end {
+            return;
+        }
+
+        let position = position as u32;
+        if self.pending.as_ref().map_or(false, |chunk| position - chunk.start >= self.chunk_duration) {
+            self.write_chunk();
+        }
+
+        if self.pending.is_none() {
+            self.pending = Some(PendingChunk {
+                start: position,
+                end: position,
+                packets: Vec::new()
+            });
+        }
 
-            // Remove consumed voice packets
-            self.voice_packets.drain(0..chunk_packets.len()).count();
+        if let Some(chunk) = self.pending.as_mut() {
+            chunk.packets.push(ChunkPacket {
+                silence: position - chunk.end,
+                offset: position - chunk.start,
+                channels: channels,
+                data: data
+            });
+            chunk.end = position + FRAME_MILLIS as u32;
+        }
+
+    }
 
-            // Create chunk from collected voice packets
-            let chunk = Chunk {
-                user_id: self.user_id.unwrap(),
-                silence: cmp::max(((oldest_timestamp - self.last_chunk_timestamp) as i32) - 80, 0) as u32,
-                duration: last_packet_end,
-                offset: oldest_timestamp - self.start_timestamp.unwrap(),
-                packets: chunk_packets
+    fn write_chunk(&mut self) {
+
+        let chunk = match self.pending.take() {
+            Some(chunk) => chunk,
+            None => return
+        };
+
+        if let Some(user_id) = self.user_id {
+
+            // Silence in between chunks is written out in front of them
+            let (first_start, silence) = match self.first_start {
+                Some(first_start) => (first_start, chunk.start - cmp::min(self.written_end, chunk.start)),
+                None => (chunk.start, 0)
             };
 
-            self.last_chunk_timestamp += chunk.silence + chunk.duration;
+            self.first_start = Some(first_start);
+            self.written_end = chunk.end;
+            self.write_queue.send(Some(Chunk {
+                user_id: user_id,
+                silence: silence,
+                duration: chunk.end - chunk.start,
+                offset: chunk.start - first_start,
+                start: chunk.start,
+                packets: chunk.packets
 
-            Some(chunk)
+            })).ok();
 
-        } else {
-            None
         }
 
     }
 
 }
 
+struct PendingChunk {
+    start: u32,
+    end: u32,
+    packets: Vec<ChunkPacket>
+}
+
 pub struct Chunk {
     pub user_id: UserId,
     pub silence: u32,
     pub duration: u32,
     pub offset: u32,
+    pub start: u32,
     pub packets: Vec<ChunkPacket>
 }
 
@@ -171,12 +304,13 @@ impl ChunkPacket {
         let channels = self.channels;
         let mono_samples = self.data.len() / channels;
         let max_sample_value = f32::from(i16::max_value());
+        let compressor = Compressor::new(0.6);
 
         for e in 0..mono_samples {
             let i = e * channels;
             if channels == 2 {
-                let s = f32::from(self.data[i] + self.data[i + 1]);
-                self.data[e] = (compress(s / max_sample_value, 0.6) * max_sample_value) as i16;
+                let s = f32::from(self.data[i]) + f32::from(self.data[i + 1]);
+                self.data[e] = (compressor.apply(s / max_sample_value) * max_sample_value) as i16;
 
             } else {
                 self.data[e] = self.data[i];
@@ -190,19 +324,181 @@ impl ChunkPacket {
 }
 
 pub struct VoicePacket {
-    pub sequence: u16,
     pub timestamp: u32,
     pub received: u64,
     pub channels: usize,
-    pub data: Option<Vec<i16>>
+    pub data: Vec<i16>
 }
 
 
-// Helpers --------------------------------------------------------------------
-const MAX_SEQ_NUMBER: u16 = 65_535;
+// Tests ----------------------------------------------------------------------
+#[cfg(test)]
+mod test {
 
-fn seq_is_more_recent(a: u16, b: u16) -> bool {
-    (a > b) && (a - b <= MAX_SEQ_NUMBER / 2) ||
-    (b > a) && (b - a >  MAX_SEQ_NUMBER / 2)
-}
+    use std::sync::mpsc::channel;
+    use discord::model::UserId;
+    use super::{Track, VoicePacket};
 
+    static START: u64 = 1000;
+
+    fn packet(frame: u32, timestamp: u32, received: u64) -> VoicePacket {
+        VoicePacket {
+            timestamp: timestamp,
+            received: received,
+            channels: 1,
+            data: vec![100 * (frame as i16 + 1); 960]
+        }
+    }
+
+    // Packets for the given frames with timestamps starting at `first`
+    fn frames(first: u32, frames: &[u32]) -> Vec<VoicePacket> {
+        frames.iter().map(|frame| {
+            packet(*frame, first.wrapping_add(frame * 960), START + u64::from(*frame) * 20)
+
+        }).collect()
+    }
+
+    // Returns the position and first sample of every frame written out
+    fn play(packets: Vec<VoicePacket>) -> Vec<(u32, i16)> {
+
+        let (sender, receiver) = channel();
+        let mut track = Track::new(0, 60000, 100, sender);
+        track.set_user_id(&UserId(1));
+
+        for packet in packets {
+            track.add_voice_packet(packet);
+        }
+
+        track.flush();
+
+        let mut written = Vec::new();
+        while let Ok(Some(chunk)) = receiver.try_recv() {
+            assert_eq!(chunk.user_id, UserId(1));
+            for packet in chunk.packets {
+                assert_eq!(packet.data.len(), 960);
+                written.push((chunk.start + packet.offset, packet.data[0]));
+            }
+        }
+
+        written
+
+    }
+
+    fn expected(frames: &[u32]) -> Vec<(u32, i16)> {
+        frames.iter().map(|frame| {
+            (START as u32 + frame * 20, 100 * (*frame as i16 + 1))
+
+        }).collect()
+    }
+
+    #[test]
+    fn test_in_order() {
+        let all: Vec<u32> = (0..20).collect();
+        assert_eq!(play(frames(0, &all)), expected(&all));
+    }
+
+    #[test]
+    fn test_reordering() {
+        let all: Vec<u32> = (0..12).collect();
+        assert_eq!(
+            play(frames(0, &[1, 0, 2, 4, 3, 5, 8, 6, 7, 9, 11, 10])),
+            expected(&all)
+        );
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let all: Vec<u32> = (0..12).collect();
+
+        // Duplicates of buffered as well as of already written frames
+        assert_eq!(
+            play(frames(0, &[0, 1, 1, 2, 0, 3, 4, 5, 6, 7, 8, 9, 2, 10, 1, 11, 11])),
+            expected(&all)
+        );
+    }
+
+    #[test]
+    fn test_late_packets() {
+        let mut order: Vec<u32> = (0..12).filter(|f| *f != 2).collect();
+        order.push(2);
+
+        // Frame 2 is concealed once it is too late to be placed
+        let mut written = play(frames(0, &order));
+        assert_eq!(written.remove(2), (START as u32 + 40, 100));
+
+        let all: Vec<u32> = (0..12).filter(|f| *f != 2).collect();
+        assert_eq!(written, expected(&all));
+    }
+
+    #[test]
+    fn test_timestamp_wraparound() {
+        let all: Vec<u32> = (0..12).collect();
+        let first = u32::max_value() - 960 * 4 + 1;
+        assert_eq!(play(frames(first, &all)), expected(&all));
+        assert_eq!(play(frames(first, &[0, 1, 2, 4, 3, 5, 6, 7, 8, 10, 9, 11])), expected(&all));
+    }
+
+    #[test]
+    fn test_resync_after_timestamp_jump() {
+
+        // The remote clock jumps by 60 seconds while packets keep arriving
+        // in real time
+        let mut packets = frames(0, &[0, 1, 2, 3, 4, 5]);
+        for frame in 6..12 {
+            packets.push(packet(frame, 48000 * 60 + frame * 960, START + u64::from(frame) * 20));
+        }
+
+        let all: Vec<u32> = (0..12).collect();
+        assert_eq!(play(packets), expected(&all));
+
+    }
+
+    #[test]
+    fn test_no_resync_for_short_jumps() {
+
+        // Jumps of less than 10 seconds are kept as pauses in the track
+        let mut packets = frames(0, &[0, 1, 2, 3, 4, 5]);
+        for frame in 6..12 {
+            packets.push(packet(frame, 48000 * 5 + frame * 960, START + u64::from(frame) * 20));
+        }
+
+        let written = play(packets);
+        assert_eq!(&written[..6], &expected(&[0, 1, 2, 3, 4, 5])[..]);
+        assert_eq!(written[6], (START as u32 + 5000 + 6 * 20, 700));
+        assert_eq!(written.len(), 12);
+
+    }
+
+    #[test]
+    fn test_concealment() {
+        for missing in 1..6 {
+
+            let mut order = vec![0, 1, 2];
+            order.extend((3 + missing)..(3 + missing + 6));
+
+            let written = play(frames(0, &order));
+            assert_eq!(written.len(), 9 + missing as usize);
+
+            // Missing frames are filled with a fading copy of frame 2
+            let mut value = 300;
+            for i in 0..missing {
+                value /= 2;
+                assert_eq!(written[3 + i as usize], (START as u32 + (3 + i) * 20, value));
+            }
+
+            assert_eq!(&written[..3], &expected(&[0, 1, 2])[..]);
+            assert_eq!(&written[3 + missing as usize..], &expected(&order[3..])[..]);
+
+        }
+    }
+
+    #[test]
+    fn test_silence_gap() {
+
+        // Longer gaps are not concealed and stay silent
+        let order = vec![0, 1, 2, 9, 10, 11, 12, 13, 14];
+        assert_eq!(play(frames(0, &order)), expected(&order));
+
+    }
+
+}
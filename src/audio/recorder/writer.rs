@@ -0,0 +1,260 @@
+// STD Dependencies -----------------------------------------------------------
+use std::cmp;
+use std::fs;
+use std::thread;
+use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc::{Receiver, Sender};
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::UserId;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::audio::{Encoder, EncoderSettings, create_encoder};
+use super::RecorderEvent;
+use super::track::{Chunk, ChunkPacket};
+use super::mixdown::Mixdown;
+use super::manifest::{Manifest, ManifestTrack, RecordingSession};
+
+
+// Statics --------------------------------------------------------------------
+pub static MANIFEST_FILENAME: &str = "manifest.json";
+
+
+// Per User Track Stream ------------------------------------------------------
+struct TrackStream {
+    encoder: Option<Box<Encoder>>,
+    channels: usize,
+    file: String,
+    offset: Option<u32>,
+    samples: usize
+}
+
+impl TrackStream {
+
+    fn new(path: &PathBuf, user_id: UserId, settings: EncoderSettings) -> Result<TrackStream, String> {
+
+        let file = format!("{}.{}", user_id, settings.format.extension());
+        let file_path = path.join(&file);
+
+        info!("[AudioWriter] Creating file \"{}\"...", file_path.display());
+
+        let encoder = try!(create_encoder(&file_path, settings.format, settings.channels, settings.quality));
+        Ok(TrackStream {
+            encoder: Some(encoder),
+            channels: settings.channels,
+            file: file,
+            offset: None,
+            samples: 0
+        })
+
+    }
+
+    fn write_samples(&mut self, samples: &[i16]) -> Result<(), String> {
+        self.samples += samples.len();
+        if let Some(encoder) = self.encoder.as_mut() {
+            encoder.write_samples(samples)
+
+        } else {
+            Ok(())
+        }
+    }
+
+    fn write_packet(&mut self, packet: &mut ChunkPacket) -> Result<(), String> {
+        if self.channels == 1 {
+            self.write_samples(packet.mix_to_mono())
+
+        } else if packet.channels == 2 {
+            self.write_samples(&packet.data)
+
+        } else {
+            // Mono packets are written to both channels
+            let stereo: Vec<i16> = packet.data.iter().flat_map(|s| vec![*s, *s]).collect();
+            self.write_samples(&stereo)
+        }
+    }
+
+    fn write_silence(&mut self, silence_millis: usize) -> Result<(), String> {
+
+        let silence_buffer = [0i16; 960 * 2];
+        let mut remaining = silence_millis * 48 * self.channels;
+        while remaining > 0 {
+            let count = cmp::min(remaining, silence_buffer.len());
+            try!(self.write_samples(&silence_buffer[..count]));
+            remaining -= count;
+        }
+
+        Ok(())
+
+    }
+
+    fn close(&mut self) -> Result<(), String> {
+        if let Some(encoder) = self.encoder.take() {
+            encoder.finish()
+
+        } else {
+            Ok(())
+        }
+    }
+
+}
+
+
+// Recording Writer -----------------------------------------------------------
+pub fn spawn(
+    path: PathBuf,
+    started: String,
+    session: RecordingSession,
+    settings: EncoderSettings,
+    event_queue: Sender<RecorderEvent>,
+    write_queue: Receiver<Option<Chunk>>
+
+) -> thread::JoinHandle<()> {
+
+    thread::spawn(move || {
+
+        // Encoder failures are reported to the bot instead of taking down
+        // the whole recording
+        let report = |err: String| {
+            warn!("[AudioWriter] {}", err);
+            event_queue.send(RecorderEvent::EncoderError(err)).ok();
+        };
+
+        if let Err(err) = fs::create_dir_all(&path) {
+            report(format!("Failed to create recording directory: {}", err));
+            return;
+        }
+
+        info!("[AudioWiter] Created");
+
+        let mixdown_file = format!("mixdown.{}", settings.format.extension());
+        let mut mixdown = match Mixdown::new(&path.join(&mixdown_file), settings) {
+            Ok(mixdown) => Some(mixdown),
+            Err(err) => {
+                report(format!("Failed to create mixdown: {}", err));
+                None
+            }
+        };
+
+        let mut streams: HashMap<UserId, TrackStream> = HashMap::new();
+        let mut failed: HashSet<UserId> = HashSet::new();
+
+        while let Ok(Some(chunk)) = write_queue.recv() {
+
+            if failed.contains(&chunk.user_id) {
+                continue;
+            }
+
+            if !streams.contains_key(&chunk.user_id) {
+                match TrackStream::new(&path, chunk.user_id, settings) {
+                    Ok(stream) => {
+                        streams.insert(chunk.user_id, stream);
+                    },
+                    Err(err) => {
+                        report(format!("Failed to create track for User#{}: {}", chunk.user_id, err));
+                        failed.insert(chunk.user_id);
+                        continue;
+                    }
+                }
+            }
+
+            let stream = streams.get_mut(&chunk.user_id).unwrap();
+
+            info!(
+                "[AudioWriter] Received chunk for User#{} with {} packets @ {}ms with {}ms ({}ms silence infront)",
+                chunk.user_id,
+                chunk.packets.len(),
+                chunk.offset,
+                chunk.duration,
+                chunk.silence
+            );
+
+            if stream.offset.is_none() {
+                stream.offset = Some(chunk.start);
+            }
+
+            let mut result = stream.write_silence(chunk.silence as usize);
+            for mut packet in chunk.packets {
+
+                // Place the packet at its original position in the mixdown
+                let mixed = if let Some(mixdown) = mixdown.as_mut() {
+                    mixdown.add_samples(chunk.start + packet.offset, packet.channels, &packet.data)
+
+                } else {
+                    Ok(())
+                };
+
+                if let Err(err) = mixed {
+                    report(format!("Failed to encode mixdown: {}", err));
+                    mixdown = None;
+                }
+
+                if result.is_ok() {
+                    result = stream.write_silence(packet.silence as usize);
+                }
+
+                if result.is_ok() {
+                    result = stream.write_packet(&mut packet);
+                }
+
+            }
+
+            // Stop writing to a track once its encoder failed
+            if let Err(err) = result {
+                report(format!("Failed to encode track for User#{}: {}", chunk.user_id, err));
+                stream.encoder = None;
+            }
+
+        }
+
+        info!("[AudioWriter] Closing streams...");
+
+        let mut tracks = Vec::new();
+        for (user_id, mut stream) in streams {
+
+            if let Err(err) = stream.close() {
+                report(format!("Failed to close track for User#{}: {}", user_id, err));
+            }
+
+            tracks.push(ManifestTrack {
+                user_id: user_id.0,
+                nickname: session.nicknames.get(&user_id).cloned(),
+                file: stream.file,
+                offset: stream.offset.unwrap_or(0),
+                duration: (stream.samples / stream.channels / 48) as u32
+            });
+
+        }
+
+        tracks.sort_by_key(|track| track.offset);
+
+        let duration = match mixdown.map(|mixdown| mixdown.close()) {
+            Some(Ok(duration)) => duration,
+            Some(Err(err)) => {
+                report(format!("Failed to close mixdown: {}", err));
+                0
+            },
+            None => 0
+        };
+
+        let manifest = Manifest {
+            started: started,
+            duration: duration,
+            channel_id: session.channel_id.0,
+            channel_name: session.channel_name,
+            mixdown: mixdown_file,
+            tracks: tracks
+        };
+
+        if let Err(err) = manifest.write(&path.join(MANIFEST_FILENAME)) {
+            report(format!("Failed to write manifest: {}", err));
+        }
+
+        info!("[AudioWriter] Destroyed");
+
+    })
+
+}
+
@@ -0,0 +1,334 @@
+// STD Dependencies -----------------------------------------------------------
+use std::cmp;
+use std::f64::consts::PI;
+
+
+// Statics --------------------------------------------------------------------
+static PHASES: usize = 256;
+static HALF_TAPS: usize = 16;
+
+
+// Band-limited Resampler -----------------------------------------------------
+//
+// Converts a stream of interleaved samples between two sample rates using a
+// polyphase Blackman windowed-sinc filter. The filter's cutoff frequency can
+// be lowered at runtime in order to reduce the bandwidth of the output.
+pub struct Resampler {
+    input_rate: u32,
+    output_rate: u32,
+    channels: usize,
+    step: f64,
+    position: f64,
+    bandwidth: u32,
+    kernel: Vec<f32>,
+    history: Vec<Vec<f32>>
+}
+
+impl Resampler {
+
+    pub fn new(input_rate: u32, output_rate: u32, channels: usize) -> Result<Resampler, String> {
+
+        // A zero input rate would never advance the filter position
+        if input_rate == 0 || output_rate == 0 {
+            return Err(format!("Unsupported sample rate conversion {}Hz -> {}Hz.", input_rate, output_rate));
+
+        } else if channels == 0 {
+            return Err("Unsupported channel count of 0.".to_string());
+        }
+
+        let mut resampler = Resampler {
+            input_rate: input_rate,
+            output_rate: output_rate,
+            channels: channels,
+            step: f64::from(input_rate) / f64::from(output_rate),
+            position: (HALF_TAPS - 1) as f64,
+            bandwidth: 0,
+            kernel: Vec::new(),
+            history: vec![vec![0.0; HALF_TAPS - 1]; channels]
+        };
+
+        resampler.set_bandwidth(output_rate / 2);
+        Ok(resampler)
+
+    }
+
+    pub fn set_bandwidth(&mut self, bandwidth: u32) {
+        let bandwidth = cmp::min(bandwidth, self.nyquist());
+        if bandwidth != self.bandwidth {
+            self.bandwidth = bandwidth;
+            self.kernel = build_kernel(f64::from(bandwidth) / f64::from(self.input_rate));
+        }
+    }
+
+    pub fn process(&mut self, input: &[f32], output: &mut Vec<f32>) {
+
+        // Matching rates without a reduced bandwidth do not need any filtering
+        if self.is_passthrough() {
+            output.extend_from_slice(input);
+            return;
+        }
+
+        for frame in input.chunks(self.channels) {
+            for (channel, sample) in self.history.iter_mut().zip(frame.iter()) {
+                channel.push(*sample);
+            }
+        }
+
+        let taps = HALF_TAPS * 2;
+        let available = self.history[0].len();
+
+        while self.position as usize + HALF_TAPS < available {
+
+            let index = self.position as usize;
+            let fraction = self.position - index as f64;
+            let phase = (fraction * PHASES as f64).round() as usize;
+
+            let weights = &self.kernel[phase * taps..(phase + 1) * taps];
+            let start = index + 1 - HALF_TAPS;
+
+            for channel in &self.history {
+                output.push(channel[start..start + taps].iter().zip(weights.iter()).fold(0.0, |acc, (s, w)| {
+                    acc + s * w
+                }));
+            }
+
+            self.position += self.step;
+
+        }
+
+        // Discard all samples which are no longer covered by the filter
+        let consumed = (self.position as usize + 1).saturating_sub(HALF_TAPS);
+        if consumed > 0 {
+            for channel in &mut self.history {
+                channel.drain(0..cmp::min(consumed, available));
+            }
+            self.position -= consumed as f64;
+        }
+
+    }
+
+    pub fn flush(&mut self, output: &mut Vec<f32>) {
+        if !self.is_passthrough() {
+            let silence = vec![0.0; HALF_TAPS * self.channels];
+            self.process(&silence, output);
+        }
+    }
+
+}
+
+
+// Internal Interface ---------------------------------------------------------
+impl Resampler {
+
+    fn nyquist(&self) -> u32 {
+        cmp::min(self.input_rate, self.output_rate) / 2
+    }
+
+    fn is_passthrough(&self) -> bool {
+        self.input_rate == self.output_rate && self.bandwidth == self.nyquist()
+    }
+
+}
+
+
+// Channel Mapping ------------------------------------------------------------
+pub fn mix_to_stereo(input: &[f32], channels: usize, output: &mut Vec<f32>) {
+    match channels {
+        0 => {},
+        1 => for sample in input {
+            output.push(*sample);
+            output.push(*sample);
+        },
+        2 => output.extend_from_slice(input),
+
+        // Fold even channels into the left and odd channels into the right
+        // side, keeping the overall level
+        _ => for frame in input.chunks(channels) {
+            let (mut left, mut right) = (0.0, 0.0);
+            for (index, sample) in frame.iter().enumerate() {
+                if index % 2 == 0 {
+                    left += *sample;
+
+                } else {
+                    right += *sample;
+                }
+            }
+            output.push(left / ((channels + 1) / 2) as f32);
+            output.push(right / (channels / 2) as f32);
+        }
+    }
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn build_kernel(cutoff: f64) -> Vec<f32> {
+
+    let taps = HALF_TAPS * 2;
+    let mut kernel = Vec::with_capacity((PHASES + 1) * taps);
+
+    for phase in 0..PHASES + 1 {
+
+        let fraction = phase as f64 / PHASES as f64;
+        let weights: Vec<f64> = (0..taps).map(|tap| {
+            let x = (tap + 1) as f64 - HALF_TAPS as f64 - fraction;
+            2.0 * cutoff * sinc(2.0 * cutoff * x) * blackman(x / HALF_TAPS as f64)
+
+        }).collect();
+
+        // Normalize every phase to unity gain
+        let sum = weights.iter().fold(0.0, |acc, w| acc + w);
+        kernel.extend(weights.into_iter().map(|w| (w / sum) as f32));
+
+    }
+
+    kernel
+
+}
+
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-9 {
+        1.0
+
+    } else {
+        (PI * x).sin() / (PI * x)
+    }
+}
+
+fn blackman(x: f64) -> f64 {
+    if x.abs() >= 1.0 {
+        0.0
+
+    } else {
+        0.42 + 0.5 * (PI * x).cos() + 0.08 * (2.0 * PI * x).cos()
+    }
+}
+
+
+
+// Tests ----------------------------------------------------------------------
+#[cfg(test)]
+mod test {
+
+    use std::f64::consts::PI;
+    use super::{Resampler, mix_to_stereo};
+
+    static OUTPUT_RATE: u32 = 48000;
+    static AMPLITUDE: f64 = 0.5;
+
+    fn resample_sine(input_rate: u32, frequency: f64, bandwidth: Option<u32>) -> Vec<f32> {
+
+        let input: Vec<f32> = (0..input_rate / 10).map(|i| {
+            (AMPLITUDE * (2.0 * PI * frequency * f64::from(i) / f64::from(input_rate)).sin()) as f32
+
+        }).collect();
+
+        let mut resampler = Resampler::new(input_rate, OUTPUT_RATE, 1).unwrap();
+        if let Some(bandwidth) = bandwidth {
+            resampler.set_bandwidth(bandwidth);
+        }
+
+        let mut output = Vec::new();
+        for block in input.chunks(1000) {
+            resampler.process(block, &mut output);
+        }
+        resampler.flush(&mut output);
+        output
+
+    }
+
+    // Fits a sine of the given frequency to the output, ignoring the filter's
+    // ramp up and down at both ends, and returns its amplitude together with
+    // the level of everything else relative to it in dB
+    fn analyze(output: &[f32], frequency: f64) -> (f64, f64) {
+
+        let skip = 200;
+        let samples = &output[skip..output.len() - skip];
+        let count = samples.len() as f64;
+
+        let (mut a, mut b) = (0.0, 0.0);
+        for (i, s) in samples.iter().enumerate() {
+            let t = 2.0 * PI * frequency * (i + skip) as f64 / f64::from(OUTPUT_RATE);
+            a += f64::from(*s) * t.sin();
+            b += f64::from(*s) * t.cos();
+        }
+
+        let (a, b) = (2.0 * a / count, 2.0 * b / count);
+        let residual = samples.iter().enumerate().fold(0.0, |acc, (i, s)| {
+            let t = 2.0 * PI * frequency * (i + skip) as f64 / f64::from(OUTPUT_RATE);
+            let r = f64::from(*s) - a * t.sin() - b * t.cos();
+            acc + r * r
+
+        }) / count;
+
+        let amplitude = a.hypot(b);
+        (amplitude, 20.0 * (residual.sqrt() / (amplitude / 2.0f64.sqrt())).log10())
+
+    }
+
+    #[test]
+    fn test_upsampling_sweep() {
+        for input_rate in &[44100, 22050, 16000] {
+
+            // Output length follows the rate ratio
+            let output = resample_sine(*input_rate, 1000.0, None);
+            let expected = (OUTPUT_RATE / 10) as usize;
+            assert!(output.len() >= expected && output.len() <= expected + 1);
+
+            for fraction in &[0.05, 0.1, 0.2, 0.3, 0.4] {
+                let frequency = f64::from(*input_rate) * fraction;
+                let (amplitude, noise) = analyze(&resample_sine(*input_rate, frequency, None), frequency);
+                assert!(
+                    (amplitude - AMPLITUDE).abs() < AMPLITUDE * 0.01,
+                    "{}Hz sine @ {}Hz: amplitude {}", frequency, input_rate, amplitude
+                );
+                assert!(
+                    noise < -45.0,
+                    "{}Hz sine @ {}Hz: aliasing at {}dB", frequency, input_rate, noise
+                );
+            }
+
+        }
+    }
+
+    #[test]
+    fn test_reduced_bandwidth() {
+
+        let (amplitude, _) = analyze(&resample_sine(OUTPUT_RATE, 1000.0, Some(4000)), 1000.0);
+        assert!((amplitude - AMPLITUDE).abs() < AMPLITUDE * 0.01);
+
+        let (amplitude, _) = analyze(&resample_sine(OUTPUT_RATE, 10000.0, Some(4000)), 10000.0);
+        assert!(amplitude < AMPLITUDE * 0.01);
+
+    }
+
+    #[test]
+    fn test_passthrough() {
+        let output = resample_sine(OUTPUT_RATE, 1000.0, None);
+        assert_eq!(output.len(), (OUTPUT_RATE / 10) as usize);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(Resampler::new(0, OUTPUT_RATE, 2).is_err());
+        assert!(Resampler::new(OUTPUT_RATE, 0, 2).is_err());
+        assert!(Resampler::new(OUTPUT_RATE, OUTPUT_RATE, 0).is_err());
+    }
+
+    #[test]
+    fn test_mix_to_stereo() {
+
+        let mut output = Vec::new();
+        mix_to_stereo(&[0.5, -0.5], 1, &mut output);
+        assert_eq!(output, vec![0.5, 0.5, -0.5, -0.5]);
+
+        output.clear();
+        mix_to_stereo(&[0.25, 0.5, 0.75], 3, &mut output);
+        assert_eq!(output, vec![0.5, 0.5]);
+
+        output.clear();
+        mix_to_stereo(&[0.5, 0.5], 0, &mut output);
+        assert!(output.is_empty());
+
+    }
+
+}
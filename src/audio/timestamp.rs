@@ -0,0 +1,56 @@
+// RTP Timestamp Extension ----------------------------------------------------
+//
+// RTP timestamps are 32 bit values which wrap around after roughly a day of
+// 48kHz audio. They are extended into a monotonic 64 bit range relative to the
+// most recent one by interpreting the difference as a signed value, which
+// also places reordered packets before their successors.
+pub struct TimestampExtender {
+    base: i64,
+    reference: Option<(u32, i64)>
+}
+
+impl TimestampExtender {
+
+    pub fn new(base: i64) -> TimestampExtender {
+        TimestampExtender {
+            base: base,
+            reference: None
+        }
+    }
+
+    pub fn extend(&mut self, timestamp: u32) -> i64 {
+
+        let extended = match self.reference {
+            Some((raw, extended)) => extended + i64::from(timestamp.wrapping_sub(raw) as i32),
+            None => self.base + i64::from(timestamp)
+        };
+
+        if self.reference.map_or(true, |(_, reference)| extended > reference) {
+            self.reference = Some((timestamp, extended));
+        }
+
+        extended
+
+    }
+
+}
+
+
+// Tests ----------------------------------------------------------------------
+#[cfg(test)]
+mod test {
+
+    use super::TimestampExtender;
+
+    #[test]
+    fn test_extend_wraparound() {
+        let mut extender = TimestampExtender::new(0);
+        let first = u32::max_value() - 959;
+        assert_eq!(extender.extend(first), i64::from(first));
+        assert_eq!(extender.extend(first.wrapping_add(1920)), i64::from(first) + 1920);
+        assert_eq!(extender.extend(first.wrapping_add(960)), i64::from(first) + 960);
+        assert_eq!(extender.extend(first.wrapping_add(2880)), i64::from(first) + 2880);
+    }
+
+}
+
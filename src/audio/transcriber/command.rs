@@ -0,0 +1,174 @@
+// STD Dependencies -----------------------------------------------------------
+use std::thread;
+use std::time::{Duration, Instant};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::{Segment, Transcriber};
+
+
+// Statics --------------------------------------------------------------------
+static INPUT_PLACEHOLDER: &str = "{input}";
+static TIMEOUT_SECONDS: u64 = 60 * 60;
+static POLL_INTERVAL_MILLIS: u64 = 250;
+
+
+// Command Line Transcriber ---------------------------------------------------
+//
+// Invokes a local whisper.cpp style binary and parses the timestamped
+// segments it prints, e.g. "[00:00:01.240 --> 00:00:03.900]  Hello there".
+pub struct CommandLineTranscriber {
+    path: PathBuf,
+    arguments: Vec<String>
+}
+
+impl CommandLineTranscriber {
+    pub fn new(path: PathBuf, arguments: Vec<String>) -> CommandLineTranscriber {
+        CommandLineTranscriber {
+            path: path,
+            arguments: arguments
+        }
+    }
+}
+
+impl CommandLineTranscriber {
+
+    fn run(&self, arguments: &[String]) -> Result<(ExitStatus, String, String), String> {
+
+        let mut child = try!(Command::new(&self.path)
+            .args(arguments)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("Failed to run {}: {}", self.path.display(), err)));
+
+        // Both pipes are drained on their own threads so the child never
+        // blocks on a full buffer while we wait for it
+        let stdout = read_pipe(child.stdout.take());
+        let stderr = read_pipe(child.stderr.take());
+
+        let deadline = Instant::now() + Duration::from_secs(TIMEOUT_SECONDS);
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break Ok(status),
+                Ok(None) => if Instant::now() >= deadline {
+                    child.kill().ok();
+                    child.wait().ok();
+                    break Err(format!(
+                        "{} did not finish within {} minutes",
+                        self.path.display(),
+                        TIMEOUT_SECONDS / 60
+                    ));
+
+                } else {
+                    thread::sleep(Duration::from_millis(POLL_INTERVAL_MILLIS));
+                },
+                Err(err) => {
+                    child.kill().ok();
+                    break Err(format!("Failed to wait for {}: {}", self.path.display(), err));
+                }
+            }
+        };
+
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+        status.map(|status| (status, stdout, stderr))
+
+    }
+
+}
+
+impl Transcriber for CommandLineTranscriber {
+
+    fn transcribe(&self, path: &Path) -> Result<Vec<Segment>, String> {
+
+        let input = try!(path.to_str().ok_or_else(|| "Invalid input path.".to_string()));
+
+        // The input file replaces its placeholder or is passed last
+        let mut arguments: Vec<String> = self.arguments.iter().map(|arg| {
+            arg.replace(INPUT_PLACEHOLDER, input)
+
+        }).collect();
+
+        if !self.arguments.iter().any(|arg| arg.contains(INPUT_PLACEHOLDER)) {
+            arguments.push(input.to_string());
+        }
+
+        let (status, stdout, stderr) = try!(self.run(&arguments));
+        if status.success() {
+            Ok(stdout.lines().filter_map(parse_segment).collect())
+
+        } else {
+            Err(format!(
+                "{} exited with {}: {}",
+                self.path.display(),
+                status,
+                stderr.trim()
+            ))
+        }
+
+    }
+
+    fn name(&self) -> &str {
+        self.path.file_name().and_then(|name| name.to_str()).unwrap_or("transcriber")
+    }
+
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_end(&mut bytes).ok();
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+fn parse_segment(line: &str) -> Option<Segment> {
+
+    let line = line.trim();
+    if !line.starts_with('[') {
+        return None;
+    }
+
+    let end = match line.find(']') {
+        Some(end) => end,
+        None => return None
+    };
+
+    // Only the start of each segment is kept
+    line[1..end].split("-->").next().and_then(|time| parse_time(time.trim())).map(|start| {
+        Segment {
+            start: start,
+            text: line[end + 1..].trim().to_string()
+        }
+    })
+
+}
+
+fn parse_time(text: &str) -> Option<u32> {
+
+    // Either hh:mm:ss.mmm or mm:ss.mmm, with "," as an alternative separator
+    let (clock, millis) = match text.rfind(|c: char| c == '.' || c == ',') {
+        Some(index) => (&text[..index], &text[index + 1..]),
+        None => (text, "0")
+    };
+
+    let mut seconds = 0;
+    for part in clock.split(':') {
+        match part.parse::<u32>() {
+            Ok(value) => seconds = seconds * 60 + value,
+            Err(_) => return None
+        }
+    }
+
+    millis.parse::<u32>().ok().map(|millis| seconds * 1000 + millis)
+
+}
+
@@ -0,0 +1,192 @@
+// STD Dependencies -----------------------------------------------------------
+use std::cmp;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+
+// External Dependencies ------------------------------------------------------
+use hound::{SampleFormat, WavSpec, WavWriter};
+
+
+// Modules --------------------------------------------------------------------
+mod command;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::audio::{AudioFormat, Resampler, open_decoder};
+use ::audio::{RecordingManifest, RECORDING_MANIFEST_FILENAME};
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::command::CommandLineTranscriber;
+
+
+// Statics --------------------------------------------------------------------
+pub static TRANSCRIPT_FILENAME: &str = "transcript.txt";
+pub static TRANSCRIPT_FAILED_FILENAME: &str = "transcript.failed";
+static TRANSCRIBE_SAMPLE_RATE: u32 = 16000;
+static READ_SAMPLES: usize = 48000;
+
+
+// Transcription Engine Abstraction -------------------------------------------
+pub struct Segment {
+    pub start: u32,
+    pub text: String
+}
+
+pub trait Transcriber: Send {
+
+    // Transcribes a 16kHz mono WAV file, segment times are relative to the
+    // start of the file
+    fn transcribe(&self, path: &Path) -> Result<Vec<Segment>, String>;
+
+    fn name(&self) -> &str;
+
+}
+
+
+// Session Transcript ---------------------------------------------------------
+pub struct TranscriptLine {
+    pub offset: u32,
+    pub speaker: String,
+    pub text: String
+}
+
+pub struct Transcript {
+    pub lines: Vec<TranscriptLine>
+}
+
+impl Transcript {
+
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+
+        let text: Vec<String> = self.lines.iter().map(|line| {
+            format!("[{}] {}: {}", format_timestamp(line.offset), line.speaker, line.text)
+
+        }).collect();
+
+        let mut file = try!(File::create(path).map_err(|err| err.to_string()));
+        file.write_all(text.join("\n").as_bytes()).map_err(|err| err.to_string())
+
+    }
+
+}
+
+
+// Transcription Pipeline -----------------------------------------------------
+pub fn transcribe_session(
+    transcriber: &Transcriber,
+    session_path: &Path
+
+) -> Result<Transcript, String> {
+
+    let manifest = try!(RecordingManifest::read(&session_path.join(RECORDING_MANIFEST_FILENAME)));
+
+    let mut lines = Vec::new();
+    for track in &manifest.tracks {
+
+        let speaker = track.nickname.clone().unwrap_or_else(|| {
+            format!("User#{}", track.user_id)
+        });
+
+        // Engines expect plain 16kHz mono audio
+        let wav_path = session_path.join(format!("{}.transcribe.wav", track.user_id));
+        let segments = convert_track(
+            &session_path.join(&track.file),
+            &wav_path
+
+        ).and_then(|_| {
+            transcriber.transcribe(&wav_path)
+        });
+
+        fs::remove_file(&wav_path).ok();
+
+        // Segments are shifted onto the timeline of the whole session
+        for segment in try!(segments) {
+            let text = segment.text.trim().to_string();
+            if !text.is_empty() {
+                lines.push(TranscriptLine {
+                    offset: track.offset + segment.start,
+                    speaker: speaker.to_string(),
+                    text: text
+                });
+            }
+        }
+
+    }
+
+    lines.sort_by(|a, b| a.offset.cmp(&b.offset));
+
+    Ok(Transcript {
+        lines: lines
+    })
+
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn convert_track(input: &PathBuf, output: &PathBuf) -> Result<(), String> {
+
+    let format = try!(input.extension().and_then(|ext| ext.to_str()).and_then(|ext| {
+        AudioFormat::from_extension(ext)
+
+    }).ok_or_else(|| "Unsupported track format.".to_string()));
+
+    let mut stream = try!(open_decoder(input, format));
+    let info = stream.info();
+    let channels = cmp::max(usize::from(info.channels), 1);
+    let scale = 2.0f32.powi(i32::from(info.bits_per_sample) - 1);
+
+    let spec = WavSpec {
+        channels: 1,
+        sample_rate: TRANSCRIBE_SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: SampleFormat::Int
+    };
+
+    let mut writer = try!(WavWriter::create(output, spec).map_err(|err| err.to_string()));
+    let mut resampler = try!(Resampler::new(info.sample_rate, TRANSCRIBE_SAMPLE_RATE, 1));
+    let mut samples: Vec<i64> = Vec::with_capacity(READ_SAMPLES * channels);
+    let mut mono: Vec<f32> = Vec::with_capacity(READ_SAMPLES);
+    let mut resampled: Vec<f32> = Vec::new();
+
+    loop {
+
+        samples.clear();
+        samples.extend(stream.by_ref().take(READ_SAMPLES * channels));
+
+        // Down mix all channels into a single one
+        mono.clear();
+        mono.extend(samples.chunks(channels).map(|frame| {
+            frame.iter().map(|s| *s as f32 / scale).sum::<f32>() / frame.len() as f32
+        }));
+
+        resampled.clear();
+        resampler.process(&mono, &mut resampled);
+
+        let exhausted = samples.len() < READ_SAMPLES * channels;
+        if exhausted {
+            resampler.flush(&mut resampled);
+        }
+
+        for s in &resampled {
+            let sample = (s.max(-1.0).min(1.0) * 32767.0) as i16;
+            try!(writer.write_sample(sample).map_err(|err| err.to_string()));
+        }
+
+        if exhausted {
+            break;
+        }
+
+    }
+
+    writer.finalize().map_err(|err| err.to_string())
+
+}
+
+fn format_timestamp(millis: u32) -> String {
+    let seconds = millis / 1000;
+    format!("{:02}:{:02}:{:02}", seconds / 3600, (seconds / 60) % 60, seconds % 60)
+}
+
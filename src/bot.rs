@@ -15,8 +15,8 @@ use discord::model::{
 // Internal Dependencies ------------------------------------------------------
 use upload::Upload;
 use command::Command;
-use action::{ActionGroup, TimedActions, TwitchActions};
-use audio::MixerEvent;
+use action::{ActionGroup, MessageActions, RecordingActions, TimedActions, TwitchActions};
+use audio::{MixerEvent, RecorderEvent};
 use server::Server;
 use core::{
     Channel,
@@ -26,11 +26,17 @@ use core::{
 };
 
 
+// Statics --------------------------------------------------------------------
+static RECORDING_CLEANUP_INTERVAL_MILLIS: u64 = 60 * 60 * 1000;
+static RECORDING_TRANSCRIBE_INTERVAL_MILLIS: u64 = 5 * 60 * 1000;
+
+
 // Bot Configuration ----------------------------------------------------------
 pub struct BotConfig {
     pub bot_nickname: String,
     pub server_whitelist: Vec<ServerId>,
     pub config_path: PathBuf,
+    pub database_url: String,
     pub twitch_client_id: String,
     pub twitch_update_interval: u64,
     pub effect_playback_separation_ms: u64,
@@ -46,6 +52,7 @@ impl Default for BotConfig {
             bot_nickname: "".to_string(),
             server_whitelist: Vec::new(),
             config_path: PathBuf::from(""),
+            database_url: "".to_string(),
             twitch_client_id: "".to_string(),
             twitch_update_interval: 0,
             effect_playback_separation_ms: 0,
@@ -68,12 +75,16 @@ pub struct Bot {
 impl Bot {
 
     pub fn create(token: String, config: BotConfig) {
+        Bot::create_with_queue(EventQueue::new(token), config);
+    }
+
+    pub fn create_with_queue(queue: EventQueue, config: BotConfig) {
 
         let bot = Bot {
             servers: HashMap::new()
         };
 
-        bot.run(token, config);
+        bot.run(queue, config);
 
     }
 
@@ -87,9 +98,8 @@ impl Bot {
 // Internal Interface ---------------------------------------------------------
 impl Bot {
 
-    fn run(mut self, token: String, config: BotConfig) {
+    fn run(mut self, mut queue: EventQueue, config: BotConfig) {
 
-        let mut queue = EventQueue::new(token);
         let mut actions: ActionGroup = vec![];
 
         'main: loop {
@@ -172,17 +182,54 @@ impl Bot {
                     }
 
                 } else if config.server_whitelist.contains(&server_id) {
-                    let server = Server::from_possible_server(server, config, queue);
-                    self.servers.insert(server.id, server);
-
-                    if config.twitch_update_interval > 0 && !config.twitch_client_id.is_empty() {
-                        return vec![
-                            TimedActions::Periodic::new(
-                                config.twitch_update_interval * 1000,
+                    let mut server = Server::from_possible_server(server, config, queue);
+
+                    // Twitch checks are stored like any other scheduled action
+                    // but follow the configured update interval
+                    let twitch_interval = if config.twitch_update_interval > 0 && !config.twitch_client_id.is_empty() {
+                        Some(config.twitch_update_interval * 1000)
+
+                    } else {
+                        None
+                    };
+
+                    let schedule_twitch = server.sync_scheduled_action(
+                        TwitchActions::OnlineCheck::KIND,
+                        twitch_interval
+                    );
+
+                    // Resume any actions which were scheduled before a restart
+                    let mut actions = server.restore_scheduled_actions();
+                    if let (true, Some(interval)) = (schedule_twitch, twitch_interval) {
+                        actions.push(
+                            TimedActions::Scheduled::new(
+                                server_id,
+                                0,
+                                interval,
+                                server.get_bot().map_or(UserId(0), |bot| bot.id),
+                                "Twitch online check".to_string(),
                                 TwitchActions::OnlineCheck::new(server_id)
                             )
-                        ];
+                        );
                     }
+
+                    self.servers.insert(server.id, server);
+
+                    actions.push(
+                        TimedActions::Periodic::new(
+                            RECORDING_CLEANUP_INTERVAL_MILLIS,
+                            RecordingActions::Cleanup::new(server_id)
+                        )
+                    );
+
+                    actions.push(
+                        TimedActions::Periodic::new(
+                            RECORDING_TRANSCRIBE_INTERVAL_MILLIS,
+                            RecordingActions::Transcribe::new(server_id)
+                        )
+                    );
+
+                    return actions;
                 }
 
             },
@@ -199,12 +246,29 @@ impl Bot {
                     server.add_member(member, config);
                 }
             }
+            DiscordEvent::ServerMemberUpdate { server_id, roles, user, .. } => {
+                if let Some(server) = self.servers.get_mut(&server_id) {
+                    server.update_member_roles(&user.id, roles);
+                }
+            },
             DiscordEvent::ServerMemberRemove(server_id, user) => {
                 if let Some(server) = self.servers.get_mut(&server_id) {
                     server.remove_member_from_user(user);
                 }
             },
 
+            DiscordEvent::ServerRoleCreate(server_id, role) |
+            DiscordEvent::ServerRoleUpdate(server_id, role) => {
+                if let Some(server) = self.servers.get_mut(&server_id) {
+                    server.update_role(role.id, role.name);
+                }
+            },
+            DiscordEvent::ServerRoleDelete(server_id, role_id) => {
+                if let Some(server) = self.servers.get_mut(&server_id) {
+                    server.remove_role(&role_id);
+                }
+            },
+
 
             // Channel Related Events
             DiscordEvent::ChannelCreate(channel) => {
@@ -281,8 +345,7 @@ impl Bot {
                     if let Some(server) = self.servers.get_mut(&server_id) {
                         return server.update_member_voice_state(
                             voice_state,
-                            queue,
-                            config
+                            queue
                         )
                     }
                 }
@@ -303,16 +366,32 @@ impl Bot {
 
     ) -> ActionGroup {
 
+        // Pick up configuration changes from disk
+        for server in self.servers.values_mut() {
+            server.reload_config();
+        }
+
+        // Report failures of active recordings and apply the automatic
+        // recording rules of all servers
+        let mut actions: ActionGroup = Vec::new();
+        for server in self.servers.values_mut() {
+            for event in server.recorder_events() {
+                actions.append(&mut recorder_event(server, event));
+            }
+            actions.append(&mut server.apply_recording_rules());
+        }
+
         // Fetch mixer events from all servers
         let events: Vec<MixerEvent> = self.servers.values().map(|server| {
             server.events()
 
         }).flat_map(|e| e).collect();
 
-        events.into_iter().flat_map(|event| {
+        actions.extend(events.into_iter().flat_map(|event| {
             self.mixer_event(event, config, queue)
+        }));
 
-        }).collect()
+        actions
 
     }
 
@@ -403,12 +482,12 @@ impl Bot {
             },
             MessageContent::Upload(attachment, message) => {
                 if let Some((
-                    _,
+                    server,
                     member
 
                 )) = self.get_server_and_member(&message) {
                     Upload::from_message(attachment, message)
-                           .process(member, bot_config)
+                           .process(member, server.config())
 
                 } else {
                     vec![]
@@ -464,3 +543,202 @@ impl Bot {
 
 }
 
+
+// Helpers --------------------------------------------------------------------
+fn recorder_event(server: &Server, event: RecorderEvent) -> ActionGroup {
+
+    let mut actions: ActionGroup = Vec::new();
+    if let RecorderEvent::EncoderError(err) = event {
+        warn!("[Bot] {} RecorderEvent encoder error: {}", server, err);
+        if let Some(channel_id) = server.recording_announce_channel_id() {
+            actions.push(MessageActions::Send::single_public_channel(
+                &channel_id,
+                format!("Audio recording error: {}", err)
+            ));
+        }
+    }
+
+    actions
+
+}
+
+
+
+// Tests ----------------------------------------------------------------------
+#[cfg(test)]
+mod test {
+
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Read;
+    use std::path::{Path, PathBuf};
+
+    use diesel::Connection;
+    use diesel::sqlite::SqliteConnection;
+    use diesel::connection::SimpleConnection;
+    use serde_json;
+    use clock_ticks;
+
+    use discord::model::{ChannelId, ServerId, GatewayEvent, Event as DiscordEvent};
+
+    use ::audio::FlacEncoder;
+    use ::core::{EventQueue, ReplayCall, ReplayHandle};
+    use super::{Bot, BotConfig};
+
+    static SERVER_CREATE: &str = r#"{
+        "id": "1",
+        "name": "Replay",
+        "icon": null,
+        "splash": null,
+        "owner_id": "10",
+        "region": "eu-central",
+        "afk_channel_id": null,
+        "afk_timeout": 300,
+        "verification_level": 0,
+        "default_message_notifications": 0,
+        "mfa_level": 0,
+        "joined_at": "2017-08-01T00:00:00.000000+00:00",
+        "large": false,
+        "unavailable": false,
+        "member_count": 2,
+        "features": [],
+        "emojis": [],
+        "presences": [],
+        "voice_states": [],
+        "roles": [
+            {"id": "1", "name": "@everyone", "color": 0, "hoist": false, "position": 0, "permissions": 0, "managed": false, "mentionable": false},
+            {"id": "5", "name": "Bot", "color": 0, "hoist": false, "position": 1, "permissions": 0, "managed": false, "mentionable": false}
+        ],
+        "members": [
+            {
+                "user": {"id": "10", "username": "Bot", "discriminator": "0001", "avatar": null, "bot": true},
+                "nick": null, "roles": ["5"], "joined_at": "2017-08-01T00:00:00.000000+00:00", "deaf": false, "mute": false
+            },
+            {
+                "user": {"id": "20", "username": "Member", "discriminator": "0002", "avatar": null},
+                "nick": null, "roles": [], "joined_at": "2017-08-01T00:00:00.000000+00:00", "deaf": false, "mute": false
+            }
+        ],
+        "channels": [
+            {"id": "100", "type": 0, "name": "general", "position": 0, "permission_overwrites": [], "topic": null, "last_message_id": null},
+            {
+                "id": "200", "type": 2, "name": "Voice", "position": 1, "bitrate": 64000, "user_limit": 0,
+                "permission_overwrites": [{"id": "5", "type": "role", "allow": 3145728, "deny": 0}]
+            }
+        ]
+    }"#;
+
+    static MEMBER_JOINS_VOICE: &str = r#"{
+        "guild_id": "1",
+        "channel_id": "200",
+        "user_id": "20",
+        "session_id": "replay",
+        "deaf": false,
+        "mute": false,
+        "self_deaf": false,
+        "self_mute": false,
+        "suppress": false
+    }"#;
+
+    fn dispatch(kind: &str, data: &str) -> DiscordEvent {
+        let payload = format!(r#"{{"op": 0, "s": 1, "t": "{}", "d": {}}}"#, kind, data);
+        match GatewayEvent::decode(serde_json::from_str(&payload).unwrap()).unwrap() {
+            GatewayEvent::Dispatch(_, event) => event,
+            _ => panic!("Expected a dispatch event")
+        }
+    }
+
+    // Creates a fresh configuration directory which is unique to the test
+    fn setup_config_path(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!(
+            "discord_bot_{}_{}",
+            name,
+            clock_ticks::precise_time_ns()
+        ));
+        fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    // Creates a fresh database with all migrations applied
+    fn setup_database(path: &Path, statements: &str) -> String {
+
+        let url = path.to_str().unwrap().to_string();
+        let connection = SqliteConnection::establish(&url).unwrap();
+        let mut migrations: Vec<PathBuf> = fs::read_dir(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("migrations")
+
+        ).unwrap().filter_map(|entry| entry.ok()).map(|entry| entry.path().join("up.sql")).collect();
+
+        migrations.sort();
+
+        for migration in migrations {
+            let mut sql = String::new();
+            File::open(&migration).unwrap().read_to_string(&mut sql).unwrap();
+            connection.batch_execute(&sql).unwrap();
+        }
+
+        connection.batch_execute(statements).unwrap();
+        url
+
+    }
+
+    // Writes a one second tone as a sound effect file
+    fn setup_effect(path: &Path) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let samples: Vec<i16> = (0..48_000).map(|i| {
+            ((i as f32 * 2.0 * ::std::f32::consts::PI / 109.0).sin() * 12000.0) as i16
+
+        }).collect();
+        let mut encoder = FlacEncoder::create(path, 1, 48_000).unwrap();
+        encoder.write_samples(&samples).unwrap();
+        encoder.finish().unwrap();
+    }
+
+    #[test]
+    fn test_member_joining_voice_is_greeted() {
+
+        let config_path = setup_config_path("greeting");
+        setup_effect(&config_path.join("1").join("effects").join("hello.flac"));
+
+        let database_url = setup_database(&config_path.join("bot.sqlite"), "
+            INSERT INTO effects (server_id, name, uploader, duration_ms, transcript) VALUES ('1', 'hello', '', 1000, '');
+            INSERT INTO greetings (server_id, nickname, effect_name) VALUES ('1', 'Member#0002', 'hello');
+        ");
+
+        // Members which are already connected on startup are not greeted, so
+        // the bot idles for a bit before the member joins
+        let transport = ReplayHandle::new()
+            .event(dispatch("GUILD_CREATE", SERVER_CREATE))
+            .idle(12)
+            .event(dispatch("VOICE_STATE_UPDATE", MEMBER_JOINS_VOICE));
+
+        let log = transport.log();
+
+        Bot::create_with_queue(EventQueue::from_transport(Box::new(transport)), BotConfig {
+            bot_nickname: "Bot#0001".to_string(),
+            server_whitelist: vec![ServerId(1)],
+            config_path: config_path.clone(),
+            database_url: database_url,
+            .. BotConfig::default()
+        });
+
+        // The greeting makes the bot join the member's channel and hand the
+        // effect to its mixer
+        let mut log = log.borrow_mut();
+        assert!(log.calls.contains(&ReplayCall::ConnectVoice(ServerId(1), ChannelId(200))));
+        assert!(log.has_voice_source(&ServerId(1)));
+
+        // Pull frames past the initial mixer delay until the effect is heard
+        let mut buffer = [0i16; 960 * 2];
+        let audible = (0..500).any(|_| {
+            log.read_voice_frame(&ServerId(1), &mut buffer).map_or(false, |count| {
+                buffer[..count].iter().any(|s| *s != 0)
+            })
+        });
+
+        fs::remove_dir_all(&config_path).ok();
+        assert!(audible, "The greeting never reached the mixer output");
+
+    }
+
+}
@@ -0,0 +1,114 @@
+// STD Dependencies -----------------------------------------------------------
+use std::ascii::AsciiExt;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::command::{Command, CommandHandler};
+use ::action::{ActionGroup, EffectActions, MessageActions};
+
+
+// Statics --------------------------------------------------------------------
+static DEFAULT_CLIP_SECONDS: u64 = 10;
+
+static USAGE_TEXT: &str = "Usage: `!clip [<seconds>] <effect_name>`
+
+Saves the most recent voice audio of the bot's current voice channel as a new sound effect, credited to you.
+
+- **`seconds`** - How far to look back, defaults to 10 seconds and is limited by the server's `clip_buffer_ms` setting.
+- **`effect_name`** - The name of the new effect.
+
+Members who opted out via `!record optout` are never included in clips.";
+
+
+// Command Implementation -----------------------------------------------------
+pub struct Handler;
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_min_arguments!(1);
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+
+        if command.arguments.len() > 2 {
+            return self.usage(command);
+        }
+
+        let (seconds, name) = if command.arguments.len() == 2 {
+            (command.arguments[0].parse::<u64>().ok(), &command.arguments[1])
+
+        } else {
+            (Some(DEFAULT_CLIP_SECONDS), &command.arguments[0])
+        };
+
+        let buffer_ms = command.server.clip_buffer_ms();
+        let max_seconds = buffer_ms.unwrap_or(0) / 1000;
+        let seconds = seconds.and_then(|s| if s > 0 && s <= max_seconds {
+            Some(s)
+
+        } else {
+            None
+        });
+        if !command.member.is_uploader && !command.member.is_admin {
+            MessageActions::Send::private(
+                &command.message,
+                "Only white listed users can create sound effects.".to_string()
+            )
+
+        } else if buffer_ms.is_none() {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Clips are not enabled on {} or the bot is not in a voice channel.",
+                    command.server.name
+                )
+            )
+
+        } else if name.len() < 2 || !name.chars().all(|c| c.is_ascii() && (c.is_alphanumeric() || c == '_')) {
+            MessageActions::Send::private(
+                &command.message,
+                "Effect names must be at least two characters long and may only contain letters, digits and `_`.".to_string()
+            )
+
+        } else if command.server.has_effect(name) {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "A sound effect named `{}` already exists on {}.",
+                    name,
+                    command.server.name
+                )
+            )
+
+        } else if let Some(seconds) = seconds {
+            vec![EffectActions::Clip::new(
+                command.message,
+                name.to_string(),
+                seconds * 1000,
+                command.member.nickname.to_string()
+            )]
+
+        } else {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Clips on {} can be between 1 and {} second(s) long.",
+                    command.server.name,
+                    max_seconds
+                )
+            )
+        }
+
+    }
+
+    fn help(&self) -> &str {
+        "Save the most recent voice audio as a new sound effect."
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
+    }
+
+}
+
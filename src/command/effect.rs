@@ -0,0 +1,164 @@
+// Internal Dependencies ------------------------------------------------------
+use ::effect::Effect;
+use ::command::{Command, CommandHandler};
+use ::action::{ActionGroup, EffectActions, MessageActions};
+
+
+// Statics --------------------------------------------------------------------
+static MAX_VOLUME_DB: f32 = 20.0;
+
+static USAGE_TEXT: &str = "Usage: `!effect <subcommand> <effect_name> ...`
+
+Adjusts how an existing sound effect is played back, without having to re-upload it.
+
+**Subcommands:**
+
+- `volume <effect_name> <db>` - Raise or lower the volume by the specified amount of decibels, e.g. `-6` or `3.5`.
+- `trim <effect_name> <start_ms> <end_ms>` - Skip the specified number of milliseconds at the start and end.
+- `fade <effect_name> <in_ms> <out_ms>` - Fade in at the start and out at the end over the specified number of milliseconds.
+- `reset <effect_name>` - Remove all adjustments.
+
+Only bot admins and uploaders can adjust sound effects.";
+
+
+// Command Implementation -----------------------------------------------------
+pub struct Handler;
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_min_arguments!(2);
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+
+        if !command.member.is_admin && !command.member.is_uploader {
+            return MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Only bot admins and uploaders can adjust sound effects on {}.",
+                    command.server.name
+                )
+            );
+        }
+
+        if let Some(effect) = command.server.get_effect(&command.arguments[1]) {
+            match command.arguments[0].as_str() {
+                "volume" => self.volume(&command, effect),
+                "trim" => self.trim(&command, effect),
+                "fade" => self.fade(&command, effect),
+                "reset" => vec![EffectActions::Adjust::new(
+                    command.message,
+                    effect,
+                    Default::default()
+                )],
+                _ => self.usage(command)
+            }
+
+        } else {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Sound effect `{}` does not exist on {}.",
+                    command.arguments[1],
+                    command.server.name
+                )
+            )
+        }
+
+    }
+
+    fn help(&self) -> &str {
+        "Adjust the volume, trim or fade of existing sound effects."
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
+    }
+
+}
+
+impl Handler {
+
+    fn volume(&self, command: &Command, effect: &Effect) -> ActionGroup {
+        match command.arguments.get(2).and_then(|v| v.parse::<f32>().ok()) {
+            Some(db) if db.abs() <= MAX_VOLUME_DB => {
+                let mut settings = effect.settings().clone();
+                settings.volume_db = db;
+                vec![EffectActions::Adjust::new(command.message, effect, settings)]
+            },
+            _ => MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "The volume must be a number of decibels between -{0} and {0}.",
+                    MAX_VOLUME_DB
+                )
+            )
+        }
+    }
+
+    fn trim(&self, command: &Command, effect: &Effect) -> ActionGroup {
+        match parse_millis_pair(&command.arguments[2..]) {
+            Some((start, end)) if start.checked_add(end).map_or(false, |total| {
+                total < effect.duration_ms()
+
+            }) => {
+                let mut settings = effect.settings().clone();
+                settings.trim_start_ms = start;
+                settings.trim_end_ms = end;
+                vec![EffectActions::Adjust::new(command.message, effect, settings)]
+            },
+            Some(_) => MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Sound effect `{}` is only {}ms long and cannot be trimmed any further.",
+                    effect.name,
+                    effect.duration_ms()
+                )
+            ),
+            None => MessageActions::Send::private(
+                &command.message,
+                "Usage: `!effect trim <effect_name> <start_ms> <end_ms>`".to_string()
+            )
+        }
+    }
+
+    fn fade(&self, command: &Command, effect: &Effect) -> ActionGroup {
+        match parse_millis_pair(&command.arguments[2..]) {
+            Some((fade_in, fade_out)) if fade_in <= effect.duration_ms() && fade_out <= effect.duration_ms() => {
+                let mut settings = effect.settings().clone();
+                settings.fade_in_ms = fade_in;
+                settings.fade_out_ms = fade_out;
+                vec![EffectActions::Adjust::new(command.message, effect, settings)]
+            },
+            Some(_) => MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Sound effect `{}` is only {}ms long and cannot be faded any longer.",
+                    effect.name,
+                    effect.duration_ms()
+                )
+            ),
+            None => MessageActions::Send::private(
+                &command.message,
+                "Usage: `!effect fade <effect_name> <in_ms> <out_ms>`".to_string()
+            )
+        }
+    }
+
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn parse_millis_pair(arguments: &[String]) -> Option<(u64, u64)> {
+    if arguments.len() == 2 {
+        match (arguments[0].parse::<u64>(), arguments[1].parse::<u64>()) {
+            (Ok(a), Ok(b)) => Some((a, b)),
+            _ => None
+        }
+
+    } else {
+        None
+    }
+}
+
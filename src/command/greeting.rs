@@ -78,7 +78,7 @@ impl Handler {
                 )
             )
 
-        } else if command.server.has_matching_effects(effect_name, command.config) {
+        } else if command.server.has_matching_effects(effect_name) {
             vec![GreetingActions::Add::new(
                 command.message,
                 nickname.to_string(),
@@ -0,0 +1,114 @@
+// Internal Dependencies ------------------------------------------------------
+use ::audio::MixerSettings;
+use ::command::{Command, CommandHandler};
+use ::action::{ActionGroup, MessageActions, MixerActions};
+
+
+// Statics --------------------------------------------------------------------
+static USAGE_TEXT: &str = "Usage: `!mixer [show|sources|delay|threshold|volume|limiter|reset] [value]`
+
+Shows or changes the audio mixer settings of the server.
+
+- `show` - Shows the current settings.
+- `sources <1-8>` - The number of effect lists which are played back simultaneously.
+- `delay <0-10000>` - The delay in milliseconds before playback starts after joining a channel.
+- `threshold <0.0-1.0>` - The level above which the compressor starts to soften peaks.
+- `volume <-30.0-10.0>` - The master volume in decibels.
+- `limiter <on|off>` - Replaces the compressor with a look-ahead limiter.
+- `reset` - Restores all default settings.
+
+Changes are applied to the active voice connection immediately.";
+
+
+// Command Implementation -----------------------------------------------------
+pub struct Handler;
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_server_admin!();
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+
+        if command.arguments.is_empty() {
+            return vec![MixerActions::Show::new(command.message)];
+        }
+
+        let mut settings = command.server.get_mixer_settings();
+        let value = command.arguments.get(1).map(|v| v.as_str()).unwrap_or("");
+        let valid = match command.arguments[0].as_str() {
+            "show" => return vec![MixerActions::Show::new(command.message)],
+            "reset" => {
+                settings = MixerSettings::default();
+                true
+            },
+            "sources" => match value.parse::<usize>() {
+                Ok(sources) if sources >= 1 && sources <= 8 => {
+                    settings.max_parallel_sources = sources;
+                    true
+                },
+                _ => false
+            },
+            "delay" => match value.parse::<u64>() {
+                Ok(delay) if delay <= 10000 => {
+                    // The mixer counts down the delay in 20ms frames
+                    settings.delay_ms = delay - delay % 20;
+                    true
+                },
+                _ => false
+            },
+            "threshold" => match value.parse::<f32>() {
+                Ok(threshold) if threshold >= 0.0 && threshold <= 1.0 => {
+                    settings.compressor_threshold = threshold;
+                    true
+                },
+                _ => false
+            },
+            "volume" => match value.parse::<f32>() {
+                Ok(volume) if volume >= -30.0 && volume <= 10.0 => {
+                    settings.master_volume_db = volume;
+                    true
+                },
+                _ => false
+            },
+            "limiter" => match value {
+                "on" => {
+                    settings.limiter = true;
+                    true
+                },
+                "off" => {
+                    settings.limiter = false;
+                    true
+                },
+                _ => false
+            },
+            _ => return self.usage(command)
+        };
+
+        if valid {
+            vec![MixerActions::Update::new(command.message, settings)]
+
+        } else {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Invalid value `{}` for the mixer setting `{}`, see `!help mixer` for the allowed ranges.",
+                    value,
+                    command.arguments[0]
+                )
+            )
+        }
+
+    }
+
+    fn help(&self) -> &str {
+        "Show or change the audio mixer settings of the server."
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
+    }
+
+}
+
@@ -40,6 +40,25 @@ macro_rules! require_exact_arguments {
     );
 }
 
+#[macro_export]
+macro_rules! cooldown {
+    ($capacity:expr, $refill_ms:expr) => (
+        fn cooldown(&self) -> Option<::server::Cooldown> {
+            Some(::server::Cooldown {
+                capacity: $capacity,
+                refill_ms: $refill_ms
+            })
+        }
+    );
+    ($capacity:expr, $refill_ms:expr, $bucket:expr) => (
+        cooldown!($capacity, $refill_ms);
+
+        fn cooldown_bucket(&self) -> Option<&str> {
+            Some($bucket)
+        }
+    );
+}
+
 #[macro_export]
 macro_rules! delete_command_message {
     () => (
@@ -53,19 +72,29 @@ macro_rules! delete_command_message {
 // Modules --------------------------------------------------------------------
 mod alias;
 mod ban;
+mod clip;
 mod delete;
+mod effect;
 mod greeting;
 mod help;
 mod ip;
 mod leave;
+mod mixer;
 mod not_found;
+mod perm;
 mod pin;
 mod play;
+mod playlist;
 mod record;
 mod rename;
+mod schedule;
+mod search;
 mod sounds;
 mod silence;
+mod stats;
 mod streamer;
+mod tag;
+mod timer;
 mod uploader;
 
 
@@ -74,21 +103,33 @@ lazy_static! {
     static ref COMMANDS: HashMap<&'static str, Box<CommandHandler>> = {
         let mut m: HashMap<&'static str, Box<CommandHandler>> = HashMap::new();
         m.insert("alias", Box::new(alias::Handler));
+        m.insert("at", Box::new(timer::Handler::time()));
         m.insert("ban", Box::new(ban::Handler));
+        m.insert("clip", Box::new(clip::Handler));
         m.insert("delete", Box::new(delete::Handler));
+        m.insert("effect", Box::new(effect::Handler));
+        m.insert("every", Box::new(timer::Handler::interval()));
         m.insert("greeting", Box::new(greeting::Handler));
         m.insert("ip", Box::new(ip::Handler));
         m.insert("leave", Box::new(leave::Handler));
+        m.insert("mixer", Box::new(mixer::Handler));
+        m.insert("perm", Box::new(perm::Handler));
         m.insert("pin", Box::new(pin::Handler));
+        m.insert("playlist", Box::new(playlist::Handler));
         m.insert("s", Box::new(play::Handler::instant()));
         m.insert("q", Box::new(play::Handler::queued()));
         m.insert("n", Box::new(play::Handler::nippel()));
         m.insert("help", Box::new(help::Handler));
+        m.insert("in", Box::new(timer::Handler::delay()));
         m.insert("record", Box::new(record::Handler));
         m.insert("rename", Box::new(rename::Handler));
+        m.insert("schedule", Box::new(schedule::Handler));
+        m.insert("search", Box::new(search::Handler));
         m.insert("silence", Box::new(silence::Handler));
         m.insert("sounds", Box::new(sounds::Handler));
+        m.insert("stats", Box::new(stats::Handler));
         m.insert("streamer", Box::new(streamer::Handler));
+        m.insert("tag", Box::new(tag::Handler));
         m.insert("uploader", Box::new(uploader::Handler));
         m
     };
@@ -97,7 +138,7 @@ lazy_static! {
 
 // Internal Dependencies ------------------------------------------------------
 use ::bot::BotConfig;
-use ::server::Server;
+use ::server::{Cooldown, Server};
 use ::core::{Member, Message};
 use ::action::{ActionGroup, MessageActions};
 
@@ -158,13 +199,25 @@ impl<'a> Command<'a> {
                 )
             ));
 
-        } else if handler.require_server_admin() && !self.member.is_admin {
+        } else if !self.server.member_has_permission(
+            self.member,
+            &self.name,
+            handler.require_server_admin()
+        ) {
             actions.push(MessageActions::Send::single_private(
                 &self.message,
-                format!(
-                    "The command `{}` requires bot admin rights on the current server.",
-                    self.name
-                )
+                if handler.require_server_admin() {
+                    format!(
+                        "The command `{}` requires bot admin rights on the current server.",
+                        self.name
+                    )
+
+                } else {
+                    format!(
+                        "The command `{}` is restricted to specific users and roles on the current server.",
+                        self.name
+                    )
+                }
             ));
 
         } else if argc < handler.require_min_arguments()
@@ -172,6 +225,20 @@ impl<'a> Command<'a> {
 
             actions.append(&mut handler.usage(self));
 
+        } else if let Err(wait_ms) = self.server.consume_cooldown(
+            self.member,
+            handler.cooldown_bucket().unwrap_or(self.name.as_str()),
+            handler.cooldown()
+        ) {
+            actions.push(MessageActions::Send::single_private(
+                &self.message,
+                format!(
+                    "You are using `!{}` too frequently, please wait another {:.1} second(s).",
+                    self.name,
+                    wait_ms as f64 / 1000.0
+                )
+            ));
+
         } else {
             actions.append(&mut handler.run(self));
         }
@@ -236,6 +303,15 @@ pub trait CommandHandler: Sync {
         None
     }
 
+    fn cooldown(&self) -> Option<Cooldown> {
+        None
+    }
+
+    // Commands sharing a bucket name also share their cooldown
+    fn cooldown_bucket(&self) -> Option<&str> {
+        None
+    }
+
     fn delete_command_message(&self) -> bool {
         false
     }
@@ -0,0 +1,126 @@
+// Internal Dependencies ------------------------------------------------------
+use ::command::{Command, CommandHandler};
+use ::action::{ActionGroup, PermissionActions, MessageActions};
+
+
+// Statics --------------------------------------------------------------------
+static USAGE_TEXT: &str = "Usage: `!perm grant <subject> <command>` or `!perm revoke <subject> <command>` or `!perm list`
+
+Grants or revokes the permission to use a command.
+
+Each **`subject`** can be one of the following:
+
+- `user#ident` or a user mention - A specific user.
+- `role_name` or a role mention - All users with the role.
+
+Each **`command`** is the name of a command without the leading `!`, or `*` for all commands which do not require bot admin rights.
+
+Once a command has at least one grant, it can only be used by the granted users and roles as well as bot admins.";
+
+
+// Command Implementation -----------------------------------------------------
+pub struct Handler;
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_server_admin!();
+    require_min_arguments!(1);
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+        match command.arguments[0].as_str() {
+            "grant" => if command.arguments.len() < 3 {
+                self.usage(command)
+
+            } else {
+                self.grant(
+                    &command,
+                    &command.arguments[1],
+                    &command.arguments[2]
+                )
+            },
+            "revoke" => if command.arguments.len() < 3 {
+                self.usage(command)
+
+            } else {
+                self.revoke(
+                    &command,
+                    &command.arguments[1],
+                    &command.arguments[2]
+                )
+            },
+            "list" => vec![PermissionActions::List::new(command.message)],
+            _ => self.usage(command)
+        }
+    }
+
+    fn help(&self) -> &str {
+        "List, grant or revoke command permissions for users and roles."
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
+    }
+
+}
+
+impl Handler {
+
+    fn grant(
+        &self,
+        command: &Command,
+        subject: &str,
+        command_name: &str
+
+    ) -> ActionGroup {
+        if command_name != "*" && !command.all_commands.contains_key(command_name) {
+            MessageActions::Send::private(
+                &command.message,
+                format!("**`{}`** is not a known command.", command_name)
+            )
+
+        } else if let Some(subject) = command.server.parse_permission_subject(subject) {
+            vec![PermissionActions::Grant::new(
+                command.message,
+                subject,
+                command_name.to_string()
+            )]
+
+        } else {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "`{}` is neither a member nor a role on {}.",
+                    subject, command.server.name
+                )
+            )
+        }
+    }
+
+    fn revoke(
+        &self,
+        command: &Command,
+        subject: &str,
+        command_name: &str
+
+    ) -> ActionGroup {
+        if let Some(subject) = command.server.parse_permission_subject(subject) {
+            vec![PermissionActions::Revoke::new(
+                command.message,
+                subject,
+                command_name.to_string()
+            )]
+
+        } else {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "`{}` is neither a member nor a role on {}.",
+                    subject, command.server.name
+                )
+            )
+        }
+    }
+
+}
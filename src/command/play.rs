@@ -1,9 +1,12 @@
 // Internal Dependencies ------------------------------------------------------
+use ::server::PlaybackSource;
 use ::command::{Command, CommandHandler};
 use ::action::{ActionGroup, EffectActions, MessageActions};
 
 
 // Statics --------------------------------------------------------------------
+static MAX_SUGGESTIONS: usize = 10;
+
 static USAGE_TEXT_S: &str = "Usage: `!s <effect_name>, ...`
 
 Instantly starts the playback of one or more requested sound effects.
@@ -15,6 +18,7 @@ Each **`effect_name`** can be one of the following patterns:
 - `*wildcard` - A random effect which *ends* with the specified wildcard.
 - `wildcard*` - A random effect which *starts* with the specified wildcard.
 - `*wildcard*` - A random effect which *contains* the specified wildcard.
+- `#tag` - A random effect tagged with the specified tag.
 
 If more than one effect is requested, a playback queue will be created and the effects will be played back one after another.
 
@@ -33,6 +37,7 @@ Each **`effect_name`** can be one of the following patterns:
 - `*wildcard` - A random effect which *ends* with the specified wildcard.
 - `wildcard*` - A random effect which *starts* with the specified wildcard.
 - `*wildcard*` - A random effect which *contains* the specified wildcard.
+- `#tag` - A random effect tagged with the specified tag.
 
 If more than one effect is requested, a playback queue will be created and the effects will be played back one after another.
 
@@ -73,6 +78,7 @@ impl Handler {
 impl CommandHandler for Handler {
 
     require_unique_server!();
+    cooldown!(5, 3000, "play");
     delete_command_message!();
 
     fn run(&self, command: Command) -> ActionGroup {
@@ -91,13 +97,16 @@ impl CommandHandler for Handler {
 
             let mut effects = command.server.map_effects(
                 &command.arguments[..],
-                false,
-                command.config
+                false
             );
 
             if effects.is_empty() {
 
-                let similiar = command.server.map_similiar_effects(&command.arguments[..]);
+                let similiar: Vec<String> = command.server.search_effects(
+                    &command.arguments.join(" "),
+                    MAX_SUGGESTIONS
+
+                ).into_iter().map(|m| m.name).collect();
                 if similiar.is_empty() {
                     MessageActions::Send::private(
                         &command.message,
@@ -124,8 +133,7 @@ impl CommandHandler for Handler {
                 if let Some(ref prefixes) = self.prefixes {
                     let mut prefix_effects = command.server.map_effects(
                         &prefixes,
-                        false,
-                        command.config
+                        false
                     );
                     prefix_effects.append(&mut effects);
                     effects = prefix_effects;
@@ -135,6 +143,7 @@ impl CommandHandler for Handler {
                     channel_id,
                     effects,
                     self.queued,
+                    PlaybackSource::Command(command.member.id),
                     None
                 )]
 
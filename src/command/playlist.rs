@@ -0,0 +1,234 @@
+// Internal Dependencies ------------------------------------------------------
+use ::audio::MixerCommand;
+use ::command::{Command, CommandHandler};
+use ::action::{ActionGroup, EffectActions, PlaylistActions, MessageActions};
+
+
+// Statics --------------------------------------------------------------------
+static MAX_LOOPS: usize = 100;
+
+static USAGE_TEXT: &str = "Usage: `!playlist <subcommand>`
+
+Manages and plays named lists of sound effects.
+
+**Subcommands:**
+
+- `add <name> <effect_name>...` - Create or replace a playlist from one or more effect patterns.
+- `remove <name>` - Remove a playlist.
+- `list` - List all playlists.
+- `play <name> [loops]` - Queue a playlist in your current voice channel, optionally repeating it `loops` more times, at most 100.
+- `shuffle <name> [loops]` - Like `play`, but in random order.
+- `skip` - Skip the currently playing effect(s).
+- `pause` / `resume` - Pause or resume playback.
+- `loop <count>` - Repeat the most recently queued playlist `count` more times, `0` stops looping.
+- `stop` - Stop playback and clear all queued effects.
+
+Playlists can only be replaced or removed by their creator or bot admins.";
+
+
+// Command Implementation -----------------------------------------------------
+pub struct Handler;
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_min_arguments!(1);
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+        match command.arguments[0].as_str() {
+            "add" => if command.arguments.len() < 3 {
+                self.usage(command)
+
+            } else {
+                self.add(
+                    &command,
+                    &command.arguments[1],
+                    &command.arguments[2..]
+                )
+            },
+            "remove" => if command.arguments.len() < 2 {
+                self.usage(command)
+
+            } else {
+                self.remove(&command, &command.arguments[1])
+            },
+            "list" => vec![PlaylistActions::List::new(command.message)],
+            "play" | "shuffle" => if command.arguments.len() < 2 {
+                self.usage(command)
+
+            } else {
+                self.play(&command, command.arguments[0] == "shuffle")
+            },
+            "skip" => self.control(&command, MixerCommand::Skip, "skipped the current effect"),
+            "pause" => self.control(&command, MixerCommand::Pause, "paused playback"),
+            "resume" => self.control(&command, MixerCommand::Resume, "resumed playback"),
+            "loop" => match command.arguments.get(1).and_then(|c| c.parse::<usize>().ok()) {
+                Some(count) if count > MAX_LOOPS => self.loops_exceeded(&command),
+                Some(count) => self.control(
+                    &command,
+                    MixerCommand::Loop(count),
+                    "changed the playlist looping"
+                ),
+                None => self.usage(command)
+            },
+            "stop" => vec![
+                EffectActions::Silence::new(command.message),
+                MessageActions::Send::single_public(
+                    &command.message,
+                    format!("{} has stopped the playlist.", command.member.nickname)
+                )
+            ],
+            _ => self.usage(command)
+        }
+    }
+
+    fn help(&self) -> &str {
+        "Manage, play and control playlists of sound effects."
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
+    }
+
+}
+
+impl Handler {
+
+    fn add(
+        &self,
+        command: &Command,
+        name: &str,
+        effect_names: &[String]
+
+    ) -> ActionGroup {
+        if !self.may_modify(command, name) {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Only its creator or bot admins can change the playlist `{}` on {}.",
+                    name, command.server.name
+                )
+            )
+
+        } else if command.server.map_effects(effect_names, false).is_empty() {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "No effect(s) matching `{}` were found on {}.",
+                    effect_names.join("`, `"),
+                    command.server.name
+                )
+            )
+
+        } else {
+            vec![PlaylistActions::Add::new(
+                command.message,
+                name.to_string(),
+                command.member.id,
+                effect_names.iter().map(|e| e.to_string()).collect()
+            )]
+        }
+    }
+
+    fn remove(&self, command: &Command, name: &str) -> ActionGroup {
+        if !command.server.has_playlist(name) {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "A playlist named `{}` does not exist on {}.",
+                    name, command.server.name
+                )
+            )
+
+        } else if !self.may_modify(command, name) {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Only its creator or bot admins can remove the playlist `{}` on {}.",
+                    name, command.server.name
+                )
+            )
+
+        } else {
+            vec![PlaylistActions::Remove::new(command.message, name.to_string())]
+        }
+    }
+
+    fn play(&self, command: &Command, shuffle: bool) -> ActionGroup {
+
+        let name = &command.arguments[1];
+        let loops = command.arguments.get(2).and_then(|l| l.parse::<usize>().ok()).unwrap_or(0);
+
+        if loops > MAX_LOOPS {
+            self.loops_exceeded(command)
+
+        } else if !command.server.has_playlist(name) {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "A playlist named `{}` does not exist on {}.",
+                    name, command.server.name
+                )
+            )
+
+        } else if let Some(channel_id) = command.member.voice_channel_id {
+            vec![
+                PlaylistActions::Play::new(
+                    command.message,
+                    channel_id,
+                    name.to_string(),
+                    shuffle,
+                    loops
+                ),
+                MessageActions::Send::single_public(
+                    &command.message,
+                    format!(
+                        "{} has queued the playlist `{}`.",
+                        command.member.nickname,
+                        name
+                    )
+                )
+            ]
+
+        } else {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "You must be in a voice channel on {} in order to play a playlist.",
+                    command.server.name
+                )
+            )
+        }
+
+    }
+
+    fn may_modify(&self, command: &Command, name: &str) -> bool {
+        command.member.is_admin
+            || !command.server.has_playlist(name)
+            || command.server.get_playlist_creator(name) == Some(command.member.id)
+    }
+
+    fn control(
+        &self,
+        command: &Command,
+        mixer_command: MixerCommand,
+        description: &str
+
+    ) -> ActionGroup {
+        vec![PlaylistActions::Control::new(
+            command.message,
+            mixer_command,
+            format!("{} has {}.", command.member.nickname, description)
+        )]
+    }
+
+    fn loops_exceeded(&self, command: &Command) -> ActionGroup {
+        MessageActions::Send::private(
+            &command.message,
+            format!("Playlists can be repeated at most {} times.", MAX_LOOPS)
+        )
+    }
+
+}
+
@@ -3,39 +3,116 @@ use ::command::{Command, CommandHandler};
 use ::action::{ActionGroup, MessageActions, RecordingActions};
 
 
+// Statics --------------------------------------------------------------------
+static USAGE_TEXT: &str = "Usage: `!record [start|stop|list|info|transcript|delete|optout|optin] [<session>]`
+
+Manages voice recordings of the server.
+
+- `optout` - Excludes your voice from all recordings, including an active one.
+- `optin` - Includes your voice in recordings again.
+
+The following sub commands require bot admin rights:
+
+- `start` - Starts recording your current voice channel.
+- `stop` - Stops the active recording.
+- `list` - Lists all recorded sessions.
+- `info <session>` - Shows the tracks, members and files of a session.
+- `transcript <session>` - Posts the speech-to-text transcript of a session.
+- `delete <session>` - Deletes all files of a session.
+
+Old sessions are removed automatically when `recordings_max_age_ms` or `recordings_max_size` are set in the server configuration.
+
+Finished sessions are transcribed in the background when `transcriber_path` is set in the server configuration.";
+
+
 // Command Implementation -----------------------------------------------------
 pub struct Handler;
 
 impl CommandHandler for Handler {
 
     require_unique_server!();
-    require_server_admin!();
-    require_exact_arguments!(1);
+    require_min_arguments!(1);
     delete_command_message!();
 
     fn run(&self, command: Command) -> ActionGroup {
+
+        // Every member can decide whether they want to be recorded
+        match command.arguments[0].as_str() {
+            "optout" => return vec![RecordingActions::OptOut::new(command.message, true)],
+            "optin" => return vec![RecordingActions::OptOut::new(command.message, false)],
+            _ => {}
+        }
+
+        if !command.server.member_has_permission(command.member, "record", true) {
+            return MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "The command `!record {}` requires bot admin rights on the current server.",
+                    command.arguments[0]
+                )
+            );
+        }
+
         match command.arguments[0].as_str() {
             "start" => self.start(&command),
             "stop" => self.stop(&command),
+            "list" => vec![RecordingActions::List::new(command.message)],
+            "info" | "transcript" | "delete" => self.manage(&command),
             _ => self.usage(command)
         }
+
     }
 
     fn help(&self) -> &str {
-        "Start or stop voice recording in your current voice channel."
+        "Start, stop and manage voice recordings or opt out of them."
     }
 
     fn usage(&self, command: Command) -> ActionGroup {
-        MessageActions::Send::private(
-            &command.message,
-            "Usage: `!record [start|stop]`".to_string()
-        )
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
     }
 
 }
 
 impl Handler {
 
+    fn manage(&self, command: &Command) -> ActionGroup {
+
+        // Sessions from older versions contain spaces and may be quoted
+        let name = command.arguments[1..].join(" ").trim_matches('"').to_string();
+        if name.is_empty() {
+            MessageActions::Send::private(
+                &command.message,
+                format!("Usage: `!record {} <session>`", command.arguments[0])
+            )
+
+        } else if command.server.get_recording(&name).is_none() {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "No audio recording named `{}` exists on {}, see `!record list`.",
+                    name,
+                    command.server.name
+                )
+            )
+
+        } else if command.arguments[0] == "info" {
+            vec![RecordingActions::Info::new(command.message, name)]
+
+        } else if command.arguments[0] == "transcript" {
+            vec![RecordingActions::Transcript::new(command.message, name)]
+
+        } else if command.server.is_active_recording(&name) {
+            MessageActions::Send::private(
+                &command.message,
+                format!("Audio recording `{}` is still in progress.", name)
+            )
+
+        } else {
+            vec![RecordingActions::Delete::new(command.message, name)]
+        }
+
+    }
+
     fn start(&self, command: &Command) -> ActionGroup {
         if command.server.is_recording_voice() {
             MessageActions::Send::private(
@@ -49,7 +126,9 @@ impl Handler {
         } else if let Some(channel_id) = command.member.voice_channel_id {
             vec![RecordingActions::Start::new(
                 command.message.server_id,
-                channel_id
+                channel_id,
+                command.message.user_id,
+                Some(command.message.channel_id)
             )]
 
         } else {
@@ -76,7 +155,9 @@ impl Handler {
         } else if let Some(channel_id) = command.member.voice_channel_id {
             vec![RecordingActions::Stop::new(
                 command.message.server_id,
-                channel_id
+                channel_id,
+                command.message.user_id,
+                Some(command.message.channel_id)
             )]
 
         } else {
@@ -0,0 +1,55 @@
+// Internal Dependencies ------------------------------------------------------
+use ::command::{Command, CommandHandler};
+use ::action::{ActionGroup, ScheduleActions, MessageActions};
+
+
+// Statics --------------------------------------------------------------------
+static USAGE_TEXT: &str = "Usage: `!schedule list` or `!schedule cancel <id>`
+
+Lists or cancels the sound effects which were scheduled via `!in`, `!at` or `!every`.
+
+Scheduled sound effects can only be canceled by the user who scheduled them or by bot admins.";
+
+
+// Command Implementation -----------------------------------------------------
+pub struct Handler;
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_min_arguments!(1);
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+        match command.arguments[0].as_str() {
+            "list" => vec![ScheduleActions::List::new(command.message)],
+            "cancel" => if command.arguments.len() < 2 {
+                self.usage(command)
+
+            } else if let Ok(id) = command.arguments[1].trim_left_matches('#').parse::<i32>() {
+                vec![ScheduleActions::Cancel::new(
+                    command.message,
+                    id,
+                    command.member.is_admin
+                )]
+
+            } else {
+                MessageActions::Send::private(
+                    &command.message,
+                    format!("`{}` is not a valid schedule id.", command.arguments[1])
+                )
+            },
+            _ => self.usage(command)
+        }
+    }
+
+    fn help(&self) -> &str {
+        "List or cancel scheduled sound effects."
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
+    }
+
+}
+
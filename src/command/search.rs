@@ -0,0 +1,39 @@
+// Internal Dependencies ------------------------------------------------------
+use ::command::{Command, CommandHandler};
+use ::action::{ActionGroup, EffectActions, MessageActions};
+
+
+// Statics --------------------------------------------------------------------
+static USAGE_TEXT: &str = "Usage: `!search <query>`
+
+Searches the names, aliases, tags and transcripts of all sound effects for the words in the query.
+
+Results are ranked by relevance, with matching names ranking above tags and transcripts. If nothing matches, effects with similar names are suggested instead.";
+
+
+// Command Implementation -----------------------------------------------------
+pub struct Handler;
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_min_arguments!(1);
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+        vec![EffectActions::Search::new(
+            command.message,
+            command.arguments.join(" ")
+        )]
+    }
+
+    fn help(&self) -> &str {
+        "Search sound effects by name, alias, tag and transcript."
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
+    }
+
+}
+
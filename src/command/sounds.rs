@@ -4,17 +4,21 @@ use ::action::{ActionGroup, EffectActions, MessageActions};
 
 
 // Statics --------------------------------------------------------------------
-static USAGE_TEXT: &str = "Usage: `!sounds [<effect_pattern>, ...]`
+static USAGE_TEXT: &str = "Usage: `!sounds [<effect_pattern>, ...]` or `!sounds \"<phrase>\"`
 
 Lists all available sound effects that match the specified pattern(s).
 
+When given a single quoted `\"phrase\"`, the transcripts of all effects are searched instead and the results are ranked by how closely they match the phrase.
+
 Each **`effect_pattern`** can be one of the following variants:
 
 - `full_sound_name` - Only the exactly matching effect.
 - `prefix` - A random effect which name starts with the specified prefix, followed by an underscore.
 - `*wildcard` - A random effect which *ends* with the specified wildcard.
 - `wildcard*` - A random effect which *starts* with the specified wildcard.
-- `*wildcard*` - A random effect which *contains* the specified wildcard.";
+- `*wildcard*` - A random effect which *contains* the specified wildcard.
+- `#tag` - A random effect tagged with the specified tag.
+- `\"some words\"` - A random effect which transcript *contains* the specified words.";
 
 
 // Command Implementation -----------------------------------------------------
@@ -29,6 +33,9 @@ impl CommandHandler for Handler {
         if command.arguments.is_empty() {
             vec![EffectActions::List::all(command.message)]
 
+        } else if let Some(phrase) = quoted_phrase(&command.arguments) {
+            vec![EffectActions::List::search(command.message, phrase)]
+
         } else {
             vec![EffectActions::List::matching(command.message, command.arguments)]
         }
@@ -44,3 +51,21 @@ impl CommandHandler for Handler {
 
 }
 
+
+// Helpers --------------------------------------------------------------------
+fn quoted_phrase(arguments: &[String]) -> Option<String> {
+    if arguments.len() == 1 {
+        let argument = &arguments[0];
+        let len = argument.len();
+        if len > 2 && argument.starts_with('"') && argument.ends_with('"') {
+            Some(argument[1..len - 1].to_string())
+
+        } else {
+            None
+        }
+
+    } else {
+        None
+    }
+}
+
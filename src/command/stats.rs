@@ -0,0 +1,62 @@
+// Internal Dependencies ------------------------------------------------------
+use ::command::{Command, CommandHandler};
+use ::action::{ActionGroup, MessageActions, StatsActions};
+
+
+// Statics --------------------------------------------------------------------
+static USAGE_TEXT: &str = "Usage: `!stats <subcommand>`
+
+Shows sound effect playback statistics.
+
+**Subcommands:**
+
+- `top` - The most played effects, the most active members and effects which were never played.
+- `me` - Your most played effects.
+- `effect <effect_name>` - How often and how recently an effect was played and by whom.";
+
+
+// Command Implementation -----------------------------------------------------
+pub struct Handler;
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_min_arguments!(1);
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+        match command.arguments[0].as_str() {
+            "top" => vec![StatsActions::Top::new(command.message)],
+            "me" => vec![StatsActions::Member::new(command.message)],
+            "effect" => if command.arguments.len() < 2 {
+                self.usage(command)
+
+            } else if !command.server.has_effect(&command.arguments[1]) {
+                MessageActions::Send::private(
+                    &command.message,
+                    format!(
+                        "No effect named `{}` exists on {}.",
+                        command.arguments[1], command.server.name
+                    )
+                )
+
+            } else {
+                vec![StatsActions::Effect::new(
+                    command.message,
+                    command.arguments[1].to_string()
+                )]
+            },
+            _ => self.usage(command)
+        }
+    }
+
+    fn help(&self) -> &str {
+        "Show playback statistics of sound effects and members."
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
+    }
+
+}
+
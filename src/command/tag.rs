@@ -0,0 +1,96 @@
+// Internal Dependencies ------------------------------------------------------
+use ::command::{Command, CommandHandler};
+use ::action::{ActionGroup, MessageActions, TagActions};
+
+
+// Statics --------------------------------------------------------------------
+static USAGE_TEXT: &str = "Usage: `!tag add <effect_name> <tag>` or `!tag remove <effect_name> <tag>`
+
+Tags sound effects or aliases with a category.
+
+Tagged effects can be played via the `#tag` pattern, e.g. `!s #memes` plays a random effect tagged with `memes`.";
+
+
+// Command Implementation -----------------------------------------------------
+pub struct Handler;
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_exact_arguments!(3);
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+
+        if !command.member.is_admin && !command.member.is_uploader {
+            return MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Only bot admins and uploaders can tag sound effects on {}.",
+                    command.server.name
+                )
+            );
+        }
+
+        match command.arguments[0].as_str() {
+            "add" => self.tag(&command, true),
+            "remove" => self.tag(&command, false),
+            _ => self.usage(command)
+        }
+    }
+
+    fn help(&self) -> &str {
+        "Add or remove tags of sound effects and aliases."
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, USAGE_TEXT.to_string())
+    }
+
+}
+
+impl Handler {
+
+    fn tag(&self, command: &Command, add: bool) -> ActionGroup {
+
+        let name = &command.arguments[1];
+        let tag = command.arguments[2].trim_left_matches('#').to_lowercase();
+
+        if !command.server.has_effect(name) && !command.server.has_alias(name) {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "No effect or alias named `{}` exists on {}.",
+                    name, command.server.name
+                )
+            )
+
+        } else if tag.is_empty() || !tag.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+            MessageActions::Send::private(
+                &command.message,
+                "Tags may only contain letters, digits, `_` and `-`.".to_string()
+            )
+
+        } else if add && command.server.has_effect_tag(name, &tag) {
+            MessageActions::Send::private(
+                &command.message,
+                format!("`{}` is already tagged with `#{}`.", name, tag)
+            )
+
+        } else if !add && !command.server.has_effect_tag(name, &tag) {
+            MessageActions::Send::private(
+                &command.message,
+                format!("`{}` is not tagged with `#{}`.", name, tag)
+            )
+
+        } else if add {
+            vec![TagActions::Add::new(command.message, name.to_string(), tag)]
+
+        } else {
+            vec![TagActions::Remove::new(command.message, name.to_string(), tag)]
+        }
+
+    }
+
+}
+
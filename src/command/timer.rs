@@ -0,0 +1,337 @@
+// External Dependencies ------------------------------------------------------
+use chrono;
+use chrono::Local;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::ChannelId;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::server::PlaybackSource;
+use ::command::{Command, CommandHandler};
+use ::text_util::{format_duration, parse_duration};
+use ::action::{ActionGroup, EffectActions, MessageActions, TimedActions};
+
+
+// Statics --------------------------------------------------------------------
+static MIN_INTERVAL_MILLIS: u64 = 60 * 1000;
+static MAX_DELAY_MILLIS: u64 = 365 * 24 * 60 * 60 * 1000;
+
+static USAGE_TEXT_IN: &str = "Usage: `!in <duration> <effect_name>, ... [@channel_name]`
+
+Plays one or more sound effects once the specified duration has passed.
+
+**`duration`** is a combination of numbers and units, e.g. `30s`, `10m` or `1h30m`, of at most one year.
+
+The effects are played in your current voice channel unless a voice channel is named with a trailing `@channel_name`.
+
+Use `!schedule list` to view and `!schedule cancel <id>` to cancel pending playbacks.";
+
+static USAGE_TEXT_AT: &str = "Usage: `!at <HH:MM> <effect_name>, ... [@channel_name]`
+
+Plays one or more sound effects at the next occurrence of the specified local time of the bot.
+
+The effects are played in your current voice channel unless a voice channel is named with a trailing `@channel_name`.
+
+Use `!schedule list` to view and `!schedule cancel <id>` to cancel pending playbacks.";
+
+static USAGE_TEXT_EVERY: &str = "Usage: `!every <duration> <effect_name>, ... [@channel_name]`
+
+Repeatedly plays one or more sound effects, each time the specified duration has passed.
+
+**`duration`** is a combination of numbers and units, e.g. `30m`, `1h` or `1d`, and must be between one minute and one year.
+
+The effects are played in your current voice channel unless a voice channel is named with a trailing `@channel_name`.
+
+Use `!schedule list` to view and `!schedule cancel <id>` to cancel repeated playbacks.";
+
+
+// Command Implementation -----------------------------------------------------
+#[derive(Copy, Clone)]
+enum Timing {
+    Delay,
+    Time,
+    Interval
+}
+
+pub struct Handler {
+    timing: Timing
+}
+
+impl Handler {
+
+    pub fn delay() -> Handler {
+        Handler {
+            timing: Timing::Delay
+        }
+    }
+
+    pub fn time() -> Handler {
+        Handler {
+            timing: Timing::Time
+        }
+    }
+
+    pub fn interval() -> Handler {
+        Handler {
+            timing: Timing::Interval
+        }
+    }
+
+}
+
+impl CommandHandler for Handler {
+
+    require_unique_server!();
+    require_min_arguments!(2);
+    delete_command_message!();
+
+    fn run(&self, command: Command) -> ActionGroup {
+
+        // An optional trailing @channel_name selects the target voice channel,
+        // the first argument is always the time
+        let split = command.arguments.iter().skip(1).position(|arg| {
+            arg.starts_with('@')
+
+        }).map_or(command.arguments.len(), |index| index + 1);
+
+        let patterns: Vec<String> = command.arguments[1..split].to_vec();
+        let channel_name = command.arguments[split..].join(" ");
+
+        let channel_id = if channel_name.is_empty() {
+            command.member.voice_channel_id
+
+        } else {
+            command.server.get_voice_channel_id(&channel_name[1..])
+        };
+
+        if patterns.is_empty() {
+            self.usage(command)
+
+        } else if let Err(err) = self.verify_patterns(&command, &patterns) {
+            MessageActions::Send::private(&command.message, err)
+
+        } else if let Some(channel_id) = channel_id {
+            match self.timing {
+                Timing::Delay => self.schedule_delay(&command, channel_id, patterns),
+                Timing::Time => self.schedule_time(&command, channel_id, patterns),
+                Timing::Interval => self.schedule_interval(&command, channel_id, patterns)
+            }
+
+        } else if channel_name.is_empty() {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "You must either be in a voice channel on {} or name one via `@channel_name`.",
+                    command.server.name
+                )
+            )
+
+        } else {
+            MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "`{}` is not a voice channel on {}.",
+                    &channel_name[1..],
+                    command.server.name
+                )
+            )
+        }
+
+    }
+
+    fn help(&self) -> &str {
+        match self.timing {
+            Timing::Delay => "Play a sound effect after a delay.",
+            Timing::Time => "Play a sound effect at a specific time of day.",
+            Timing::Interval => "Repeatedly play a sound effect in a fixed interval."
+        }
+    }
+
+    fn usage(&self, command: Command) -> ActionGroup {
+        MessageActions::Send::private(&command.message, match self.timing {
+            Timing::Delay => USAGE_TEXT_IN,
+            Timing::Time => USAGE_TEXT_AT,
+            Timing::Interval => USAGE_TEXT_EVERY
+
+        }.to_string())
+    }
+
+}
+
+impl Handler {
+
+    fn verify_patterns(&self, command: &Command, patterns: &[String]) -> Result<(), String> {
+        if command.server.map_effects(patterns, false).is_empty() {
+            Err(format!(
+                "No effect(s) matching `{}` were found on {}.",
+                patterns.join("`, `"),
+                command.server.name
+            ))
+
+        } else {
+            Ok(())
+        }
+    }
+
+    fn schedule_delay(
+        &self,
+        command: &Command,
+        channel_id: ChannelId,
+        patterns: Vec<String>
+
+    ) -> ActionGroup {
+        if let Some(delay) = parse_duration(&command.arguments[0]) {
+            self.schedule(
+                command,
+                channel_id,
+                patterns,
+                delay,
+                0,
+                format!("in {}", format_duration(delay))
+            )
+
+        } else {
+            MessageActions::Send::private(
+                &command.message,
+                format!("`{}` is not a valid duration.", command.arguments[0])
+            )
+        }
+    }
+
+    fn schedule_time(
+        &self,
+        command: &Command,
+        channel_id: ChannelId,
+        patterns: Vec<String>
+
+    ) -> ActionGroup {
+        if let Some(delay) = parse_time_of_day(&command.arguments[0]) {
+            self.schedule(
+                command,
+                channel_id,
+                patterns,
+                delay,
+                0,
+                format!("at {}", command.arguments[0])
+            )
+
+        } else {
+            MessageActions::Send::private(
+                &command.message,
+                format!("`{}` is not a valid time, expected `HH:MM`.", command.arguments[0])
+            )
+        }
+    }
+
+    fn schedule_interval(
+        &self,
+        command: &Command,
+        channel_id: ChannelId,
+        patterns: Vec<String>
+
+    ) -> ActionGroup {
+        match parse_duration(&command.arguments[0]) {
+            Some(interval) if interval >= MIN_INTERVAL_MILLIS => self.schedule(
+                command,
+                channel_id,
+                patterns,
+                interval,
+                interval,
+                format!("every {}", format_duration(interval))
+            ),
+            Some(_) => MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "The interval must be at least {}.",
+                    format_duration(MIN_INTERVAL_MILLIS)
+                )
+            ),
+            None => MessageActions::Send::private(
+                &command.message,
+                format!("`{}` is not a valid duration.", command.arguments[0])
+            )
+        }
+    }
+
+    fn schedule(
+        &self,
+        command: &Command,
+        channel_id: ChannelId,
+        patterns: Vec<String>,
+        delay: u64,
+        interval: u64,
+        timing: String
+
+    ) -> ActionGroup {
+
+        if delay > MAX_DELAY_MILLIS || interval > MAX_DELAY_MILLIS {
+            return MessageActions::Send::private(
+                &command.message,
+                format!(
+                    "Playbacks cannot be scheduled more than {} in advance.",
+                    format_duration(MAX_DELAY_MILLIS)
+                )
+            );
+        }
+
+        let label = format!("`{}` {}", patterns.join("`, `"), timing);
+        let channel_name = command.server.channel_name(&channel_id).unwrap_or_else(|| {
+            channel_id.to_string()
+        });
+
+        vec![
+            TimedActions::Scheduled::new(
+                command.message.server_id,
+                delay,
+                interval,
+                command.member.id,
+                label.clone(),
+                EffectActions::Play::from_patterns(
+                    command.message.server_id,
+                    channel_id,
+                    patterns,
+                    true,
+                    PlaybackSource::Schedule(command.member.id)
+                )
+            ),
+            MessageActions::Send::single_private(
+                &command.message,
+                format!(
+                    "Scheduled {} in #{} on {}. Use `!schedule list` to view or cancel it.",
+                    label,
+                    channel_name,
+                    command.server.name
+                )
+            )
+        ]
+
+    }
+
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn parse_time_of_day(text: &str) -> Option<u64> {
+
+    let mut parts = text.split(':').map(|p| p.parse::<u32>().ok());
+    let (hour, minute) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(Some(hour)), Some(Some(minute)), None) => (hour, minute),
+        _ => return None
+    };
+
+    let now = Local::now();
+    now.date().and_hms_opt(hour, minute, 0).map(|mut due| {
+
+        // Times which already passed today refer to tomorrow
+        if due <= now {
+            due = due + chrono::Duration::days(1);
+        }
+
+        (due.timestamp() - now.timestamp()) as u64 * 1000
+
+    })
+
+}
+
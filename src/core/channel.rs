@@ -94,6 +94,10 @@ impl Channel {
         self.voice_users.retain(|id| id != member_id);
     }
 
+    pub fn is_voice(&self) -> bool {
+        self.is_voice
+    }
+
     pub fn is_empty_voice(&self) -> bool {
         self.voice_users.is_empty()
     }
@@ -5,15 +5,18 @@ use std::collections::VecDeque;
 
 
 // Discord Dependencies -------------------------------------------------------
-use discord::voice::VoiceConnection;
-use discord::{Connection, Discord, Error, State};
+use discord::Error;
+use discord::voice::{AudioSource, AudioReceiver};
 use discord::model::{
     ChannelId, MessageId, UserId, ServerId,
-    Event as DiscordEvent,
-    PrivateChannel
+    Event as DiscordEvent
 };
 
 
+// Internal Dependencies ------------------------------------------------------
+use super::transport::{DiscordHandle, Transport};
+
+
 // Low Level Event Abstraction ------------------------------------------------
 #[derive(Debug)]
 pub enum Event {
@@ -29,7 +32,7 @@ pub enum Event {
 // Low Level Event Queue ------------------------------------------------------
 pub struct EventQueue {
     events: VecDeque<Event>,
-    receiver: DiscordHandle
+    receiver: Box<Transport>
 }
 
 
@@ -37,14 +40,19 @@ pub struct EventQueue {
 impl EventQueue {
 
     pub fn new(token: String) -> EventQueue {
-        EventQueue {
-            events: VecDeque::new(),
-            receiver: DiscordHandle::from_token(
+        EventQueue::from_transport(Box::new(
+            DiscordHandle::from_token(
                 token.clone()
 
             ).expect("[EL] Initial connection failed.")
-        }
+        ))
+    }
 
+    pub fn from_transport(transport: Box<Transport>) -> EventQueue {
+        EventQueue {
+            events: VecDeque::new(),
+            receiver: transport
+        }
     }
 
     pub fn events(&mut self) -> Vec<Event> {
@@ -85,30 +93,27 @@ impl EventQueue {
 
     }
 
-    pub fn connect_server_voice<C: FnOnce(&mut VoiceConnection)>(
+    pub fn connect_server_voice(
         &mut self,
         server_id: ServerId,
         channel_id: ChannelId,
-        callback: C
+        source: Box<AudioSource>
     ) {
-        let voice_connection = self.receiver.connection.voice(Some(server_id));
         info!("[EL] Creating voice connection for Channel#{} on Server#{}", channel_id, server_id);
-        voice_connection.connect(channel_id);
-        callback(voice_connection);
+        self.receiver.connect_voice(server_id, channel_id, source);
     }
 
-    pub fn with_server_voice<C: FnOnce(&mut VoiceConnection)>(
+    pub fn set_server_voice_receiver(
         &mut self,
         server_id: ServerId,
-        callback: C
+        receiver: Option<Box<AudioReceiver>>
     ) {
-        let voice_connection = self.receiver.connection.voice(Some(server_id));
-        callback(voice_connection);
+        self.receiver.set_voice_receiver(server_id, receiver);
     }
 
     pub fn disconnect_server_voice(&mut self, server_id: ServerId) {
         info!("[EL] Dropping voice connection for Server#{}", server_id);
-        self.receiver.connection.drop_voice(Some(server_id));
+        self.receiver.drop_voice(server_id);
     }
 
 }
@@ -117,14 +122,14 @@ impl EventQueue {
 impl EventQueue {
 
     pub fn send_message_to_user(&mut self, user_id: &UserId, content: String) {
-        if let Some(channel) = self.private_channel_for_user(user_id) {
-            self.send_message_to_channel(&channel.id, content);
+        if let Some(channel_id) = self.receiver.private_channel_for_user(user_id) {
+            self.send_message_to_channel(&channel_id, content);
         }
     }
 
     pub fn send_message_to_channel(&mut self, channel_id: &ChannelId, content: String) {
 
-        if self.receiver.discord.send_message(*channel_id, content.as_str(), "", false).is_err() {
+        if self.receiver.send_message(channel_id, content.as_str()).is_err() {
             warn!("[EL] Failed to sent message.");
             self.events.push_back(Event::SendMessageFailure(*channel_id, content));
 
@@ -135,7 +140,7 @@ impl EventQueue {
     }
 
     pub fn delete_message(&mut self, message_id: MessageId, channel_id: ChannelId) {
-        if self.receiver.discord.delete_message(channel_id, message_id).is_err() {
+        if self.receiver.delete_message(&channel_id, &message_id).is_err() {
             warn!("[EL] Failed to delete message.");
             self.events.push_back(Event::DeleteMessageFailure(channel_id, message_id));
 
@@ -146,59 +151,3 @@ impl EventQueue {
 
 }
 
-
-// Internal Interface ---------------------------------------------------------
-impl EventQueue {
-
-    fn private_channel_for_user(&self, user_id: &UserId) -> Option<PrivateChannel> {
-        self.receiver.discord.create_private_channel(*user_id).ok()
-    }
-
-}
-
-
-// Discord Abstraction --------------------------------------------------------
-pub struct DiscordHandle {
-    token: String,
-    discord: Discord,
-    connection: Connection,
-    state: State
-}
-
-impl DiscordHandle {
-
-    fn from_token(token: String) -> Result<DiscordHandle, String> {
-
-        let discord = try!(Discord::from_bot_token(&token).map_err(|err| {
-            err.to_string()
-        }));
-
-        match discord.connect() {
-            Ok((mut conn, ready)) => {
-                conn.set_non_blocking(true);
-                Ok(DiscordHandle {
-                    token: token,
-                    discord: discord,
-                    connection: conn,
-                    state: State::new(ready)
-                })
-            },
-            Err(err) => Err(err.to_string())
-        }
-
-    }
-
-    fn reconnect(&self) -> Result<DiscordHandle, String> {
-        DiscordHandle::from_token(self.token.clone())
-    }
-
-    fn update(&mut self, event: &DiscordEvent) {
-        self.state.update(event);
-    }
-
-    fn recv_event(&mut self) -> Result<DiscordEvent, Error> {
-        self.connection.recv_event()
-    }
-
-}
-
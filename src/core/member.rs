@@ -32,6 +32,7 @@ pub struct Member {
     pub is_admin: bool,
     pub is_uploader: bool,
     pub is_banned: bool,
+    pub is_recording_opt_out: bool,
     pub voice_channel_id: Option<ChannelId>,
     pub last_voice_leave: HashMap<ChannelId, u64>,
     pub mute: bool,
@@ -75,6 +76,7 @@ impl Member {
             is_admin: false,
             is_uploader: false,
             is_banned: false,
+            is_recording_opt_out: false,
             voice_channel_id: None,
             last_voice_leave: HashMap::new(),
             mute: false,
@@ -82,14 +84,14 @@ impl Member {
         }
     }
 
-    pub fn should_be_greeted(&mut self, bot_config: &BotConfig) -> bool {
+    pub fn should_be_greeted(&mut self, greeting_separation_ms: u64) -> bool {
         if self.voice_channel_id.is_some() {
             let now = clock_ticks::precise_time_ms();
             let time = self.last_voice_leave
                            .entry(self.voice_channel_id.unwrap())
                            .or_insert(0);
 
-            now - *time > bot_config.greeting_separation_ms
+            now - *time > greeting_separation_ms
 
         } else {
             false
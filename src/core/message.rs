@@ -6,6 +6,10 @@ use std::fmt;
 use discord::model::{Attachment, MessageId, ChannelId, UserId, ServerId};
 
 
+// Internal Dependencies ------------------------------------------------------
+use ::text_util::split_arguments;
+
+
 // Message Content Abstraction ------------------------------------------------
 pub enum MessageContent {
     Command(String, Vec<String>, Message),
@@ -55,9 +59,13 @@ impl Message {
 
         if content.starts_with('!') {
 
-            let mut split = content.trim().split(' ');
-            let command = split.next().unwrap_or("!");
-            let command_name = command[1..].to_string();
+            let mut arguments = split_arguments(content.trim());
+            let command_name = if arguments.is_empty() {
+                String::new()
+
+            } else {
+                arguments.remove(0)[1..].to_string()
+            };
 
             if command_name.is_empty() {
                 vec![]
@@ -65,7 +73,7 @@ impl Message {
             } else {
                 vec![MessageContent::Command(
                     command_name,
-                    split.map(|s| s.to_string()).filter(|s| !s.is_empty()).collect(),
+                    arguments,
                     self
                 )]
             }
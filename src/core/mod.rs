@@ -3,6 +3,7 @@ mod channel;
 mod event;
 mod member;
 mod message;
+mod transport;
 
 
 // Re-Exports -----------------------------------------------------------------
@@ -10,4 +11,7 @@ pub use self::channel::Channel;
 pub use self::event::{Event, EventQueue};
 pub use self::member::Member;
 pub use self::message::{Message, MessageContent};
+pub use self::transport::Transport;
+#[cfg(test)]
+pub use self::transport::{ReplayHandle, ReplayLog, ReplayCall};
 
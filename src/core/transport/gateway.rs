@@ -0,0 +1,116 @@
+// Discord Dependencies -------------------------------------------------------
+use discord::{Connection, Discord, Error, State};
+use discord::voice::{AudioSource, AudioReceiver};
+use discord::model::{
+    ChannelId, MessageId, UserId, ServerId,
+    Event as DiscordEvent
+};
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::Transport;
+
+
+// Discord Gateway Abstraction ------------------------------------------------
+pub struct DiscordHandle {
+    token: String,
+    discord: Discord,
+    connection: Connection,
+    state: State
+}
+
+impl DiscordHandle {
+
+    pub fn from_token(token: String) -> Result<DiscordHandle, String> {
+
+        let discord = try!(Discord::from_bot_token(&token).map_err(|err| {
+            err.to_string()
+        }));
+
+        match discord.connect() {
+            Ok((mut conn, ready)) => {
+                conn.set_non_blocking(true);
+                Ok(DiscordHandle {
+                    token: token,
+                    discord: discord,
+                    connection: conn,
+                    state: State::new(ready)
+                })
+            },
+            Err(err) => Err(err.to_string())
+        }
+
+    }
+
+}
+
+
+// Transport Implementation ---------------------------------------------------
+impl Transport for DiscordHandle {
+
+    fn recv_event(&mut self) -> Result<DiscordEvent, Error> {
+        self.connection.recv_event()
+    }
+
+    fn update(&mut self, event: &DiscordEvent) {
+        self.state.update(event);
+    }
+
+    fn reconnect(&self) -> Result<Box<Transport>, String> {
+        DiscordHandle::from_token(self.token.clone()).map(|handle| {
+            Box::new(handle) as Box<Transport>
+        })
+    }
+
+    fn send_message(&mut self, channel_id: &ChannelId, content: &str) -> Result<(), String> {
+        self.discord.send_message(*channel_id, content, "", false).map(|_| {
+            ()
+
+        }).map_err(|err| {
+            err.to_string()
+        })
+    }
+
+    fn delete_message(&mut self, channel_id: &ChannelId, message_id: &MessageId) -> Result<(), String> {
+        self.discord.delete_message(*channel_id, *message_id).map_err(|err| {
+            err.to_string()
+        })
+    }
+
+    fn private_channel_for_user(&mut self, user_id: &UserId) -> Option<ChannelId> {
+        self.discord.create_private_channel(*user_id).ok().map(|channel| {
+            channel.id
+        })
+    }
+
+    fn connect_voice(
+        &mut self,
+        server_id: ServerId,
+        channel_id: ChannelId,
+        source: Box<AudioSource>
+    ) {
+        let voice_connection = self.connection.voice(Some(server_id));
+        voice_connection.connect(channel_id);
+        voice_connection.clear_receiver();
+        voice_connection.play(source);
+    }
+
+    fn set_voice_receiver(
+        &mut self,
+        server_id: ServerId,
+        receiver: Option<Box<AudioReceiver>>
+    ) {
+        let voice_connection = self.connection.voice(Some(server_id));
+        if let Some(receiver) = receiver {
+            voice_connection.set_receiver(receiver);
+
+        } else {
+            voice_connection.clear_receiver();
+        }
+    }
+
+    fn drop_voice(&mut self, server_id: ServerId) {
+        self.connection.drop_voice(Some(server_id));
+    }
+
+}
@@ -0,0 +1,52 @@
+// Discord Dependencies -------------------------------------------------------
+use discord::Error;
+use discord::voice::{AudioSource, AudioReceiver};
+use discord::model::{
+    ChannelId, MessageId, UserId, ServerId,
+    Event as DiscordEvent
+};
+
+
+// Modules --------------------------------------------------------------------
+mod gateway;
+#[cfg(test)]
+mod replay;
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::gateway::DiscordHandle;
+#[cfg(test)]
+pub use self::replay::{ReplayHandle, ReplayLog, ReplayCall};
+
+
+// Transport Abstraction ------------------------------------------------------
+pub trait Transport {
+
+    fn recv_event(&mut self) -> Result<DiscordEvent, Error>;
+
+    fn update(&mut self, event: &DiscordEvent);
+
+    fn reconnect(&self) -> Result<Box<Transport>, String>;
+
+    fn send_message(&mut self, channel_id: &ChannelId, content: &str) -> Result<(), String>;
+
+    fn delete_message(&mut self, channel_id: &ChannelId, message_id: &MessageId) -> Result<(), String>;
+
+    fn private_channel_for_user(&mut self, user_id: &UserId) -> Option<ChannelId>;
+
+    fn connect_voice(
+        &mut self,
+        server_id: ServerId,
+        channel_id: ChannelId,
+        source: Box<AudioSource>
+    );
+
+    fn set_voice_receiver(
+        &mut self,
+        server_id: ServerId,
+        receiver: Option<Box<AudioReceiver>>
+    );
+
+    fn drop_voice(&mut self, server_id: ServerId);
+
+}
@@ -0,0 +1,180 @@
+// STD Dependencies -----------------------------------------------------------
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::Error;
+use discord::voice::{AudioSource, AudioReceiver};
+use discord::model::{
+    ChannelId, MessageId, UserId, ServerId,
+    Event as DiscordEvent
+};
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::Transport;
+
+
+// Recorded Outgoing Calls ----------------------------------------------------
+#[derive(Debug, PartialEq)]
+pub enum ReplayCall {
+    SendMessage(ChannelId, String),
+    DeleteMessage(ChannelId, MessageId),
+    ConnectVoice(ServerId, ChannelId),
+    SetVoiceReceiver(ServerId, bool),
+    DropVoice(ServerId)
+}
+
+
+// Replay Log -----------------------------------------------------------------
+#[derive(Default)]
+pub struct ReplayLog {
+    pub calls: Vec<ReplayCall>,
+    sources: HashMap<ServerId, Box<AudioSource>>,
+    receivers: HashMap<ServerId, Box<AudioReceiver>>
+}
+
+impl ReplayLog {
+
+    pub fn private_channel(user_id: &UserId) -> ChannelId {
+        ChannelId(user_id.0)
+    }
+
+    pub fn messages_to(&self, channel_id: &ChannelId) -> Vec<&str> {
+        self.calls.iter().filter_map(|call| {
+            match *call {
+                ReplayCall::SendMessage(ref id, ref content) if id == channel_id => {
+                    Some(content.as_str())
+                },
+                _ => None
+            }
+
+        }).collect()
+    }
+
+    pub fn has_voice_source(&self, server_id: &ServerId) -> bool {
+        self.sources.contains_key(server_id)
+    }
+
+    pub fn has_voice_receiver(&self, server_id: &ServerId) -> bool {
+        self.receivers.contains_key(server_id)
+    }
+
+    pub fn read_voice_frame(&mut self, server_id: &ServerId, buffer: &mut [i16]) -> Option<usize> {
+        self.sources.get_mut(server_id).and_then(|source| {
+            source.read_frame(buffer)
+        })
+    }
+
+}
+
+
+// Replay Transport -----------------------------------------------------------
+pub struct ReplayHandle {
+    steps: VecDeque<Option<DiscordEvent>>,
+    log: Rc<RefCell<ReplayLog>>
+}
+
+impl ReplayHandle {
+
+    pub fn new() -> ReplayHandle {
+        ReplayHandle {
+            steps: VecDeque::new(),
+            log: Rc::new(RefCell::new(ReplayLog::default()))
+        }
+    }
+
+    pub fn event(mut self, event: DiscordEvent) -> ReplayHandle {
+        self.steps.push_back(Some(event));
+        self
+    }
+
+    pub fn idle(mut self, count: usize) -> ReplayHandle {
+        for _ in 0..count {
+            self.steps.push_back(None);
+        }
+        self
+    }
+
+    pub fn log(&self) -> Rc<RefCell<ReplayLog>> {
+        self.log.clone()
+    }
+
+}
+
+
+// Transport Implementation ---------------------------------------------------
+impl Transport for ReplayHandle {
+
+    fn recv_event(&mut self) -> Result<DiscordEvent, Error> {
+        match self.steps.pop_front() {
+            Some(Some(event)) => Ok(event),
+            Some(None) => Err(Error::Other("Replay idle")),
+
+            // Close the connection once the script has been fully replayed
+            None => Err(Error::Closed(None, "Replay finished".to_string()))
+        }
+    }
+
+    fn update(&mut self, _: &DiscordEvent) {
+    }
+
+    fn reconnect(&self) -> Result<Box<Transport>, String> {
+        Err("Replay transport cannot reconnect.".to_string())
+    }
+
+    fn send_message(&mut self, channel_id: &ChannelId, content: &str) -> Result<(), String> {
+        self.log.borrow_mut().calls.push(
+            ReplayCall::SendMessage(*channel_id, content.to_string())
+        );
+        Ok(())
+    }
+
+    fn delete_message(&mut self, channel_id: &ChannelId, message_id: &MessageId) -> Result<(), String> {
+        self.log.borrow_mut().calls.push(
+            ReplayCall::DeleteMessage(*channel_id, *message_id)
+        );
+        Ok(())
+    }
+
+    fn private_channel_for_user(&mut self, user_id: &UserId) -> Option<ChannelId> {
+        Some(ReplayLog::private_channel(user_id))
+    }
+
+    fn connect_voice(
+        &mut self,
+        server_id: ServerId,
+        channel_id: ChannelId,
+        source: Box<AudioSource>
+    ) {
+        let mut log = self.log.borrow_mut();
+        log.calls.push(ReplayCall::ConnectVoice(server_id, channel_id));
+        log.receivers.remove(&server_id);
+        log.sources.insert(server_id, source);
+    }
+
+    fn set_voice_receiver(
+        &mut self,
+        server_id: ServerId,
+        receiver: Option<Box<AudioReceiver>>
+    ) {
+        let mut log = self.log.borrow_mut();
+        log.calls.push(ReplayCall::SetVoiceReceiver(server_id, receiver.is_some()));
+        if let Some(receiver) = receiver {
+            log.receivers.insert(server_id, receiver);
+
+        } else {
+            log.receivers.remove(&server_id);
+        }
+    }
+
+    fn drop_voice(&mut self, server_id: ServerId) {
+        let mut log = self.log.borrow_mut();
+        log.calls.push(ReplayCall::DropVoice(server_id));
+        log.sources.remove(&server_id);
+        log.receivers.remove(&server_id);
+    }
+
+}
@@ -1,6 +1,7 @@
 // External Dependencies ------------------------------------------------------
 use diesel;
 use diesel::prelude::*;
+use diesel::Connection;
 
 
 // Modules --------------------------------------------------------------------
@@ -10,9 +11,37 @@ pub mod schema;
 
 // Internal Dependencies ------------------------------------------------------
 use ::server::ServerConfig;
-use self::models::{User, NewUser};
-use self::schema::users::dsl::{server_id, nickname as user_nickname};
+use self::models::{User, NewUser, NewSyncedUser};
+use self::schema::users::dsl::{
+    server_id, nickname as user_nickname,
+    is_admin, is_uploader, is_banned, is_recording_opt_out
+};
 use self::schema::users::table as userTable;
+use self::schema::synced_users::table as syncedUserTable;
+use self::schema::synced_users::dsl::{
+    server_id as synced_server_id, flag as synced_flag, nickname as synced_nickname
+};
+
+
+// User Flags -----------------------------------------------------------------
+#[derive(Debug, Copy, Clone)]
+pub enum UserFlag {
+    Admin,
+    Uploader,
+    Banned,
+    RecordingOptOut
+}
+
+impl UserFlag {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            UserFlag::Admin => "admin",
+            UserFlag::Uploader => "uploader",
+            UserFlag::Banned => "banned",
+            UserFlag::RecordingOptOut => "recording_opt_out"
+        }
+    }
+}
 
 
 // Database Abstractions ------------------------------------------------------
@@ -48,7 +77,8 @@ pub fn get_user_or_default(
              nickname: nickname.to_string(),
              is_admin: false,
              is_uploader: false,
-             is_banned: false
+             is_banned: false,
+             is_recording_opt_out: false
          }
      })
 }
@@ -70,7 +100,8 @@ pub fn create_user_if_not_exists(
             nickname: nickname,
             is_admin: false,
             is_uploader: false,
-            is_banned: false
+            is_banned: false,
+            is_recording_opt_out: false
 
         }).into(userTable).execute(&config.connection).and_then(|_| {
             Ok(())
@@ -84,3 +115,84 @@ pub fn create_user_if_not_exists(
     }
 }
 
+
+pub fn set_user_flag(
+    config: &ServerConfig,
+    nickname: &str,
+    flag: UserFlag,
+    value: bool
+
+) -> Result<(), String> {
+
+    try!(create_user_if_not_exists(config, nickname));
+
+    let user = userTable.filter(
+        server_id.eq(&config.table_id)
+
+    ).filter(
+        user_nickname.eq(nickname)
+    );
+
+    match flag {
+        UserFlag::Admin => diesel::update(user).set(is_admin.eq(value)).execute(&config.connection),
+        UserFlag::Uploader => diesel::update(user).set(is_uploader.eq(value)).execute(&config.connection),
+        UserFlag::Banned => diesel::update(user).set(is_banned.eq(value)).execute(&config.connection),
+        UserFlag::RecordingOptOut => diesel::update(user).set(is_recording_opt_out.eq(value)).execute(&config.connection)
+
+    }.map(|_| ()).map_err(|err| {
+        err.to_string()
+    })
+
+}
+
+// Users whose flags were granted by the configuration file
+pub fn get_synced_users(config: &ServerConfig, flag: UserFlag) -> Vec<String> {
+    syncedUserTable.filter(
+        synced_server_id.eq(&config.table_id)
+
+    ).filter(
+        synced_flag.eq(flag.as_str())
+
+    ).select(synced_nickname).load::<String>(&config.connection).unwrap_or_else(|_| vec![])
+}
+
+pub fn set_synced_users(
+    config: &ServerConfig,
+    flag: UserFlag,
+    nicknames: &[String]
+
+) -> Result<(), String> {
+
+    // Duplicate entries in the configuration would violate the unique
+    // constraint and roll back the whole transaction
+    let mut nicknames = nicknames.to_vec();
+    nicknames.sort();
+    nicknames.dedup();
+
+    config.connection.transaction::<_, diesel::result::Error, _>(|| {
+
+        try!(diesel::delete(
+            syncedUserTable.filter(
+                synced_server_id.eq(&config.table_id)
+
+            ).filter(
+                synced_flag.eq(flag.as_str())
+            )
+
+        ).execute(&config.connection));
+
+        for nickname in &nicknames {
+            try!(diesel::insert(&NewSyncedUser {
+                server_id: &config.table_id,
+                flag: flag.as_str(),
+                nickname: nickname
+
+            }).into(syncedUserTable).execute(&config.connection));
+        }
+
+        Ok(())
+
+    }).map_err(|err| {
+        err.to_string()
+    })
+}
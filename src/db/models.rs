@@ -10,7 +10,8 @@ pub struct User {
     pub nickname: String,
     pub is_admin: bool,
     pub is_uploader: bool,
-    pub is_banned: bool
+    pub is_banned: bool,
+    pub is_recording_opt_out: bool
 }
 
 #[derive(Insertable)]
@@ -20,7 +21,8 @@ pub struct NewUser<'a> {
     pub nickname: &'a str,
     pub is_admin: bool,
     pub is_uploader: bool,
-    pub is_banned: bool
+    pub is_banned: bool,
+    pub is_recording_opt_out: bool
 }
 
 #[derive(Queryable)]
@@ -65,7 +67,13 @@ pub struct Effect {
     pub duration_ms: i32,
     pub silent_start_samples: i32,
     pub silent_end_samples: i32,
-    pub transcript: String
+    pub transcript: String,
+    pub volume_db: f32,
+    pub trim_start_ms: i32,
+    pub trim_end_ms: i32,
+    pub fade_in_ms: i32,
+    pub fade_out_ms: i32,
+    pub file_format: String
 }
 
 #[derive(Insertable)]
@@ -78,7 +86,8 @@ pub struct NewEffect<'a> {
     pub duration_ms: i32,
     pub silent_start_samples: i32,
     pub silent_end_samples: i32,
-    pub transcript: &'a str
+    pub transcript: &'a str,
+    pub file_format: &'a str
 }
 
 #[derive(Queryable)]
@@ -101,3 +110,135 @@ pub struct NewStreamer<'a> {
     pub last_online: i32
 }
 
+
+#[derive(Queryable)]
+pub struct Permission {
+    pub id: i32,
+    pub server_id: String,
+    pub subject_kind: String,
+    pub subject_id: String,
+    pub command: String
+}
+
+#[derive(Insertable)]
+#[table_name="permissions"]
+pub struct NewPermission<'a> {
+    pub server_id: &'a str,
+    pub subject_kind: &'a str,
+    pub subject_id: String,
+    pub command: &'a str
+}
+
+#[derive(Queryable)]
+pub struct ScheduledAction {
+    pub id: i32,
+    pub server_id: String,
+    pub due_at: i64,
+    pub interval_ms: i64,
+    pub kind: String,
+    pub payload: String,
+    pub owner_id: String,
+    pub label: String
+}
+
+#[derive(Insertable)]
+#[table_name="scheduled_actions"]
+pub struct NewScheduledAction<'a> {
+    pub server_id: &'a str,
+    pub due_at: i64,
+    pub interval_ms: i64,
+    pub kind: &'a str,
+    pub payload: String,
+    pub owner_id: String,
+    pub label: &'a str
+}
+
+#[derive(Queryable)]
+pub struct Playlist {
+    pub id: i32,
+    pub server_id: String,
+    pub name: String,
+    pub creator: String,
+    pub effect_names: String
+}
+
+#[derive(Insertable)]
+#[table_name="playlists"]
+pub struct NewPlaylist<'a> {
+    pub server_id: &'a str,
+    pub name: &'a str,
+    pub creator: &'a str,
+    pub effect_names: &'a str
+}
+
+#[derive(Queryable)]
+pub struct MixerSettings {
+    pub id: i32,
+    pub server_id: String,
+    pub max_parallel_sources: i32,
+    pub delay_ms: i32,
+    pub compressor_threshold: f32,
+    pub master_volume_db: f32,
+    pub limiter: bool
+}
+
+#[derive(Insertable)]
+#[table_name="mixer_settings"]
+pub struct NewMixerSettings<'a> {
+    pub server_id: &'a str,
+    pub max_parallel_sources: i32,
+    pub delay_ms: i32,
+    pub compressor_threshold: f32,
+    pub master_volume_db: f32,
+    pub limiter: bool
+}
+
+#[derive(Queryable)]
+pub struct Play {
+    pub id: i32,
+    pub server_id: String,
+    pub effect_name: String,
+    pub user_id: String,
+    pub channel_id: String,
+    pub played_at: i64,
+    pub queued: bool,
+    pub source: String
+}
+
+#[derive(Insertable)]
+#[table_name="plays"]
+pub struct NewPlay<'a> {
+    pub server_id: &'a str,
+    pub effect_name: &'a str,
+    pub user_id: String,
+    pub channel_id: String,
+    pub played_at: i64,
+    pub queued: bool,
+    pub source: &'a str
+}
+
+#[derive(Queryable)]
+pub struct Tag {
+    pub id: i32,
+    pub server_id: String,
+    pub kind: String,
+    pub effect_name: String,
+    pub name: String
+}
+
+#[derive(Insertable)]
+#[table_name="tags"]
+pub struct NewTag<'a> {
+    pub server_id: &'a str,
+    pub kind: &'a str,
+    pub effect_name: &'a str,
+    pub name: &'a str
+}
+
+#[derive(Insertable)]
+#[table_name="synced_users"]
+pub struct NewSyncedUser<'a> {
+    pub server_id: &'a str,
+    pub flag: &'a str,
+    pub nickname: &'a str
+}
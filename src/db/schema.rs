@@ -1,2 +1,28 @@
-infer_schema!("dotenv:DATABASE_URL");
+// The FTS5 search index and its shadow tables have no primary keys, so the
+// regular tables are inferred individually
+infer_table_from_schema!("dotenv:DATABASE_URL", "users");
+infer_table_from_schema!("dotenv:DATABASE_URL", "greetings");
+infer_table_from_schema!("dotenv:DATABASE_URL", "aliases");
+infer_table_from_schema!("dotenv:DATABASE_URL", "effects");
+infer_table_from_schema!("dotenv:DATABASE_URL", "mixer_settings");
+infer_table_from_schema!("dotenv:DATABASE_URL", "streamers");
+infer_table_from_schema!("dotenv:DATABASE_URL", "permissions");
+infer_table_from_schema!("dotenv:DATABASE_URL", "scheduled_actions");
+infer_table_from_schema!("dotenv:DATABASE_URL", "playlists");
+infer_table_from_schema!("dotenv:DATABASE_URL", "plays");
+infer_table_from_schema!("dotenv:DATABASE_URL", "tags");
+infer_table_from_schema!("dotenv:DATABASE_URL", "synced_users");
 
+
+// The search index is declared by hand, its full text queries are written as
+// comparisons against the table name itself
+table! {
+    effects_search (rowid) {
+        rowid -> Integer,
+        server_id -> Text,
+        kind -> Text,
+        name -> Text,
+        tags -> Text,
+        content -> Text,
+    }
+}
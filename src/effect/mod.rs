@@ -3,12 +3,17 @@ use std::fmt;
 use std::path::PathBuf;
 
 
+// Internal Dependencies ------------------------------------------------------
+use ::audio::AudioFormat;
+use ::text_util::normalize_words;
+
+
 // Modules --------------------------------------------------------------------
 mod registry;
 
 
 // Re-Exports -----------------------------------------------------------------
-pub use self::registry::EffectRegistry;
+pub use self::registry::{EffectRegistry, SelectionStrategy};
 
 
 // Effect Statistics ----------------------------------------------------------
@@ -21,14 +26,35 @@ pub struct EffectStat {
 }
 
 
+// Effect Playback Settings ---------------------------------------------------
+#[derive(Debug, Clone, Default)]
+pub struct EffectSettings {
+    pub volume_db: f32,
+    pub trim_start_ms: u64,
+    pub trim_end_ms: u64,
+    pub fade_in_ms: u64,
+    pub fade_out_ms: u64
+}
+
+
+// Effect Search Results ----------------------------------------------------
+#[derive(Debug, Clone)]
+pub struct SearchMatch {
+    pub name: String,
+    pub is_alias: bool
+}
+
+
 // Effect Abstraction ---------------------------------------------------------
 #[derive(Debug)]
 pub struct Effect {
     pub name: String,
     path: PathBuf,
     stats: EffectStat,
+    settings: EffectSettings,
     uploader: String,
-    transcript: String
+    transcript: String,
+    words: String
 }
 
 impl Effect {
@@ -37,6 +63,7 @@ impl Effect {
         name: &str,
         path: PathBuf,
         stats: EffectStat,
+        settings: EffectSettings,
         uploader: String,
         transcript: String
 
@@ -45,7 +72,9 @@ impl Effect {
             name: name.to_string(),
             path: path,
             stats: stats,
+            settings: settings,
             uploader: uploader,
+            words: normalize_words(&transcript).join(" "),
             transcript: transcript
         }
     }
@@ -56,10 +85,29 @@ impl Effect {
         1.0 + gain * 0.75
     }
 
+    pub fn volume_gain(&self) -> f32 {
+        self.auto_adjust_gain() * 10.0f32.powf(self.settings.volume_db / 20.0)
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        self.stats.duration_ms
+    }
+
+    pub fn settings(&self) -> &EffectSettings {
+        &self.settings
+    }
+
     pub fn to_path_str(&self) -> &str {
         self.path.to_str().unwrap_or("")
     }
 
+    pub fn format(&self) -> AudioFormat {
+        self.path.extension().and_then(|ext| ext.to_str()).and_then(|ext| {
+            AudioFormat::from_extension(ext)
+
+        }).unwrap_or(AudioFormat::Flac)
+    }
+
     pub fn uploader(&self) -> Option<&str> {
         if self.uploader.is_empty() {
             None
@@ -73,6 +121,11 @@ impl Effect {
         &self.transcript
     }
 
+    // The normalized words of the transcript, used for matching and indexing
+    pub fn transcript_words(&self) -> &str {
+        &self.words
+    }
+
 }
 
 impl Clone for Effect {
@@ -81,8 +134,10 @@ impl Clone for Effect {
             name: self.name.to_string(),
             path: self.path.clone(),
             stats: self.stats.clone(),
+            settings: self.settings.clone(),
             uploader: self.uploader.clone(),
-            transcript: "".to_string()
+            transcript: self.transcript.clone(),
+            words: self.words.clone()
         }
     }
 }
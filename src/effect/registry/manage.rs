@@ -1,7 +1,9 @@
 // STD Dependencies -----------------------------------------------------------
 use std::fs;
+use std::cmp;
 use std::fs::File;
 use std::path::PathBuf;
+use std::collections::HashSet;
 use std::io::{Read, Write};
 
 
@@ -12,15 +14,19 @@ use diesel::prelude::*;
 use diesel::Connection as DieselConnection;
 use hyper::Client;
 use hyper::header::Connection;
-use flac::{ReadStream, StreamReader, StreamIter};
 
 
 // Internal Dependencies ------------------------------------------------------
 use ::server::ServerConfig;
+use ::audio::{AudioFormat, Decoder, FlacEncoder, open_decoder};
 use ::db::schema::effects::table as effectTable;
-use ::db::schema::effects::dsl::{server_id, name as effect_name};
+use ::db::schema::effects::dsl::{
+    server_id, name as effect_name,
+    volume_db, trim_start_ms, trim_end_ms, fade_in_ms, fade_out_ms,
+    transcript
+};
 use ::db::models::{Effect as EffectModel, NewEffect as NewEffectModel};
-use ::effect::{EffectRegistry, Effect, EffectStat};
+use ::effect::{EffectRegistry, Effect, EffectSettings, EffectStat};
 
 
 // Public Effect Management Interface -----------------------------------------
@@ -28,7 +34,10 @@ impl EffectRegistry {
 
     pub fn reload_effects(&mut self, config: &ServerConfig) {
         self.effects.clear();
+        self.transcript_words.clear();
         self.load_effects(config);
+        self.load_tags(config);
+        self.load_play_counts(config);
     }
 
     pub fn rename_effect(
@@ -42,14 +51,15 @@ impl EffectRegistry {
         let mut new_effect_path = config.effects_path.clone();
         if let Some(uploader) = effect.uploader() {
             new_effect_path.push(format!(
-                "{}.{}.flac",
+                "{}.{}.{}",
                 name,
-                uploader.replace("#", "_")
+                uploader.replace("#", "_"),
+                effect.format().extension()
             ))
 
         } else {
             new_effect_path.push(name);
-            new_effect_path.set_extension("flac");
+            new_effect_path.set_extension(effect.format().extension());
         }
 
         let q = effectTable.filter(server_id.eq(&config.table_id)).filter(effect_name.eq(effect.name.clone()));
@@ -81,12 +91,36 @@ impl EffectRegistry {
         })
     }
 
+    pub fn update_effect_settings(
+        &mut self,
+        config: &ServerConfig,
+        effect: &Effect,
+        settings: &EffectSettings
+
+    ) -> Result<(), String> {
+        let q = effectTable.filter(server_id.eq(&config.table_id)).filter(effect_name.eq(&effect.name));
+        diesel::update(q).set((
+            volume_db.eq(settings.volume_db),
+            trim_start_ms.eq(column_millis(settings.trim_start_ms)),
+            trim_end_ms.eq(column_millis(settings.trim_end_ms)),
+            fade_in_ms.eq(column_millis(settings.fade_in_ms)),
+            fade_out_ms.eq(column_millis(settings.fade_out_ms))
+
+        )).execute(&config.connection).map(|_| {
+            self.reload_effects(config)
+
+        }).map_err(|_| {
+           "Failed to update effect settings in database.".to_string()
+        })
+    }
+
     pub fn download_effect(
         &mut self,
         config: &ServerConfig,
         name: &str,
         upload_url: &str,
-        uploader: &str
+        uploader: &str,
+        format: AudioFormat
 
     ) -> Result<(), String> {
 
@@ -95,72 +129,71 @@ impl EffectRegistry {
             name,
             upload_url,
             Some(uploader),
-            "flac"
+            format.extension()
 
         ).and_then(|effect_path| {
-            analyze_flac(&effect_path).map_err(|err| {
-                err.to_string()
+            self.add_effect_file(config, name, uploader, effect_path, format)
+        })
 
-            }).and_then(|stats| {
-                diesel::insert(&NewEffectModel {
-                    server_id: &config.table_id,
-                    name: name,
-                    uploader: uploader,
-                    peak_db: stats.peak_db,
-                    duration_ms: stats.duration_ms as i32,
-                    silent_start_samples: stats.silent_start_samples as i32,
-                    silent_end_samples: stats.silent_end_samples as i32,
-                    transcript: ""
-
-                }).into(effectTable)
-                  .execute(&config.connection)
-                  .and_then(|_| {
-
-                    Ok(self.reload_effects(config))
-
-                }).map_err(|_| {
-                    "Failed to analyze uploaded flac file.".to_string()
-                })
+    }
 
-            }).map_err(|err| {
-                fs::remove_file(effect_path).map_err(|err| {
-                    err.to_string()
+    pub fn create_effect(
+        &mut self,
+        config: &ServerConfig,
+        name: &str,
+        uploader: &str,
+        samples: &[i16]
 
-                }).ok();
-                err
-            })
+    ) -> Result<(), String> {
 
-        }).map_err(|err| {
-            err.to_string()
-        })
+        let effect_path = effect_file_path(
+            config.effects_path.clone(),
+            name,
+            Some(uploader),
+            AudioFormat::Flac.extension()
+        );
+
+        let encoded = FlacEncoder::create(&effect_path, 1, 48_000).and_then(|mut encoder| {
+            try!(encoder.write_samples(samples));
+            encoder.finish()
+        });
+
+        match encoded {
+            Ok(_) => self.add_effect_file(config, name, uploader, effect_path, AudioFormat::Flac),
+            Err(err) => {
+                fs::remove_file(effect_path).ok();
+                Err(err)
+            }
+        }
 
     }
 
     pub fn download_transcript(
         &mut self,
-        _: &ServerConfig,
-        _: &str,
-        _: &str
+        config: &ServerConfig,
+        name: &str,
+        upload_url: &str
 
     ) -> Result<(), String> {
-        // TODO update transcript for effect in DB
-        // TODO download text instead of file
-        /*
-        download_file(
-            config.effects_path.clone(),
-            name,
-            upload_url,
-            None,
-            "txt"
 
-        ).map_err(|err| {
-            err.to_string()
+        // Transcripts are stored as uploaded, they are only normalized for
+        // matching once loaded
+        let q = effectTable.filter(server_id.eq(&config.table_id)).filter(effect_name.eq(name));
+        download_text(upload_url).and_then(|text| {
+            diesel::update(q).set(
+                transcript.eq(text.trim())
 
-        }).and_then(|_| {
-            Ok(self.reload_effects(config))
+            ).execute(&config.connection).map_err(|_| {
+                "Failed to update effect transcript in database.".to_string()
+
+            }).and_then(|updated| if updated == 0 {
+                Err(format!("No effect named \"{}\" exists in database.", name))
+
+            } else {
+                Ok(self.reload_effects(config))
+            })
         })
-        */
-        Ok(())
+
     }
 
 }
@@ -169,6 +202,48 @@ impl EffectRegistry {
 // Internal Interface ---------------------------------------------------------
 impl EffectRegistry {
 
+    fn add_effect_file(
+        &mut self,
+        config: &ServerConfig,
+        name: &str,
+        uploader: &str,
+        effect_path: PathBuf,
+        format: AudioFormat
+
+    ) -> Result<(), String> {
+
+        analyze_audio(&effect_path, format).and_then(|stats| {
+            diesel::insert(&NewEffectModel {
+                server_id: &config.table_id,
+                name: name,
+                uploader: uploader,
+                peak_db: stats.peak_db,
+                duration_ms: stats.duration_ms as i32,
+                silent_start_samples: stats.silent_start_samples as i32,
+                silent_end_samples: stats.silent_end_samples as i32,
+                transcript: "",
+                file_format: format.extension()
+
+            }).into(effectTable)
+              .execute(&config.connection)
+              .and_then(|_| {
+
+                Ok(self.reload_effects(config))
+
+            }).map_err(|_| {
+                "Failed to analyze uploaded audio file.".to_string()
+            })
+
+        }).map_err(|err| {
+            fs::remove_file(effect_path).map_err(|err| {
+                err.to_string()
+
+            }).ok();
+            err
+        })
+
+    }
+
     fn load_effects(&mut self, config: &ServerConfig) {
 
         let start = clock_ticks::precise_time_ms();
@@ -177,6 +252,14 @@ impl EffectRegistry {
                   .unwrap_or_else(|_| vec![]) {
 
             let effect = self.effect_from_model(config, effect);
+
+            // Index transcript words for searching
+            for word in effect.transcript_words().split(' ').filter(|w| !w.is_empty()) {
+                self.transcript_words.entry(word.to_string()).or_insert_with(HashSet::new).insert(
+                    effect.name.clone()
+                );
+            }
+
             self.effects.insert(effect.name.clone(), effect);
         }
 
@@ -205,7 +288,7 @@ impl EffectRegistry {
         let mut path = PathBuf::new();
         path.push(config.effects_path.clone());
         path.push(name);
-        path.set_extension("flac");
+        path.set_extension(&effect.file_format);
 
         Effect::new(
             effect.name.as_str(),
@@ -216,6 +299,13 @@ impl EffectRegistry {
                 silent_start_samples: effect.silent_start_samples as u64,
                 silent_end_samples: effect.silent_end_samples as u64
             },
+            EffectSettings {
+                volume_db: effect.volume_db,
+                trim_start_ms: cmp::max(effect.trim_start_ms, 0) as u64,
+                trim_end_ms: cmp::max(effect.trim_end_ms, 0) as u64,
+                fade_in_ms: cmp::max(effect.fade_in_ms, 0) as u64,
+                fade_out_ms: cmp::max(effect.fade_out_ms, 0) as u64
+            },
             effect.uploader,
             effect.transcript
         )
@@ -225,25 +315,31 @@ impl EffectRegistry {
 }
 
 
+// Statics --------------------------------------------------------------------
+static MAX_TRANSCRIPT_BYTES: u64 = 16 * 1024;
+
+
 // Helpers --------------------------------------------------------------------
-fn analyze_flac(flac_path: &PathBuf) -> Result<EffectStat, String> {
-    StreamReader::<File>::from_file(flac_path.to_str().unwrap_or(""))
-        .map_err(|_| "Failed to open flac file.".to_string())
-        .and_then(|stream| {
-            Ok(analyze_flac_stream(stream))
-        })
+fn column_millis(ms: u64) -> i32 {
+    cmp::min(ms, i32::max_value() as u64) as i32
 }
 
-fn analyze_flac_stream(stream: StreamReader<File>) -> EffectStat {
+fn analyze_audio(path: &PathBuf, format: AudioFormat) -> Result<EffectStat, String> {
+    open_decoder(path, format).map(analyze_stream)
+}
+
+fn analyze_stream(stream: Box<Decoder>) -> EffectStat {
 
     let stream_info = stream.info();
-    let samples: StreamIter<ReadStream<File>, i64> = StreamIter::new(stream);
+    let max_sample_value = 2.0f64.powi(i32::from(stream_info.bits_per_sample) - 1);
 
+    let mut total_samples = 0u64;
     let mut sample_count = 0;
     let mut last_active_sample = 0;
 
-    let sum_squares = samples.into_iter().fold(0.0f64, |acc, s| {
-        let sample = f64::from(s) / 32768.0;
+    let sum_squares = stream.fold(0.0f64, |acc, s| {
+        total_samples += 1;
+        let sample = s as f64 / max_sample_value;
         if sample > 0.01 {
             sample_count += 1;
             if sample > 0.025 {
@@ -256,9 +352,13 @@ fn analyze_flac_stream(stream: StreamReader<File>) -> EffectStat {
         }
     });
 
+    // Not all formats carry their length in the header, so we count the
+    // decoded samples instead
+    let frames = total_samples / u64::from(cmp::max(stream_info.channels, 1));
+
     let rms = (sum_squares / (sample_count as f64)).sqrt();
     EffectStat {
-        duration_ms: (stream_info.total_samples * 1000) / u64::from(stream_info.sample_rate),
+        duration_ms: (frames * 1000) / u64::from(stream_info.sample_rate),
         peak_db: (20.0 * rms.log(10.0)) as f32,
         silent_start_samples: 0,
         silent_end_samples: sample_count - last_active_sample
@@ -266,14 +366,13 @@ fn analyze_flac_stream(stream: StreamReader<File>) -> EffectStat {
 
 }
 
-fn download_file(
+fn effect_file_path(
     mut directory: PathBuf,
     name: &str,
-    url: &str,
     nickname: Option<&str>,
     ext: &str
 
-) -> Result<PathBuf, String> {
+) -> PathBuf {
 
     if let Some(nickname) = nickname {
         directory.push(&format!("{}.{}.{}", name, nickname.replace("#", "_"), ext));
@@ -282,6 +381,21 @@ fn download_file(
         directory.push(&format!("{}.{}", name, ext));
     }
 
+    directory
+
+}
+
+fn download_file(
+    directory: PathBuf,
+    name: &str,
+    url: &str,
+    nickname: Option<&str>,
+    ext: &str
+
+) -> Result<PathBuf, String> {
+
+    let directory = effect_file_path(directory, name, nickname, ext);
+
     let client = Client::new();
     client.get(url)
         .header(Connection::close())
@@ -305,3 +419,18 @@ fn download_file(
 
 }
 
+fn download_text(url: &str) -> Result<String, String> {
+    let client = Client::new();
+    client.get(url)
+        .header(Connection::close())
+        .send()
+        .map_err(|err| err.to_string())
+        .and_then(|resp| {
+            let mut text = String::new();
+            resp.take(MAX_TRANSCRIPT_BYTES)
+                .read_to_string(&mut text)
+                .map_err(|err| err.to_string())
+                .map(|_| text)
+        })
+}
+
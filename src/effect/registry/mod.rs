@@ -1,29 +1,40 @@
 // STD Dependencies -----------------------------------------------------------
 use std::fmt;
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 
 
 // External Dependencies ------------------------------------------------------
 use clock_ticks;
-use rand::{thread_rng, Rng};
-use edit_distance::edit_distance;
 
 
 // Internal Dependencies ------------------------------------------------------
-use ::bot::BotConfig;
 use ::effect::Effect;
 use ::server::ServerConfig;
+use ::text_util::normalize_words;
 
 
 // Modules --------------------------------------------------------------------
 mod manage;
+mod search;
+mod selection;
+mod tags;
+
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::selection::SelectionStrategy;
 
 
 // Effects Registration -------------------------------------------------------
 #[derive(Debug)]
 pub struct EffectRegistry {
     effects: HashMap<String, Effect>,
-    last_played: HashMap<String, u64>
+    transcript_words: HashMap<String, HashSet<String>>,
+    tags: HashMap<(String, String), Vec<String>>,
+    last_played: HashMap<String, u64>,
+    play_counts: HashMap<String, u64>,
+    bags: RefCell<HashMap<String, Vec<String>>>
 }
 
 
@@ -33,7 +44,11 @@ impl EffectRegistry {
     pub fn new() -> EffectRegistry {
         EffectRegistry {
             effects: HashMap::new(),
-            last_played: HashMap::new()
+            transcript_words: HashMap::new(),
+            tags: HashMap::new(),
+            last_played: HashMap::new(),
+            play_counts: HashMap::new(),
+            bags: RefCell::new(HashMap::new())
         }
     }
 
@@ -54,6 +69,7 @@ impl EffectRegistry {
             name.to_string(),
             clock_ticks::precise_time_ms()
         );
+        *self.play_counts.entry(name.to_string()).or_insert(0) += 1;
     }
 
     pub fn map_patterns(
@@ -61,7 +77,7 @@ impl EffectRegistry {
         patterns: &[String],
         aliases: Option<&HashMap<String, Vec<String>>>,
         match_all: bool,
-        config: &BotConfig
+        config: &ServerConfig
 
     ) -> Vec<&Effect> {
 
@@ -84,34 +100,42 @@ impl EffectRegistry {
 
     }
 
-    pub fn map_similiar(
-        &self,
-        patterns: &[String]
+    pub fn search_transcripts(&self, phrase: &str) -> Vec<&Effect> {
 
-    ) -> Vec<&str> {
-        self.effects.keys().map(|name| name.as_str()).filter(|name| {
-            patterns.iter().any(|p| {
+        let words = normalize_words(phrase);
+        let phrase = format!(" {} ", words.join(" "));
 
-                let len = p.len();
-                let p = if len > 2 && p.starts_with('*') && p.ends_with('*') {
-                    &p[1..len - 1]
-
-                } else if len > 1 && p.starts_with('*') {
-                    &p[1..]
+        // Collect all effects which share at least one word with the phrase
+        let mut matches: HashMap<&str, usize> = HashMap::new();
+        for word in &words {
+            if let Some(names) = self.transcript_words.get(word) {
+                for name in names {
+                    *matches.entry(name.as_str()).or_insert(0) += 1;
+                }
+            }
+        }
 
-                } else if len > 1 && p.ends_with('*') {
-                    &p[0..len - 1]
+        // Rank exact phrase matches first, then by the number of matching
+        // words and finally prefer shorter transcripts
+        let mut ranked: Vec<(&Effect, bool, usize)> = matches.into_iter().filter_map(|(name, count)| {
+            self.effects.get(name).map(|effect| {
+                let exact = format!(" {} ", effect.transcript_words()).contains(&phrase);
+                (effect, exact, count)
+            })
 
-                } else {
-                    p
-                };
+        }).collect();
 
-                name.ends_with(p) || name.starts_with(p)
-                    || name.contains(p) || edit_distance(name, p) < 3
+        ranked.sort_by(|a, b| {
+            match (b.1, b.2).cmp(&(a.1, a.2)) {
+                Ordering::Equal => (a.0.transcript_words().len(), &a.0.name).cmp(
+                    &(b.0.transcript_words().len(), &b.0.name)
+                ),
+                ordering => ordering
+            }
+        });
 
-            })
+        ranked.into_iter().map(|(effect, _, _)| effect).collect()
 
-        }).take(10).collect()
     }
 
 }
@@ -125,7 +149,7 @@ impl EffectRegistry {
         pattern: &str,
         aliases: Option<&HashMap<String, Vec<String>>>,
         match_all: bool,
-        config: &BotConfig
+        config: &ServerConfig
 
     ) -> Option<Vec<&Effect>> {
 
@@ -133,6 +157,7 @@ impl EffectRegistry {
         let mut matching_effects: Vec<&str> = self.effects.values().filter(|effect| {
             match_effect_pattern(
                 effect,
+                self.get_tags("effect", &effect.name),
                 *self.last_played.get(&effect.name).unwrap_or(&0),
                 pattern,
                 match_all,
@@ -151,7 +176,7 @@ impl EffectRegistry {
                 n.as_str()
 
             }).filter(|name| {
-                match_alias_pattern(name, pattern)
+                match_alias_pattern(name, self.get_tags("alias", name), pattern)
 
             }).collect();
 
@@ -169,30 +194,31 @@ impl EffectRegistry {
             Some(effects)
 
         } else {
-            self.map_random_effect(matching_effects, aliases, config)
+            self.map_random_effect(pattern, matching_effects, aliases, config)
         }
 
     }
 
     fn map_random_effect(
         &self,
+        pattern: &str,
         effects: Vec<&str>,
         aliases: Option<&HashMap<String, Vec<String>>>,
-        bot_config: &BotConfig
+        config: &ServerConfig
 
     ) -> Option<Vec<&Effect>> {
 
-        // Select one random effect...
-        if let Some(name) = thread_rng().choose(&effects[..]) {
+        // Select one effect based on the server's selection strategy...
+        if let Some(name) = self.select_effect(pattern, &effects[..], config.effect_selection) {
 
             // ...selected effect is already an actual effect
-            if let Some(effect) = self.effects.get(*name) {
+            if let Some(effect) = self.effects.get(name) {
                 Some(vec![effect])
 
             // ...selected effect is an alias, so we need to resolve its mapped effect
             } else if let Some(aliases) = aliases {
-                if let Some(effects) = aliases.get(*name) {
-                    Some(self.map_patterns(effects, None, false, bot_config))
+                if let Some(effects) = aliases.get(name) {
+                    Some(self.map_patterns(effects, None, false, config))
 
                 } else {
                     None
@@ -227,6 +253,7 @@ impl fmt::Display for EffectRegistry {
 // Helpers --------------------------------------------------------------------
 fn match_effect_pattern(
     effect: &Effect,
+    tags: &[String],
     last_played: u64,
     pattern: &str,
     ignore_recent: bool,
@@ -246,7 +273,15 @@ fn match_effect_pattern(
 
     // Transcript: Contains
     } else if len > 2 && pattern.starts_with('"') && pattern.ends_with('"') {
-        effect.transcript().contains(&pattern[1..len - 1].to_string())
+        let words = normalize_words(&pattern[1..len - 1]);
+        !words.is_empty() && format!(" {} ", effect.transcript_words()).contains(
+            &format!(" {} ", words.join(" "))
+        )
+
+    // Tag: Random
+    } else if len > 1 && pattern.starts_with('#') {
+        tags.iter().any(|tag| *tag == pattern[1..])
+            && (ignore_recent || !was_recently_played(last_played, recent_threshold))
 
     // Name: Endswith
     } else if len > 1 && pattern.starts_with('*') {
@@ -280,7 +315,7 @@ fn was_recently_played(last_played: u64, threshold: u64) -> bool {
     last_played + threshold > clock_ticks::precise_time_ms()
 }
 
-fn match_alias_pattern(alias: &str, pattern: &str) -> bool {
+fn match_alias_pattern(alias: &str, tags: &[String], pattern: &str) -> bool {
 
     let len = pattern.len();
 
@@ -292,6 +327,10 @@ fn match_alias_pattern(alias: &str, pattern: &str) -> bool {
     } else if len > 2 && pattern.starts_with('*') && pattern.ends_with('*') {
         alias.contains(&pattern[1..len - 1])
 
+    // Tag
+    } else if len > 1 && pattern.starts_with('#') {
+        tags.iter().any(|tag| *tag == pattern[1..])
+
     // Name: Endswith
     } else if len > 1 && pattern.starts_with('*') {
         alias.ends_with(&pattern[1..])
@@ -310,41 +349,3 @@ fn match_alias_pattern(alias: &str, pattern: &str) -> bool {
 
 }
 
-/*
-fn load_transcript(mut flac_path: PathBuf) -> Option<String> {
-
-    flac_path.set_extension("txt");
-
-    if let Ok(mut file) = File::open(flac_path) {
-
-        let mut text = String::new();
-        file.read_to_string(&mut text).expect("Failed to read flac transcript.");
-
-        // Remove linebreaks
-        text = text.to_lowercase().replace(|c| {
-            match c {
-                '\n' | '\r' | '\t' => true,
-                _ => false
-            }
-
-        }, " ");
-
-        // Split up into unique words
-        let mut parts: Vec<String> = text.split(' ').filter(|s| {
-            !s.trim().is_empty()
-
-        }).map(|s| {
-            s.to_string()
-
-        }).collect();
-
-        parts.dedup();
-
-        Some(parts.join(" "))
-
-    } else {
-        None
-    }
-
-}
-*/
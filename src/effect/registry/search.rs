@@ -0,0 +1,111 @@
+// External Dependencies ------------------------------------------------------
+use diesel::prelude::*;
+use diesel::types::{Double, Text};
+use diesel::expression::dsl::sql;
+use edit_distance::edit_distance;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::server::ServerConfig;
+use ::db::schema::effects_search::table as searchTable;
+use ::db::schema::effects_search::dsl::{server_id, kind, name as search_name};
+use ::text_util::normalize_words;
+use ::effect::{EffectRegistry, SearchMatch};
+
+
+// Public Effect Search Interface ---------------------------------------------
+impl EffectRegistry {
+
+    pub fn search(
+        &self,
+        config: &ServerConfig,
+        query: &str,
+        limit: usize
+
+    ) -> Vec<SearchMatch> {
+
+        let matches = self.search_index(config, query, limit);
+        if matches.is_empty() {
+            self.search_similiar(query, limit)
+
+        } else {
+            matches.into_iter().take(limit).collect()
+        }
+
+    }
+
+}
+
+
+// Internal Interface ---------------------------------------------------------
+impl EffectRegistry {
+
+    fn search_index(
+        &self,
+        config: &ServerConfig,
+        query: &str,
+        limit: usize
+
+    ) -> Vec<SearchMatch> {
+
+        // Every word is matched as a prefix, documents matching more of the
+        // words receive a better rank
+        let words = normalize_words(query);
+        if words.is_empty() {
+            return vec![];
+        }
+
+        let expression = words.iter().map(|word| {
+            format!("\"{}\"*", word)
+
+        }).collect::<Vec<String>>().join(" OR ");
+
+        // Names weigh the most, followed by tags and finally the contents
+        searchTable.select((kind, search_name)).filter(
+            server_id.eq(&config.table_id)
+
+        ).filter(
+            sql::<Text>("effects_search").eq(expression)
+
+        ).order(
+            sql::<Double>("bm25(effects_search, 0.0, 0.0, 10.0, 5.0, 1.0)")
+
+        ).limit(
+            limit as i64
+
+        ).load::<(String, String)>(&config.connection).unwrap_or_else(|err| {
+            warn!("{} Failed to search effects: {}", self, err);
+            vec![]
+
+        }).into_iter().map(|(effect_kind, name)| {
+            SearchMatch {
+                is_alias: effect_kind == "alias",
+                name: name
+            }
+
+        }).filter(|m| m.is_alias || self.effects.contains_key(&m.name)).collect()
+
+    }
+
+    fn search_similiar(&self, query: &str, limit: usize) -> Vec<SearchMatch> {
+
+        // Fall back to the edit distance in order to catch typos
+        let query = normalize_words(query).join("_");
+        let mut names: Vec<(usize, &str)> = self.effects.keys().map(|name| {
+            (edit_distance(name, &query), name.as_str())
+
+        }).filter(|&(distance, _)| distance < 3).collect();
+
+        names.sort();
+        names.into_iter().take(limit).map(|(_, name)| {
+            SearchMatch {
+                is_alias: false,
+                name: name.to_string()
+            }
+
+        }).collect()
+
+    }
+
+}
+
@@ -0,0 +1,127 @@
+// External Dependencies ------------------------------------------------------
+use diesel::prelude::*;
+use rand::{thread_rng, Rng};
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::server::ServerConfig;
+use ::db::schema::plays::table as playTable;
+use ::db::schema::plays::dsl::{server_id, effect_name};
+use ::effect::EffectRegistry;
+
+
+// Effect Selection Strategies ------------------------------------------------
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+pub enum SelectionStrategy {
+    #[serde(rename = "uniform")]
+    Uniform,
+    #[serde(rename = "least_recently_played")]
+    LeastRecentlyPlayed,
+    #[serde(rename = "inverse_play_count")]
+    InversePlayCount,
+    #[serde(rename = "shuffle_bag")]
+    ShuffleBag
+}
+
+impl Default for SelectionStrategy {
+    fn default() -> SelectionStrategy {
+        SelectionStrategy::Uniform
+    }
+}
+
+
+// Internal Interface ---------------------------------------------------------
+impl EffectRegistry {
+
+    pub fn select_effect<'a>(
+        &self,
+        pattern: &str,
+        names: &[&'a str],
+        strategy: SelectionStrategy
+
+    ) -> Option<&'a str> {
+        match strategy {
+            SelectionStrategy::Uniform => thread_rng().choose(names).cloned(),
+            SelectionStrategy::LeastRecentlyPlayed => self.select_least_recently_played(names),
+            SelectionStrategy::InversePlayCount => self.select_inverse_play_count(names),
+            SelectionStrategy::ShuffleBag => self.select_from_bag(pattern, names)
+        }
+    }
+
+    pub fn load_play_counts(&mut self, config: &ServerConfig) {
+
+        self.play_counts.clear();
+
+        let names: Vec<String> = self.effects.keys().cloned().collect();
+        for name in names {
+            let plays = playTable.filter(
+                server_id.eq(&config.table_id)
+
+            ).filter(
+                effect_name.eq(&name)
+
+            ).count().get_result::<i64>(&config.connection).unwrap_or(0);
+
+            if plays > 0 {
+                self.play_counts.insert(name, plays as u64);
+            }
+        }
+
+    }
+
+    fn select_least_recently_played<'a>(&self, names: &[&'a str]) -> Option<&'a str> {
+
+        // Shuffle first so that effects which were never played are picked
+        // in random order
+        let mut names = names.to_vec();
+        thread_rng().shuffle(&mut names[..]);
+
+        names.into_iter().min_by_key(|name| {
+            *self.last_played.get(*name).unwrap_or(&0)
+        })
+
+    }
+
+    fn select_inverse_play_count<'a>(&self, names: &[&'a str]) -> Option<&'a str> {
+
+        let weights: Vec<f64> = names.iter().map(|name| {
+            1.0 / (*self.play_counts.get(*name).unwrap_or(&0) + 1) as f64
+
+        }).collect();
+
+        let mut target = thread_rng().gen::<f64>() * weights.iter().fold(0.0, |acc, w| acc + w);
+        for (name, weight) in names.iter().zip(weights.iter()) {
+            if target < *weight {
+                return Some(*name);
+            }
+            target -= *weight;
+        }
+
+        names.last().cloned()
+
+    }
+
+    fn select_from_bag<'a>(&self, pattern: &str, names: &[&'a str]) -> Option<&'a str> {
+
+        let mut bags = self.bags.borrow_mut();
+        let bag = bags.entry(pattern.to_string()).or_insert_with(Vec::new);
+
+        // Take the next effect from the bag which is still a candidate
+        if let Some(index) = bag.iter().position(|name| names.contains(&name.as_str())) {
+            let name = bag.remove(index);
+            return names.iter().find(|n| **n == name).cloned();
+        }
+
+        // Once the bag is empty, refill it with all candidates in random order
+        let mut refill: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+        thread_rng().shuffle(&mut refill[..]);
+
+        let name = refill.pop();
+        *bag = refill;
+
+        name.and_then(|name| names.iter().find(|n| **n == name).cloned())
+
+    }
+
+}
+
@@ -0,0 +1,98 @@
+// External Dependencies ------------------------------------------------------
+use diesel;
+use diesel::prelude::*;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::server::ServerConfig;
+use ::effect::EffectRegistry;
+use ::db::models::{Tag, NewTag};
+use ::db::schema::tags::table as tagTable;
+use ::db::schema::tags::dsl::{server_id, kind as tag_kind, effect_name, name as tag_name};
+
+
+// Public Effect Tag Interface ------------------------------------------------
+impl EffectRegistry {
+
+    pub fn has_tag(&self, kind: &str, name: &str, tag: &str) -> bool {
+        self.get_tags(kind, name).iter().any(|t| t == tag)
+    }
+
+    pub fn get_tags(&self, kind: &str, name: &str) -> &[String] {
+        self.tags.get(&(kind.to_string(), name.to_string())).map(|tags| {
+            &tags[..]
+
+        }).unwrap_or(&[])
+    }
+
+    pub fn add_tag(
+        &mut self,
+        config: &ServerConfig,
+        kind: &str,
+        name: &str,
+        tag: &str
+
+    ) -> Result<(), String> {
+        diesel::insert(&NewTag {
+            server_id: &config.table_id,
+            kind: kind,
+            effect_name: name,
+            name: tag
+
+        }).into(tagTable).execute(&config.connection).map(|_| {
+            self.load_tags(config)
+
+        }).map_err(|_| {
+            "Failed to add tag to database.".to_string()
+        })
+    }
+
+    pub fn remove_tag(
+        &mut self,
+        config: &ServerConfig,
+        kind: &str,
+        name: &str,
+        tag: &str
+
+    ) -> Result<(), String> {
+        diesel::delete(
+            tagTable.filter(
+                server_id.eq(&config.table_id)
+
+            ).filter(
+                tag_kind.eq(kind)
+
+            ).filter(
+                effect_name.eq(name)
+
+            ).filter(
+                tag_name.eq(tag)
+            )
+
+        ).execute(&config.connection).map(|_| {
+            self.load_tags(config)
+
+        }).map_err(|_| {
+            "Failed to remove tag from database.".to_string()
+        })
+    }
+
+    pub fn load_tags(&mut self, config: &ServerConfig) {
+
+        self.tags.clear();
+
+        for tag in tagTable.filter(
+            server_id.eq(&config.table_id)
+
+        ).order(tag_name).load::<Tag>(
+            &config.connection
+
+        ).unwrap_or_else(|_| vec![]) {
+            // Effects and aliases of the same name are tagged independently
+            self.tags.entry((tag.kind, tag.effect_name)).or_insert_with(Vec::new).push(tag.name);
+        }
+
+    }
+
+}
+
@@ -9,7 +9,6 @@ use diesel::prelude::*;
 
 // Internal Dependencies ------------------------------------------------------
 use super::super::Server;
-use ::bot::BotConfig;
 use ::effect::Effect;
 use ::db::models::{Greeting, NewGreeting};
 use ::db::schema::greetings::dsl::{server_id, nickname as greeting_nickname};
@@ -19,28 +18,21 @@ use ::db::schema::greetings::table as greetingsTable;
 // Server Greeting Interface --------------------------------------------------
 impl Server {
 
-    pub fn get_greeting(
-        &self,
-        member_id: &UserId,
-        bot_config: &BotConfig
-
-    ) -> Option<Vec<&Effect>> {
+    pub fn get_greeting(&self, member_id: &UserId) -> Option<Vec<&Effect>> {
         if let Some(member) = self.members.get(member_id) {
 
             // User specific greeting
             if let Some(greeting) = self._get_greeting(&member.nickname) {
                 Some(self.map_effects(
                     &[greeting.effect_name.to_string()],
-                    false,
-                    bot_config
+                    false
                 ))
 
             // Default greeting
             } else if let Some(greeting) = self._get_greeting("default") {
                 Some(self.map_effects(
                     &[greeting.effect_name.to_string()],
-                    false,
-                    bot_config
+                    false
                 ))
 
             } else {
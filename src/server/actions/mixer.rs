@@ -0,0 +1,64 @@
+// External Dependencies ------------------------------------------------------
+use diesel;
+use diesel::prelude::*;
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::super::Server;
+use ::audio::{MixerCommand, MixerSettings};
+use ::db::models::{MixerSettings as MixerSettingsModel, NewMixerSettings};
+use ::db::schema::mixer_settings::dsl::server_id;
+use ::db::schema::mixer_settings::table as mixerSettingsTable;
+
+
+// Server Mixer Settings Interface --------------------------------------------
+impl Server {
+
+    pub fn get_mixer_settings(&self) -> MixerSettings {
+        mixerSettingsTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).first::<MixerSettingsModel>(&self.config.connection).map(|row| {
+            MixerSettings {
+                max_parallel_sources: row.max_parallel_sources as usize,
+                delay_ms: row.delay_ms as u64,
+                compressor_threshold: row.compressor_threshold,
+                master_volume_db: row.master_volume_db,
+                limiter: row.limiter
+            }
+
+        }).unwrap_or_default()
+    }
+
+    pub fn update_mixer_settings(&mut self, settings: MixerSettings) -> Result<(), String> {
+
+        let q = mixerSettingsTable.filter(server_id.eq(&self.config.table_id));
+        let result = self.config.connection.transaction::<_, diesel::result::Error, _>(|| {
+            try!(diesel::delete(q).execute(&self.config.connection));
+            diesel::insert(&NewMixerSettings {
+                server_id: &self.config.table_id,
+                max_parallel_sources: settings.max_parallel_sources as i32,
+                delay_ms: settings.delay_ms as i32,
+                compressor_threshold: settings.compressor_threshold,
+                master_volume_db: settings.master_volume_db,
+                limiter: settings.limiter
+
+            }).into(mixerSettingsTable).execute(&self.config.connection)
+
+        }).map(|_| ()).map_err(|_| {
+            "Failed to update mixer settings in database.".to_string()
+        });
+
+        // Apply the settings to the currently active mixer
+        if result.is_ok() {
+            if let Some(queue) = self.mixer_commands.as_mut() {
+                queue.send(MixerCommand::Configure(settings)).ok();
+            }
+        }
+
+        result
+
+    }
+
+}
+
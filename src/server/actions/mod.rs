@@ -2,6 +2,14 @@
 mod alias;
 mod ban;
 mod greeting;
+mod mixer;
+mod permission;
+mod playlist;
+mod scheduled;
+mod stats;
 mod uploader;
 mod streamers;
 
+
+// Re-Exports -----------------------------------------------------------------
+pub use self::permission::PermissionSubject;
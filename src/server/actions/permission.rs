@@ -0,0 +1,250 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+use std::ascii::AsciiExt;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::{RoleId, UserId};
+
+
+// External Dependencies ------------------------------------------------------
+use diesel;
+use diesel::prelude::*;
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::super::Server;
+use ::core::Member;
+use ::db::models::{Permission, NewPermission};
+use ::db::schema::permissions::dsl::{server_id, subject_kind, subject_id, command as permission_command};
+use ::db::schema::permissions::table as permissionTable;
+
+
+// Statics --------------------------------------------------------------------
+static ALL_COMMANDS: &str = "*";
+
+
+// Permission Subjects --------------------------------------------------------
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PermissionSubject {
+    User(UserId),
+    Role(RoleId)
+}
+
+impl PermissionSubject {
+
+    fn from_model(permission: &Permission) -> Option<PermissionSubject> {
+        permission.subject_id.parse::<u64>().ok().and_then(|id| {
+            match permission.subject_kind.as_str() {
+                "user" => Some(PermissionSubject::User(UserId(id))),
+                "role" => Some(PermissionSubject::Role(RoleId(id))),
+                _ => None
+            }
+        })
+    }
+
+    fn kind(&self) -> &'static str {
+        match *self {
+            PermissionSubject::User(_) => "user",
+            PermissionSubject::Role(_) => "role"
+        }
+    }
+
+    fn id(&self) -> String {
+        match *self {
+            PermissionSubject::User(id) => id.0.to_string(),
+            PermissionSubject::Role(id) => id.0.to_string()
+        }
+    }
+
+    fn matches(&self, member: &Member) -> bool {
+        match *self {
+            PermissionSubject::User(id) => member.id == id,
+            PermissionSubject::Role(id) => member.roles.contains(&id)
+        }
+    }
+
+}
+
+impl fmt::Display for PermissionSubject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PermissionSubject::User(id) => write!(f, "User#{}", id),
+            PermissionSubject::Role(id) => write!(f, "Role#{}", id)
+        }
+    }
+}
+
+
+// Server Permission Interface ------------------------------------------------
+impl Server {
+
+    pub fn member_has_permission(
+        &self,
+        member: &Member,
+        command: &str,
+        require_admin: bool
+
+    ) -> bool {
+
+        if member.is_admin {
+            true
+
+        } else {
+
+            let permissions = self.command_permissions(command);
+
+            // Grants for all commands only ever extend a member's permissions
+            // and never cover admin only commands, those need to be granted
+            // explicitly
+            if permissions.iter().any(|&(ref c, ref subject)| {
+                (c == command || (c == ALL_COMMANDS && !require_admin)) && subject.matches(member)
+            }) {
+                true
+
+            // Grants for a specific command restrict its usage to the granted
+            // users and roles
+            } else if permissions.iter().any(|&(ref c, _)| c == command) {
+                false
+
+            } else {
+                !require_admin
+            }
+
+        }
+
+    }
+
+    pub fn parse_permission_subject(&self, name: &str) -> Option<PermissionSubject> {
+
+        // Role mentions
+        if name.starts_with("<@&") && name.ends_with('>') {
+            name[3..name.len() - 1].parse::<u64>().ok().map(|id| {
+                PermissionSubject::Role(RoleId(id))
+            })
+
+        // User mentions
+        } else if name.starts_with("<@") && name.ends_with('>') {
+            name[2..name.len() - 1].trim_left_matches('!').parse::<u64>().ok().map(|id| {
+                PermissionSubject::User(UserId(id))
+            })
+
+        // User#ident
+        } else if name.contains('#') {
+            self.members.values().find(|m| m.nickname == name).map(|m| {
+                PermissionSubject::User(m.id)
+            })
+
+        // Role names
+        } else {
+            self.roles.iter().find(|&(_, n)| n.eq_ignore_ascii_case(name)).map(|(id, _)| {
+                PermissionSubject::Role(*id)
+            })
+        }
+
+    }
+
+    pub fn permission_subject_name(&self, subject: &PermissionSubject) -> String {
+        match *subject {
+            PermissionSubject::User(id) => self.members.get(&id).map(|m| {
+                m.nickname.to_string()
+
+            }).unwrap_or_else(|| subject.to_string()),
+            PermissionSubject::Role(id) => self.roles.get(&id).map(|name| {
+                format!("@{}", name)
+
+            }).unwrap_or_else(|| subject.to_string())
+        }
+    }
+
+    pub fn has_permission(&self, subject: &PermissionSubject, command: &str) -> bool {
+        permissionTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            subject_kind.eq(subject.kind())
+
+        ).filter(
+            subject_id.eq(subject.id())
+
+        ).filter(
+            permission_command.eq(command)
+
+        ).count().get_result(&self.config.connection).unwrap_or(0) > 0
+    }
+
+    pub fn add_permission(&mut self, subject: &PermissionSubject, command: &str) -> bool {
+        if self.has_permission(subject, command) {
+            false
+
+        } else {
+            diesel::insert(&NewPermission {
+                server_id: &self.config.table_id,
+                subject_kind: subject.kind(),
+                subject_id: subject.id(),
+                command: command
+
+            }).into(permissionTable).execute(&self.config.connection).is_ok()
+        }
+    }
+
+    pub fn remove_permission(&mut self, subject: &PermissionSubject, command: &str) -> bool {
+        if self.has_permission(subject, command) {
+            diesel::delete(
+                permissionTable.filter(
+                    server_id.eq(&self.config.table_id)
+
+                ).filter(
+                    subject_kind.eq(subject.kind())
+
+                ).filter(
+                    subject_id.eq(subject.id())
+
+                ).filter(
+                    permission_command.eq(command)
+                )
+
+            ).execute(&self.config.connection).is_ok()
+
+        } else {
+            false
+        }
+    }
+
+    pub fn list_permissions(&self) -> Vec<(String, PermissionSubject)> {
+        permissionTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).order(
+            permission_command
+
+        ).load::<Permission>(
+            &self.config.connection
+
+        ).unwrap_or_else(|_| vec![]).into_iter().filter_map(|permission| {
+            PermissionSubject::from_model(&permission).map(|subject| {
+                (permission.command, subject)
+            })
+
+        }).collect()
+    }
+
+    fn command_permissions(&self, command: &str) -> Vec<(String, PermissionSubject)> {
+        permissionTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            permission_command.eq(command).or(permission_command.eq(ALL_COMMANDS))
+
+        ).load::<Permission>(
+            &self.config.connection
+
+        ).unwrap_or_else(|_| vec![]).into_iter().filter_map(|permission| {
+            PermissionSubject::from_model(&permission).map(|subject| {
+                (permission.command, subject)
+            })
+
+        }).collect()
+    }
+
+}
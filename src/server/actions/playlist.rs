@@ -0,0 +1,118 @@
+// Discord Dependencies -------------------------------------------------------
+use discord::model::UserId;
+
+
+// External Dependencies ------------------------------------------------------
+use diesel;
+use diesel::prelude::*;
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::super::Server;
+use ::db::models::{Playlist, NewPlaylist};
+use ::db::schema::playlists::dsl::{server_id, name as playlist_name, effect_names as playlist_effects};
+use ::db::schema::playlists::table as playlistTable;
+
+
+// Server Playlists Interface -------------------------------------------------
+impl Server {
+
+    pub fn has_playlist(&self, name: &str) -> bool {
+        playlistTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            playlist_name.eq(name)
+
+        ).count().get_result(&self.config.connection).unwrap_or(0) > 0
+    }
+
+    pub fn get_playlist_creator(&self, name: &str) -> Option<UserId> {
+        playlistTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            playlist_name.eq(name)
+
+        ).first::<Playlist>(&self.config.connection).ok().and_then(|playlist| {
+            playlist.creator.parse::<u64>().ok().map(UserId)
+        })
+    }
+
+    pub fn add_playlist(
+        &mut self,
+        name: &str,
+        creator: &UserId,
+        effect_names: &[String]
+
+    ) -> Result<(), String> {
+
+        // Existing playlists keep their original creator
+        let result = if self.has_playlist(name) {
+            diesel::update(
+                playlistTable.filter(
+                    server_id.eq(&self.config.table_id)
+
+                ).filter(
+                    playlist_name.eq(name)
+                )
+
+            ).set(
+                playlist_effects.eq(effect_names.join(" "))
+
+            ).execute(&self.config.connection)
+
+        } else {
+            diesel::insert(&NewPlaylist {
+                server_id: &self.config.table_id,
+                name: name,
+                creator: &creator.0.to_string(),
+                effect_names: &effect_names.join(" ")
+
+            }).into(playlistTable).execute(&self.config.connection)
+        };
+
+        result.map(|_| ()).map_err(|_| {
+            "Failed to store playlist in database.".to_string()
+        })
+
+    }
+
+    pub fn remove_playlist(&mut self, name: &str) -> Result<(), String> {
+        diesel::delete(
+            playlistTable.filter(
+                server_id.eq(&self.config.table_id)
+
+            ).filter(
+                playlist_name.eq(name)
+            )
+
+        ).execute(&self.config.connection).map(|_| ()).map_err(|_| {
+            "Failed to remove playlist from database.".to_string()
+        })
+    }
+
+    pub fn get_playlist(&self, name: &str) -> Option<Vec<String>> {
+        playlistTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            playlist_name.eq(name)
+
+        ).first::<Playlist>(&self.config.connection).ok().map(|playlist| {
+            playlist.effect_names.split(' ').map(|s| s.to_string()).collect()
+        })
+    }
+
+    pub fn list_playlists(&self) -> Vec<Playlist> {
+        playlistTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).order(playlist_name).load::<Playlist>(
+            &self.config.connection
+
+        ).unwrap_or_else(|_| vec![])
+    }
+
+}
+
@@ -0,0 +1,156 @@
+// Discord Dependencies -------------------------------------------------------
+use discord::model::UserId;
+
+
+// External Dependencies ------------------------------------------------------
+use diesel;
+use diesel::prelude::*;
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::super::Server;
+use ::action::{ActionHandler, ActionGroup, TimedActions};
+use ::action::registry;
+use ::db::models::{ScheduledAction, NewScheduledAction};
+use ::db::schema::scheduled_actions::dsl::{
+    server_id, id as action_id, due_at,
+    kind as action_kind, interval_ms as action_interval
+};
+use ::db::schema::scheduled_actions::table as scheduledTable;
+
+
+// Server Scheduled Action Interface ------------------------------------------
+impl Server {
+
+    pub fn add_scheduled_action(
+        &mut self,
+        due: i64,
+        interval_ms: u64,
+        owner: &UserId,
+        label: &str,
+        kind: &str,
+        payload: String
+
+    ) -> Option<i32> {
+        diesel::insert(&NewScheduledAction {
+            server_id: &self.config.table_id,
+            due_at: due,
+            interval_ms: interval_ms as i64,
+            kind: kind,
+            payload: payload,
+            owner_id: owner.0.to_string(),
+            label: label
+
+        }).into(scheduledTable).execute(&self.config.connection).and_then(|_| {
+            scheduledTable.select(action_id)
+                          .filter(server_id.eq(&self.config.table_id))
+                          .order(action_id.desc())
+                          .first::<i32>(&self.config.connection)
+
+        }).ok()
+    }
+
+    pub fn update_scheduled_action(&mut self, id: i32, due: i64) {
+        diesel::update(
+            scheduledTable.filter(
+                server_id.eq(&self.config.table_id)
+
+            ).filter(
+                action_id.eq(id)
+            )
+
+        ).set(due_at.eq(due)).execute(&self.config.connection).ok();
+    }
+
+    pub fn remove_scheduled_action(&mut self, id: i32) -> bool {
+        diesel::delete(
+            scheduledTable.filter(
+                server_id.eq(&self.config.table_id)
+
+            ).filter(
+                action_id.eq(id)
+            )
+
+        ).execute(&self.config.connection).unwrap_or(0) > 0
+    }
+
+    // Actions which follow the bot configuration instead of being scheduled by
+    // members are kept as a single stored action with the configured interval,
+    // returns whether a new action of the kind needs to be scheduled
+    pub fn sync_scheduled_action(&mut self, kind: &str, interval_ms: Option<u64>) -> bool {
+
+        let intervals = scheduledTable.select(action_interval)
+                                      .filter(server_id.eq(&self.config.table_id))
+                                      .filter(action_kind.eq(kind))
+                                      .load::<i64>(&self.config.connection)
+                                      .unwrap_or_else(|_| vec![]);
+
+        let current = interval_ms.map_or(false, |interval| intervals == vec![interval as i64]);
+        if !current && !intervals.is_empty() {
+            info!("{} Removing outdated scheduled actions ({})", self, kind);
+            diesel::delete(
+                scheduledTable.filter(
+                    server_id.eq(&self.config.table_id)
+
+                ).filter(
+                    action_kind.eq(kind)
+                )
+
+            ).execute(&self.config.connection).ok();
+        }
+
+        interval_ms.is_some() && !current
+
+    }
+
+    pub fn has_scheduled_action(&self, id: i32) -> bool {
+        scheduledTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            action_id.eq(id)
+
+        ).count().get_result(&self.config.connection).unwrap_or(0) > 0
+    }
+
+    pub fn get_scheduled_action(&self, id: i32) -> Option<ScheduledAction> {
+        scheduledTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            action_id.eq(id)
+
+        ).first::<ScheduledAction>(&self.config.connection).ok()
+    }
+
+    pub fn list_scheduled_actions(&self) -> Vec<ScheduledAction> {
+        scheduledTable.filter(server_id.eq(&self.config.table_id))
+                      .order(due_at)
+                      .load::<ScheduledAction>(&self.config.connection)
+                      .unwrap_or_else(|_| vec![])
+    }
+
+    pub fn restore_scheduled_actions(&self) -> ActionGroup {
+        self.list_scheduled_actions().into_iter().filter_map(|row| {
+            if let Some(action) = registry::restore(&row.kind, &row.payload) {
+                info!("{} Resuming scheduled action #{} ({})", self, row.id, row.kind);
+                Some(TimedActions::Scheduled::restore(
+                    self.id,
+                    row.id,
+                    row.due_at,
+                    row.interval_ms as u64,
+                    UserId(row.owner_id.parse::<u64>().unwrap_or(0)),
+                    row.label,
+                    action
+
+                ) as Box<ActionHandler>)
+
+            } else {
+                warn!("{} Failed to restore scheduled action #{} ({})", self, row.id, row.kind);
+                None
+            }
+
+        }).collect()
+    }
+
+}
@@ -0,0 +1,181 @@
+// Discord Dependencies -------------------------------------------------------
+use discord::model::{ChannelId, UserId};
+
+
+// External Dependencies ------------------------------------------------------
+use diesel;
+use diesel::prelude::*;
+use diesel::result::QueryResult;
+
+
+// Internal Dependencies ------------------------------------------------------
+use super::super::{Server, PlaybackSource};
+use ::effect::Effect;
+use ::db::models::NewPlay;
+use ::db::schema::plays::table as playTable;
+use ::db::schema::plays::dsl::{
+    server_id, effect_name as play_effect_name, user_id as play_user_id,
+    played_at, source as play_source
+};
+use ::action::TimedActions;
+
+
+// Server Playback Statistics Interface ---------------------------------------
+impl Server {
+
+    pub fn record_plays(
+        &self,
+        channel_id: &ChannelId,
+        effects: &[Effect],
+        queued: bool,
+        source: PlaybackSource
+    ) {
+        // Persisted schedules which predate play statistics carry no user
+        if source.user_id().0 == 0 {
+            return;
+        }
+
+        let now = TimedActions::timestamp_ms();
+        for effect in effects {
+            diesel::insert(&NewPlay {
+                server_id: &self.config.table_id,
+                effect_name: &effect.name,
+                user_id: source.user_id().0.to_string(),
+                channel_id: channel_id.0.to_string(),
+                played_at: now,
+                queued: queued,
+                source: source.as_str()
+
+            }).into(playTable).execute(&self.config.connection).map_err(|err| {
+                warn!("{} Failed to record play of {}: {}", self, effect, err);
+
+            }).ok();
+        }
+    }
+
+    pub fn most_played_effects(
+        &self,
+        user_id: Option<&UserId>,
+        limit: usize
+
+    ) -> Vec<(String, i64)> {
+
+        let mut counts: Vec<(String, i64)> = self.map_effects(&["*".to_string()], true).into_iter().map(|effect| {
+            (effect.name.to_string(), self.effect_play_count(&effect.name, user_id))
+
+        }).filter(|&(_, plays)| plays > 0).collect();
+
+        sort_play_counts(&mut counts, limit);
+        counts
+
+    }
+
+    pub fn most_active_members(
+        &self,
+        effect_name: Option<&str>,
+        limit: usize
+
+    ) -> Vec<(UserId, i64)> {
+
+        let mut counts: Vec<(UserId, i64)> = self.members.values().filter(|member| {
+            !member.is_bot
+
+        }).map(|member| {
+            (member.id, self.member_play_count(&member.id, effect_name))
+
+        }).filter(|&(_, plays)| plays > 0).collect();
+
+        sort_play_counts(&mut counts, limit);
+        counts
+
+    }
+
+    pub fn never_played_effects(&self) -> Vec<String> {
+
+        let mut effects: Vec<String> = self.map_effects(&["*".to_string()], true).into_iter().filter(|effect| {
+            self.effect_play_count(&effect.name, None) == 0
+
+        }).map(|effect| effect.name.to_string()).collect();
+
+        effects.sort();
+        effects
+
+    }
+
+    pub fn effect_play_summary(&self, effect_name: &str) -> (i64, Option<i64>) {
+
+        let q = playTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            play_effect_name.eq(effect_name)
+        );
+
+        let plays = self.log_play_query(q.clone().count().get_result::<i64>(&self.config.connection));
+        let last_played_at = q.select(played_at).order(played_at.desc()).first::<i64>(
+            &self.config.connection
+
+        ).ok();
+
+        (plays, last_played_at)
+
+    }
+
+    fn effect_play_count(&self, effect_name: &str, user_id: Option<&UserId>) -> i64 {
+
+        let q = playTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            play_effect_name.eq(effect_name)
+        );
+
+        self.log_play_query(if let Some(id) = user_id {
+            q.filter(play_user_id.eq(id.0.to_string())).count().get_result::<i64>(&self.config.connection)
+
+        } else {
+            q.count().get_result::<i64>(&self.config.connection)
+        })
+
+    }
+
+    fn member_play_count(&self, user_id: &UserId, effect_name: Option<&str>) -> i64 {
+
+        // Automatic playbacks are not counted towards a member's activity
+        let q = playTable.filter(
+            server_id.eq(&self.config.table_id)
+
+        ).filter(
+            play_user_id.eq(user_id.0.to_string())
+
+        ).filter(
+            play_source.ne("greeting")
+
+        ).filter(
+            play_source.ne("recording")
+        );
+
+        self.log_play_query(if let Some(name) = effect_name {
+            q.filter(play_effect_name.eq(name)).count().get_result::<i64>(&self.config.connection)
+
+        } else {
+            q.count().get_result::<i64>(&self.config.connection)
+        })
+
+    }
+
+    fn log_play_query(&self, result: QueryResult<i64>) -> i64 {
+        result.unwrap_or_else(|err| {
+            warn!("{} Failed to query play statistics: {}", self, err);
+            0
+        })
+    }
+
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn sort_play_counts<T: Ord>(counts: &mut Vec<(T, i64)>, limit: usize) {
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts.truncate(limit);
+}
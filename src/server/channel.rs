@@ -58,6 +58,13 @@ impl Server {
         None
     }
 
+    pub fn get_voice_channel_id(&self, channel_name: &str) -> Option<ChannelId> {
+        self.channels.values().find(|channel| {
+            channel.is_voice() && channel.name == channel_name
+
+        }).map(|channel| channel.id)
+    }
+
     pub fn channel_voice_members(&self, channel_id: &ChannelId) -> Vec<&Member> {
         self.channels.get(channel_id).map(|channel| {
             channel.voice_users().iter().filter_map(|user_id| {
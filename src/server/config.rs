@@ -1,7 +1,11 @@
 // STD Dependencies -----------------------------------------------------------
-use std::env;
+use std::cmp;
 use std::fmt;
+use std::fs::{self, File};
+use std::io::Read;
 use std::path::PathBuf;
+use std::time::SystemTime;
+use std::collections::HashMap;
 
 
 // Discord Dependencies -------------------------------------------------------
@@ -9,6 +13,8 @@ use discord::model::ServerId;
 
 
 // External Dependencies ------------------------------------------------------
+use toml;
+use clock_ticks;
 use diesel::Connection;
 use diesel::sqlite::SqliteConnection;
 use diesel::connection::SimpleConnection;
@@ -16,6 +22,53 @@ use diesel::connection::SimpleConnection;
 
 // Internal Dependencies ------------------------------------------------------
 use ::bot::BotConfig;
+use ::db::UserFlag;
+use ::audio::{AudioFormat, EncoderSettings};
+use ::effect::SelectionStrategy;
+use super::{Cooldown, RecordingRules};
+
+
+// Statics --------------------------------------------------------------------
+static CONFIG_CHECK_INTERVAL_MILLIS: u64 = 5000;
+static MIN_CLIP_BUFFER_MILLIS: u64 = 30000;
+static MAX_CLIP_BUFFER_MILLIS: u64 = 60000;
+static DEFAULT_JITTER_BUFFER_MILLIS: u32 = 200;
+static MIN_JITTER_BUFFER_MILLIS: u32 = 20;
+static MAX_JITTER_BUFFER_MILLIS: u32 = 2000;
+
+
+// Server Configuration File --------------------------------------------------
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    admins: Vec<String>,
+    #[serde(default)]
+    uploaders: Vec<String>,
+    #[serde(default)]
+    banned: Vec<String>,
+    effect_playback_separation_ms: Option<u64>,
+    greeting_separation_ms: Option<u64>,
+    flac_max_file_size: Option<u64>,
+    flac_sample_rate: Option<u32>,
+    flac_bits_per_sample: Option<u8>,
+    effect_selection: Option<SelectionStrategy>,
+    ducking_db: Option<f32>,
+    recordings_max_age_ms: Option<u64>,
+    recordings_max_size: Option<u64>,
+    recording_start_effect: Option<String>,
+    recording_stop_effect: Option<String>,
+    recording_jitter_buffer_ms: Option<u32>,
+    recording_format: Option<String>,
+    recording_channels: Option<usize>,
+    recording_quality: Option<f32>,
+    recording_rules: Option<RecordingRules>,
+    clip_buffer_ms: Option<u64>,
+    transcriber_path: Option<PathBuf>,
+    #[serde(default)]
+    transcriber_arguments: Vec<String>,
+    #[serde(default)]
+    cooldowns: HashMap<String, Cooldown>
+}
 
 
 // Server Configuration Abstraction -------------------------------------------
@@ -23,48 +76,288 @@ pub struct ServerConfig {
     pub table_id: String,
     pub connection: SqliteConnection,
     pub effects_path: PathBuf,
-    pub recordings_path: PathBuf
+    pub recordings_path: PathBuf,
+    pub effect_playback_separation_ms: u64,
+    pub greeting_separation_ms: u64,
+    pub flac_max_file_size: u64,
+    pub flac_sample_rate: u32,
+    pub flac_bits_per_sample: u8,
+    pub effect_selection: SelectionStrategy,
+    pub ducking_db: Option<f32>,
+    pub recordings_max_age_ms: Option<u64>,
+    pub recordings_max_size: Option<u64>,
+    pub recording_start_effect: Option<String>,
+    pub recording_stop_effect: Option<String>,
+    pub recording_jitter_buffer_ms: u32,
+    pub recording_encoder: EncoderSettings,
+    pub recording_rules: Option<RecordingRules>,
+    pub clip_buffer_ms: Option<u64>,
+    pub transcriber_path: Option<PathBuf>,
+    pub transcriber_arguments: Vec<String>,
+    pub cooldowns: HashMap<String, Cooldown>,
+    config_file_path: PathBuf,
+    config_file_modified: Option<SystemTime>,
+    config_file_checked: u64,
+    defaults: ConfigFile
 }
 
 impl ServerConfig {
 
     pub fn new(server_id: &ServerId, bot_config: &BotConfig) -> Self {
 
-        let mut effects_path = bot_config.config_path.clone();
-        effects_path.push(server_id.0.to_string());
+        let mut server_path = bot_config.config_path.clone();
+        server_path.push(server_id.0.to_string());
+
+        let mut effects_path = server_path.clone();
         effects_path.push("effects");
 
-        let mut recordings_path = bot_config.config_path.clone();
-        recordings_path.push(server_id.0.to_string());
+        let mut recordings_path = server_path.clone();
         recordings_path.push("recordings");
 
-        ServerConfig {
+        let mut config_file_path = server_path;
+        config_file_path.push("config.toml");
+
+        let mut config = ServerConfig {
             table_id: format!("{}", server_id),
-            connection: establish_connection().expect("Failed to establish database connection."),
+            connection: establish_connection(&bot_config.database_url).expect("Failed to establish database connection."),
             effects_path: effects_path,
-            recordings_path: recordings_path
+            recordings_path: recordings_path,
+            effect_playback_separation_ms: bot_config.effect_playback_separation_ms,
+            greeting_separation_ms: bot_config.greeting_separation_ms,
+            flac_max_file_size: bot_config.flac_max_file_size,
+            flac_sample_rate: bot_config.flac_sample_rate,
+            flac_bits_per_sample: bot_config.flac_bits_per_sample,
+            effect_selection: SelectionStrategy::default(),
+            ducking_db: None,
+            recordings_max_age_ms: None,
+            recordings_max_size: None,
+            recording_start_effect: None,
+            recording_stop_effect: None,
+            recording_jitter_buffer_ms: DEFAULT_JITTER_BUFFER_MILLIS,
+            recording_encoder: EncoderSettings::default(),
+            recording_rules: None,
+            clip_buffer_ms: None,
+            transcriber_path: None,
+            transcriber_arguments: Vec::new(),
+            cooldowns: HashMap::new(),
+            config_file_path: config_file_path,
+            config_file_modified: None,
+            config_file_checked: 0,
+            defaults: ConfigFile {
+                effect_playback_separation_ms: Some(bot_config.effect_playback_separation_ms),
+                greeting_separation_ms: Some(bot_config.greeting_separation_ms),
+                flac_max_file_size: Some(bot_config.flac_max_file_size),
+                flac_sample_rate: Some(bot_config.flac_sample_rate),
+                flac_bits_per_sample: Some(bot_config.flac_bits_per_sample),
+                .. ConfigFile::default()
+            }
+        };
+
+        config.reload();
+        config
+
+    }
+
+    pub fn reload_if_modified(&mut self) -> bool {
+
+        let now = clock_ticks::precise_time_ms();
+        if now - self.config_file_checked < CONFIG_CHECK_INTERVAL_MILLIS {
+            false
+
+        } else {
+            self.config_file_checked = now;
+            if self.file_modified() != self.config_file_modified {
+                info!("{} Configuration file changed on disk", self);
+                self.reload()
+
+            } else {
+                false
+            }
         }
 
     }
 
 }
 
+
+// Internal Interface ---------------------------------------------------------
+impl ServerConfig {
+
+    fn reload(&mut self) -> bool {
+
+        self.config_file_modified = self.file_modified();
+
+        match self.read_file() {
+            Ok(file) => {
+
+                info!("{} Loaded {}", self, self.config_file_path.display());
+
+                self.effect_playback_separation_ms = file.effect_playback_separation_ms.or(
+                    self.defaults.effect_playback_separation_ms
+
+                ).unwrap_or(0);
+
+                self.greeting_separation_ms = file.greeting_separation_ms.or(
+                    self.defaults.greeting_separation_ms
+
+                ).unwrap_or(0);
+
+                self.flac_max_file_size = file.flac_max_file_size.or(
+                    self.defaults.flac_max_file_size
+
+                ).unwrap_or(0);
+
+                self.flac_sample_rate = file.flac_sample_rate.or(
+                    self.defaults.flac_sample_rate
+
+                ).unwrap_or(0);
+
+                self.flac_bits_per_sample = file.flac_bits_per_sample.or(
+                    self.defaults.flac_bits_per_sample
+
+                ).unwrap_or(0);
+
+                self.effect_selection = file.effect_selection.unwrap_or_default();
+                self.ducking_db = file.ducking_db;
+                self.recordings_max_age_ms = file.recordings_max_age_ms;
+                self.recordings_max_size = file.recordings_max_size;
+                self.recording_start_effect = file.recording_start_effect.clone();
+                self.recording_stop_effect = file.recording_stop_effect.clone();
+                self.recording_jitter_buffer_ms = file.recording_jitter_buffer_ms.map_or(
+                    DEFAULT_JITTER_BUFFER_MILLIS,
+                    |ms| cmp::max(cmp::min(ms, MAX_JITTER_BUFFER_MILLIS), MIN_JITTER_BUFFER_MILLIS)
+                );
+                self.recording_encoder = encoder_settings(&file).unwrap_or_else(|err| {
+                    warn!("{} Invalid recording encoder settings: {}", self, err);
+                    EncoderSettings::default()
+                });
+                self.recording_rules = file.recording_rules.clone();
+                self.clip_buffer_ms = file.clip_buffer_ms.map(|ms| {
+                    cmp::max(cmp::min(ms, MAX_CLIP_BUFFER_MILLIS), MIN_CLIP_BUFFER_MILLIS)
+                });
+
+                self.transcriber_path = file.transcriber_path.clone();
+                self.transcriber_arguments = file.transcriber_arguments.clone();
+                self.cooldowns = file.cooldowns.clone();
+
+                self.sync_users(UserFlag::Admin, &file.admins);
+                self.sync_users(UserFlag::Uploader, &file.uploaders);
+                self.sync_users(UserFlag::Banned, &file.banned);
+                true
+
+            },
+            Err(err) => {
+                warn!("{} Failed to load {}: {}", self, self.config_file_path.display(), err);
+                false
+            }
+        }
+
+    }
+
+    fn sync_users(&self, flag: UserFlag, current: &[String]) {
+
+        // The previously synced users are kept in the database, so users
+        // which were removed from the file while the bot was offline are
+        // revoked as well. Flags granted via commands are left untouched.
+        let previous = ::db::get_synced_users(self, flag);
+        for nickname in previous.iter().filter(|n| !current.contains(n)) {
+            ::db::set_user_flag(self, nickname, flag, false).map_err(|err| {
+                warn!("{} Failed to revoke {:?} from {}: {}", self, flag, nickname, err);
+
+            }).ok();
+        }
+
+        // Grant flags to all users which are listed in the file
+        for nickname in current {
+            ::db::set_user_flag(self, nickname, flag, true).map_err(|err| {
+                warn!("{} Failed to grant {:?} to {}: {}", self, flag, nickname, err);
+
+            }).ok();
+        }
+
+        ::db::set_synced_users(self, flag, current).map_err(|err| {
+            warn!("{} Failed to store synced {:?} users: {}", self, flag, err);
+
+        }).ok();
+
+    }
+
+    fn file_modified(&self) -> Option<SystemTime> {
+        fs::metadata(&self.config_file_path).and_then(|meta| {
+            meta.modified()
+
+        }).ok()
+    }
+
+    fn read_file(&self) -> Result<ConfigFile, String> {
+        if self.config_file_path.exists() {
+            File::open(&self.config_file_path).map_err(|err| {
+                err.to_string()
+
+            }).and_then(|mut file| {
+                let mut text = String::new();
+                file.read_to_string(&mut text).map_err(|err| {
+                    err.to_string()
+
+                }).map(|_| text)
+
+            }).and_then(|text| {
+                toml::from_str::<ConfigFile>(&text).map_err(|err| {
+                    err.to_string()
+                })
+            })
+
+        } else {
+            Ok(ConfigFile::default())
+        }
+    }
+
+}
+
+
+// Traits ---------------------------------------------------------------------
 impl fmt::Debug for ServerConfig {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "[ServerConfig]")
     }
 }
 
+impl fmt::Display for ServerConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[ServerConfig {}]", self.table_id)
+    }
+}
+
 
 // Helpers --------------------------------------------------------------------
-fn establish_connection() -> Result<SqliteConnection, String> {
-    env::var("DATABASE_URL").map_err(|err| {
-        err.to_string()
+fn encoder_settings(file: &ConfigFile) -> Result<EncoderSettings, String> {
 
-    }).and_then(|url| {
-        SqliteConnection::establish(&url).map_err(|err| {
-            err.to_string()
-        })
+    let defaults = EncoderSettings::default();
+    let format = match file.recording_format.as_ref().map(|format| format.as_str()) {
+        None => defaults.format,
+        Some("flac") => AudioFormat::Flac,
+        Some("vorbis") => AudioFormat::Vorbis,
+        Some("opus") => AudioFormat::Opus,
+        Some(format) => return Err(format!("Unsupported recording format \"{}\".", format))
+    };
+
+    let channels = match file.recording_channels {
+        None => defaults.channels,
+        Some(channels) if channels == 1 || channels == 2 => channels,
+        Some(channels) => return Err(format!("Unsupported number of recording channels: {}.", channels))
+    };
+
+    Ok(EncoderSettings {
+        format: format,
+        channels: channels,
+        quality: file.recording_quality.unwrap_or(defaults.quality).max(0.0).min(1.0)
+    })
+
+}
+
+fn establish_connection(url: &str) -> Result<SqliteConnection, String> {
+    SqliteConnection::establish(url).map_err(|err| {
+        err.to_string()
 
     }).and_then(|connection| {
         connection.batch_execute(
@@ -78,4 +371,3 @@ fn establish_connection() -> Result<SqliteConnection, String> {
         })
     })
 }
-
@@ -0,0 +1,99 @@
+// STD Dependencies -----------------------------------------------------------
+use std::collections::HashMap;
+
+
+// Discord Dependencies -------------------------------------------------------
+use discord::model::UserId;
+
+
+// External Dependencies ------------------------------------------------------
+use clock_ticks;
+
+
+// Statics --------------------------------------------------------------------
+static MAX_TRACKED_BUCKETS: usize = 256;
+
+
+// Command Cooldown Abstraction -----------------------------------------------
+#[derive(Debug, Copy, Clone, Deserialize)]
+pub struct Cooldown {
+    pub capacity: u32,
+    pub refill_ms: u64
+}
+
+
+// Token Bucket Implementation ------------------------------------------------
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: u64
+}
+
+impl Bucket {
+
+    fn refill(&mut self, cooldown: &Cooldown, now: u64) {
+        let refilled = (now - self.updated) as f64 / cooldown.refill_ms as f64;
+        self.tokens = (self.tokens + refilled).min(f64::from(cooldown.capacity));
+        self.updated = now;
+    }
+
+}
+
+
+// Cooldown Tracking ----------------------------------------------------------
+#[derive(Debug, Default)]
+pub struct CooldownTracker {
+    buckets: HashMap<(UserId, String), (Bucket, Cooldown)>
+}
+
+impl CooldownTracker {
+
+    // Consumes a token from the member's bucket for the command, returning
+    // the remaining wait time in milliseconds if the bucket is empty
+    pub fn consume(
+        &mut self,
+        user_id: UserId,
+        command: &str,
+        cooldown: Cooldown
+
+    ) -> Result<(), u64> {
+
+        if cooldown.capacity == 0 || cooldown.refill_ms == 0 {
+            return Ok(());
+        }
+
+        let now = clock_ticks::precise_time_ms();
+        if self.buckets.len() > MAX_TRACKED_BUCKETS {
+            self.prune(now);
+        }
+
+        let entry = self.buckets.entry((user_id, command.to_string())).or_insert_with(|| {
+            (Bucket {
+                tokens: f64::from(cooldown.capacity),
+                updated: now
+
+            }, cooldown)
+        });
+
+        entry.1 = cooldown;
+        entry.0.refill(&cooldown, now);
+
+        if entry.0.tokens >= 1.0 {
+            entry.0.tokens -= 1.0;
+            Ok(())
+
+        } else {
+            Err(((1.0 - entry.0.tokens) * cooldown.refill_ms as f64).ceil() as u64)
+        }
+
+    }
+
+    // Drops all buckets which have fully refilled in the meantime
+    fn prune(&mut self, now: u64) {
+        self.buckets.retain(|_, &mut (ref mut bucket, ref cooldown)| {
+            bucket.refill(cooldown, now);
+            bucket.tokens < f64::from(cooldown.capacity)
+        });
+    }
+
+}
@@ -1,16 +1,48 @@
 // Discord Dependencies -------------------------------------------------------
-use discord::model::ChannelId;
+use discord::model::{ChannelId, UserId};
 
 
 // Internal Dependencies ------------------------------------------------------
-use ::audio::MixerCommand;
+use ::audio::{AudioFormat, MixerCommand};
 use ::action::ActionHandler;
-use ::bot::BotConfig;
 use ::core::EventQueue;
-use ::effect::Effect;
+use ::effect::{Effect, EffectSettings, SearchMatch};
 use super::Server;
 
 
+// Playback Sources -----------------------------------------------------------
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PlaybackSource {
+    Command(UserId),
+    Greeting(UserId),
+    Playlist(UserId),
+    Schedule(UserId),
+    Recording(UserId)
+}
+
+impl PlaybackSource {
+
+    pub fn user_id(&self) -> UserId {
+        match *self {
+            PlaybackSource::Command(user_id) | PlaybackSource::Greeting(user_id) |
+            PlaybackSource::Playlist(user_id) | PlaybackSource::Schedule(user_id) |
+            PlaybackSource::Recording(user_id) => user_id
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            PlaybackSource::Command(_) => "command",
+            PlaybackSource::Greeting(_) => "greeting",
+            PlaybackSource::Playlist(_) => "playlist",
+            PlaybackSource::Schedule(_) => "schedule",
+            PlaybackSource::Recording(_) => "recording"
+        }
+    }
+
+}
+
+
 // Server Effect Interface ----------------------------------------------------
 impl Server {
 
@@ -19,6 +51,8 @@ impl Server {
         channel_id: &ChannelId,
         effects: &[Effect],
         queued: bool,
+        loops: usize,
+        source: PlaybackSource,
         queue: &mut EventQueue,
         complete_action: Option<Box<ActionHandler>>
     ) {
@@ -52,6 +86,8 @@ impl Server {
         // we are pinned to another channel already.
         if has_channel && self.join_voice(channel_id, queue) {
 
+            self.record_plays(channel_id, effects, queued, source);
+
             // Add playback IDs to list of effects
             let mut effects: Vec<(Effect, Option<Box<ActionHandler>>)> = effects.into_iter().map(|effect| {
 
@@ -70,10 +106,10 @@ impl Server {
             if let Some(queue) = self.mixer_commands.as_mut() {
                 queue.send(MixerCommand::SetBitrate(bitrate)).ok();
                 queue.send(if queued {
-                    MixerCommand::QueueEffects(effects)
+                    MixerCommand::QueueEffects(effects, loops)
 
                 } else {
-                    MixerCommand::PlayEffects(effects)
+                    MixerCommand::PlayEffects(effects, loops)
 
                 }).ok();
             }
@@ -94,6 +130,15 @@ impl Server {
         }
     }
 
+    pub fn control_effects(&mut self, command: MixerCommand) -> bool {
+        if let Some(queue) = self.mixer_commands.as_mut() {
+            queue.send(command).is_ok()
+
+        } else {
+            false
+        }
+    }
+
     pub fn has_effect(&self, effect_name: &str) -> bool {
         self.effects.has_effect(effect_name)
     }
@@ -102,11 +147,10 @@ impl Server {
         self.effects.get_effect(effect_name)
     }
 
-    pub fn has_matching_effects(&self, effect_name: &str, bot_config: &BotConfig) -> bool {
+    pub fn has_matching_effects(&self, effect_name: &str) -> bool {
         !self.map_effects(
             &[effect_name.to_string()],
-            true,
-            bot_config
+            true
 
         ).is_empty()
     }
@@ -114,20 +158,23 @@ impl Server {
     pub fn map_effects(
         &self,
         patterns: &[String],
-        match_all: bool,
-        bot_config: &BotConfig
+        match_all: bool
 
     ) -> Vec<&Effect> {
         self.effects.map_patterns(
             patterns,
             Some(self.get_alias_map()),
             match_all,
-            bot_config
+            &self.config
         )
     }
 
-    pub fn map_similiar_effects(&self, patterns: &[String]) -> Vec<&str> {
-        self.effects.map_similiar(patterns)
+    pub fn search_effect_transcripts(&self, phrase: &str) -> Vec<&Effect> {
+        self.effects.search_transcripts(phrase)
+    }
+
+    pub fn search_effects(&self, query: &str, limit: usize) -> Vec<SearchMatch> {
+        self.effects.search(&self.config, query, limit)
     }
 
     pub fn rename_effect(&mut self, effect: &Effect, effect_name: &str) -> Result<(), String> {
@@ -138,18 +185,47 @@ impl Server {
         self.effects.delete_effect(&self.config, effect)
     }
 
+    pub fn update_effect_settings(
+        &mut self,
+        effect: &Effect,
+        settings: &EffectSettings
+
+    ) -> Result<(), String> {
+        self.effects.update_effect_settings(&self.config, effect, settings)
+    }
+
+    pub fn has_effect_tag(&self, name: &str, tag: &str) -> bool {
+        self.effects.has_tag(self.tag_kind(name), name, tag)
+    }
+
+    pub fn get_effect_tags(&self, name: &str) -> &[String] {
+        self.effects.get_tags(self.tag_kind(name), name)
+    }
+
+    pub fn add_effect_tag(&mut self, name: &str, tag: &str) -> Result<(), String> {
+        let kind = self.tag_kind(name);
+        self.effects.add_tag(&self.config, kind, name, tag)
+    }
+
+    pub fn remove_effect_tag(&mut self, name: &str, tag: &str) -> Result<(), String> {
+        let kind = self.tag_kind(name);
+        self.effects.remove_tag(&self.config, kind, name, tag)
+    }
+
     pub fn download_effect(
         &mut self,
         effect_name: &str,
         upload_url: &str,
-        uploader: &str
+        uploader: &str,
+        format: AudioFormat
 
     ) -> Result<(), String> {
         self.effects.download_effect(
             &self.config,
             effect_name,
             upload_url,
-            uploader
+            uploader,
+            format
         )
     }
 
@@ -168,3 +244,19 @@ impl Server {
 
 }
 
+
+// Internal Interface ---------------------------------------------------------
+impl Server {
+
+    fn tag_kind(&self, name: &str) -> &'static str {
+        // Effects take precedence over aliases of the same name
+        if self.has_effect(name) {
+            "effect"
+
+        } else {
+            "alias"
+        }
+    }
+
+}
+
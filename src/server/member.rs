@@ -5,6 +5,7 @@ use std::ascii::AsciiExt;
 // Discord Dependencies -------------------------------------------------------
 use discord::model::{
     ChannelId,
+    RoleId,
     UserId,
     User as DiscordUser,
     Member as DiscordMember,
@@ -21,7 +22,7 @@ use ::bot::BotConfig;
 use ::audio::MixerCommand;
 use ::action::{ActionGroup, EffectActions};
 use ::core::{EventQueue, Member};
-use super::{Server, ServerRecordingStatus, ServerVoiceStatus};
+use super::{PlaybackSource, Server, ServerRecordingStatus, ServerVoiceStatus};
 
 
 // Server Member Interface ----------------------------------------------------
@@ -87,12 +88,29 @@ impl Server {
         member.is_admin = user.is_admin;
         member.is_uploader = user.is_uploader;
         member.is_banned = user.is_banned;
+        member.is_recording_opt_out = user.is_recording_opt_out;
 
         info!("{} {} added", self, member);
         self.members.insert(member.id, member);
 
     }
 
+    pub fn update_member_flags(&mut self) {
+        for member in self.members.values_mut() {
+            let user = ::db::get_user_or_default(&self.config, &member.nickname);
+            member.is_admin = user.is_admin;
+            member.is_uploader = user.is_uploader;
+            member.is_banned = user.is_banned;
+            member.is_recording_opt_out = user.is_recording_opt_out;
+        }
+    }
+
+    pub fn update_member_roles(&mut self, member_id: &UserId, roles: Vec<RoleId>) {
+        if let Some(member) = self.members.get_mut(member_id) {
+            member.roles = roles;
+        }
+    }
+
     pub fn remove_member_from_user(&mut self, user: DiscordUser) {
         if let Some(member) = self.members.remove(&user.id) {
             info!("{} {} removed", self, member);
@@ -102,12 +120,11 @@ impl Server {
     pub fn update_member_voice_state(
         &mut self,
         voice_state: DiscordVoiceState,
-        queue: &mut EventQueue,
-        bot_config: &BotConfig
+        queue: &mut EventQueue
 
     ) -> ActionGroup {
 
-        let actions = match self.apply_voice_state(&voice_state) {
+        let mut actions = match self.apply_voice_state(&voice_state) {
 
             VoiceStateResult::UpdateServerVoice => {
 
@@ -129,7 +146,7 @@ impl Server {
             },
 
             VoiceStateResult::UpdateMemberVoice(true) => {
-                self.greet_member(&voice_state, bot_config)
+                self.greet_member(&voice_state)
             },
 
             VoiceStateResult::UpdateMemberVoice(false) | VoiceStateResult::Ignore => {
@@ -160,6 +177,7 @@ impl Server {
 
         }
 
+        actions.append(&mut self.apply_recording_rules());
         actions
 
     }
@@ -223,12 +241,7 @@ impl Server {
 
     }
 
-    fn greet_member(
-        &mut self,
-        voice_state: &DiscordVoiceState,
-        bot_config: &BotConfig
-
-    ) -> ActionGroup {
+    fn greet_member(&mut self, voice_state: &DiscordVoiceState) -> ActionGroup {
 
         let now = clock_ticks::precise_time_ms();
         let channel_id = if now - self.startup_time < 1000 {
@@ -236,7 +249,7 @@ impl Server {
             None
 
         } else if let Some(member) = self.members.get_mut(&voice_state.user_id) {
-            if member.should_be_greeted(bot_config) {
+            if member.should_be_greeted(self.config.greeting_separation_ms) {
                 Some(member.voice_channel_id.unwrap())
 
             } else {
@@ -248,15 +261,13 @@ impl Server {
         };
 
         if let Some(channel_id) = channel_id {
-            if let Some(effects) = self.get_greeting(
-                &voice_state.user_id,
-                bot_config
-            ) {
+            if let Some(effects) = self.get_greeting(&voice_state.user_id) {
                 vec![EffectActions::Play::new(
                     self.id,
                     channel_id,
                     effects,
                     false,
+                    PlaybackSource::Greeting(voice_state.user_id),
                     None
                 )]
 
@@ -290,6 +301,7 @@ impl Server {
             info!("{} Left voice channel", self);
             self.voice_status = ServerVoiceStatus::Left;
             self.recording_status = ServerRecordingStatus::Stopped;
+            self.recording_session = None;
             self.pinned_channel_id = None;
             self.voice_channel_id = None;
         }
@@ -1,12 +1,13 @@
 // STD Dependencies -----------------------------------------------------------
 use std::fmt;
+use std::cell::RefCell;
 use std::sync::mpsc;
 use std::collections::HashMap;
 
 
 // Discord Dependencies -------------------------------------------------------
 use discord::model::{
-    ChannelId, UserId, ServerId,
+    ChannelId, UserId, RoleId, ServerId,
     Server as DiscordServer,
     Channel as DiscordChannel,
     PossibleServer,
@@ -20,7 +21,7 @@ use clock_ticks;
 
 
 // Internal Dependencies ------------------------------------------------------
-use ::audio::{MixerCommand, MixerEvent};
+use ::audio::{ClipBuffer, MixerCommand, MixerEvent, RecorderCommand, RecorderEvent};
 use ::bot::BotConfig;
 use ::core::{Channel, EventQueue, Member};
 use ::effect::EffectRegistry;
@@ -30,13 +31,23 @@ use ::effect::EffectRegistry;
 mod actions;
 mod channel;
 mod config;
+mod cooldown;
 mod effect;
 mod member;
+mod recording;
+mod rules;
 mod voice;
 
 
 // Re-Exports -----------------------------------------------------------------
+pub use self::actions::PermissionSubject;
 pub use self::config::ServerConfig;
+pub use self::cooldown::Cooldown;
+pub use self::effect::PlaybackSource;
+pub use self::recording::Recording;
+pub use self::rules::RecordingRules;
+use self::cooldown::CooldownTracker;
+use self::rules::AutoRecording;
 
 
 // Server Voice Abstraction ---------------------------------------------------
@@ -70,12 +81,21 @@ pub struct Server {
     pinned_channel_id: Option<ChannelId>,
     voice_status: ServerVoiceStatus,
     recording_status: ServerRecordingStatus,
+    cooldowns: RefCell<CooldownTracker>,
 
     mixer_commands: Option<mpsc::Sender<MixerCommand>>,
     mixer_events: Option<mpsc::Receiver<MixerEvent>>,
+    recorder_commands: Option<mpsc::Sender<RecorderCommand>>,
+    recorder_events: Option<mpsc::Receiver<RecorderEvent>>,
+    recording_session: Option<String>,
+    recording_activity: u64,
+    auto_recording: AutoRecording,
+    clip_buffer: Option<ClipBuffer>,
+    transcription: Option<mpsc::Receiver<String>>,
 
     channels: HashMap<ChannelId, Channel>,
-    members: HashMap<UserId, Member>
+    members: HashMap<UserId, Member>,
+    roles: HashMap<RoleId, String>
 
 }
 
@@ -111,10 +131,19 @@ impl Server {
                     pinned_channel_id: None,
                     voice_status: ServerVoiceStatus::Left,
                     recording_status: ServerRecordingStatus::Stopped,
+                    cooldowns: RefCell::new(CooldownTracker::default()),
                     mixer_commands: None,
                     mixer_events: None,
+                    recorder_commands: None,
+                    recorder_events: None,
+                    recording_session: None,
+                    recording_activity: 0,
+                    auto_recording: AutoRecording::Idle,
+                    clip_buffer: None,
+                    transcription: None,
                     channels: HashMap::new(),
-                    members: HashMap::new()
+                    members: HashMap::new(),
+                    roles: HashMap::new()
                 }
             },
 
@@ -132,14 +161,27 @@ impl Server {
                     pinned_channel_id: None,
                     voice_status: ServerVoiceStatus::Left,
                     recording_status: ServerRecordingStatus::Stopped,
+                    cooldowns: RefCell::new(CooldownTracker::default()),
                     mixer_commands: None,
                     mixer_events: None,
+                    recorder_commands: None,
+                    recorder_events: None,
+                    recording_session: None,
+                    recording_activity: 0,
+                    auto_recording: AutoRecording::Idle,
+                    clip_buffer: None,
+                    transcription: None,
                     channels: HashMap::new(),
-                    members: HashMap::new()
+                    members: HashMap::new(),
+                    roles: HashMap::new()
                 };
 
                 server.reload();
 
+                for role in live_server.roles {
+                    server.roles.insert(role.id, role.name);
+                }
+
                 for member in live_server.members {
                     server.add_member(member, bot_config);
                 }
@@ -149,11 +191,7 @@ impl Server {
                 }
 
                 for voice_state in live_server.voice_states {
-                    server.update_member_voice_state(
-                        voice_state,
-                        queue,
-                        bot_config
-                    );
+                    server.update_member_voice_state(voice_state, queue);
                 }
 
                 // TODO why was sync_members needed in the first place?
@@ -173,14 +211,58 @@ impl Server {
     pub fn update(&mut self, server: DiscordServer) {
         self.name = server.name;
         self.region = server.region;
+        self.roles = server.roles.into_iter().map(|role| {
+            (role.id, role.name)
+
+        }).collect();
         info!("{} Updated", self);
     }
 
+    pub fn update_role(&mut self, role_id: RoleId, name: String) {
+        self.roles.insert(role_id, name);
+    }
+
+    pub fn remove_role(&mut self, role_id: &RoleId) {
+        self.roles.remove(role_id);
+        for member in self.members.values_mut() {
+            member.roles.retain(|id| id != role_id);
+        }
+    }
+
     pub fn reload(&mut self) {
         self.update_aliases();
         self.effects.reload(&self.config);
     }
 
+    pub fn reload_config(&mut self) {
+        if self.config.reload_if_modified() {
+            info!("{} Configuration reloaded", self);
+            self.update_member_flags();
+        }
+    }
+
+    pub fn config(&self) -> &ServerConfig {
+        &self.config
+    }
+
+    pub fn consume_cooldown(
+        &self,
+        member: &Member,
+        bucket: &str,
+        default: Option<Cooldown>
+
+    ) -> Result<(), u64> {
+        if member.is_admin {
+            Ok(())
+
+        } else if let Some(cooldown) = self.config.cooldowns.get(bucket).cloned().or(default) {
+            self.cooldowns.borrow_mut().consume(member.id, bucket, cooldown)
+
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn get_bot(&self) -> Option<&Member> {
         self.members.values().find(|m| m.is_active_bot)
     }
@@ -214,6 +296,24 @@ impl Server {
         }
     }
 
+    pub fn recorder_events(&mut self) -> Vec<RecorderEvent> {
+
+        let mut events = Vec::new();
+        if let Some(ref recorder_events) = self.recorder_events {
+            while let Ok(event) = recorder_events.try_recv() {
+                events.push(event)
+            }
+        }
+
+        // Voice activity only matters for the automatic recording rules
+        if events.iter().any(|event| *event == RecorderEvent::VoiceActivity) {
+            self.recording_activity = clock_ticks::precise_time_ms();
+        }
+
+        events.into_iter().filter(|event| *event != RecorderEvent::VoiceActivity).collect()
+
+    }
+
 }
 
 
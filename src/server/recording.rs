@@ -0,0 +1,271 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fs::{self, File};
+use std::thread;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, TryRecvError};
+use std::time::UNIX_EPOCH;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::audio::{
+    CommandLineTranscriber, Transcriber, RecordingManifest, transcribe_session,
+    RECORDING_MANIFEST_FILENAME, TRANSCRIPT_FILENAME, TRANSCRIPT_FAILED_FILENAME
+};
+use ::action::TimedActions;
+use super::Server;
+
+
+// Recording Session Abstraction ----------------------------------------------
+pub struct Recording {
+    pub name: String,
+    pub size: u64,
+    pub modified: i64,
+    pub files: Vec<String>,
+    pub manifest: Option<RecordingManifest>
+}
+
+
+// Server Recordings Interface ------------------------------------------------
+impl Server {
+
+    pub fn list_recordings(&self) -> Vec<Recording> {
+
+        let mut recordings: Vec<Recording> = fs::read_dir(&self.config.recordings_path).map(|entries| {
+            entries.filter_map(|entry| entry.ok()).filter(|entry| {
+                entry.path().is_dir()
+
+            }).filter_map(|entry| {
+                read_recording(&entry.path())
+
+            }).collect()
+
+        }).unwrap_or_else(|_| Vec::new());
+
+        // Newest sessions first
+        recordings.sort_by(|a, b| b.modified.cmp(&a.modified));
+        recordings
+
+    }
+
+    pub fn get_recording(&self, name: &str) -> Option<Recording> {
+        self.list_recordings().into_iter().find(|recording| recording.name == name)
+    }
+
+    pub fn is_active_recording(&self, name: &str) -> bool {
+        self.is_recording_voice() && self.recording_session.as_ref().map_or(false, |session| {
+            session == name
+        })
+    }
+
+    pub fn delete_recording(&self, name: &str) -> Result<(), String> {
+        if self.is_active_recording(name) {
+            Err("Recording is still in progress.".to_string())
+
+        } else if let Some(recording) = self.get_recording(name) {
+            let mut path = self.config.recordings_path.clone();
+            path.push(&recording.name);
+            fs::remove_dir_all(path).map_err(|err| err.to_string())
+
+        } else {
+            Err("Recording does not exist.".to_string())
+        }
+    }
+
+    pub fn enforce_recording_retention(&self) -> Vec<String> {
+
+        let max_age = self.config.recordings_max_age_ms;
+        let max_size = self.config.recordings_max_size;
+        if max_age.is_none() && max_size.is_none() {
+            return Vec::new();
+        }
+
+        let now = TimedActions::timestamp_ms();
+        let recordings = self.list_recordings();
+        let mut total: u64 = recordings.iter().map(|r| r.size).sum();
+
+        // Remove the oldest sessions first, skipping any active recording
+        let mut deleted = Vec::new();
+        for recording in recordings.iter().rev() {
+
+            let expired = max_age.map_or(false, |age| now - recording.modified > age as i64);
+            let oversized = max_size.map_or(false, |size| total > size);
+            if (!expired && !oversized) || self.is_active_recording(&recording.name) {
+                continue;
+            }
+
+            match self.delete_recording(&recording.name) {
+                Ok(_) => {
+                    info!("{} Deleted recording \"{}\"", self, recording.name);
+                    total -= recording.size;
+                    deleted.push(recording.name.to_string());
+                },
+                Err(err) => warn!("{} Failed to delete recording \"{}\": {}", self, recording.name, err)
+            }
+
+        }
+
+        deleted
+
+    }
+
+    pub fn get_recording_transcript(&self, name: &str) -> Result<Vec<String>, String> {
+
+        let mut path = self.config.recordings_path.clone();
+        path.push(name);
+
+        if path.join(TRANSCRIPT_FILENAME).exists() {
+            read_text(&path.join(TRANSCRIPT_FILENAME)).map(|text| {
+                text.lines().map(|line| line.to_string()).collect()
+            })
+
+        } else if path.join(TRANSCRIPT_FAILED_FILENAME).exists() {
+            Err(format!(
+                "Transcription failed: {}",
+                read_text(&path.join(TRANSCRIPT_FAILED_FILENAME)).unwrap_or_default().trim()
+            ))
+
+        } else if self.config.transcriber_path.is_none() {
+            Err("Transcription is not enabled on this server.".to_string())
+
+        } else {
+            Err("The recording has not been transcribed yet.".to_string())
+        }
+
+    }
+
+    pub fn transcribe_recordings(&mut self) -> Vec<String> {
+
+        // Collect the sessions which were completed by a running worker
+        let mut transcribed = Vec::new();
+        let mut running = false;
+        if let Some(receiver) = self.transcription.as_ref() {
+            loop {
+                match receiver.try_recv() {
+                    Ok(name) => transcribed.push(name),
+                    Err(TryRecvError::Empty) => {
+                        running = true;
+                        break;
+                    },
+                    Err(TryRecvError::Disconnected) => break
+                }
+            }
+        }
+
+        if running {
+            return transcribed;
+        }
+
+        self.transcription = None;
+
+        let transcriber_path = match self.config.transcriber_path.clone() {
+            Some(path) => path,
+            None => return transcribed
+        };
+
+        // Only finished sessions have a manifest
+        let recordings_path = self.config.recordings_path.clone();
+        let pending: Vec<PathBuf> = self.list_recordings().into_iter().filter(|recording| {
+            recording.manifest.is_some()
+                && !recording.files.iter().any(|file| {
+                    file == TRANSCRIPT_FILENAME || file == TRANSCRIPT_FAILED_FILENAME
+                })
+
+        }).map(|recording| recordings_path.join(&recording.name)).collect();
+
+        if pending.is_empty() {
+            return transcribed;
+        }
+
+        let transcriber: Box<Transcriber> = Box::new(CommandLineTranscriber::new(
+            transcriber_path,
+            self.config.transcriber_arguments.clone()
+        ));
+
+        info!("{} Transcribing {} recording(s) via {}...", self, pending.len(), transcriber.name());
+
+        // Transcription takes a long time, so sessions are processed one
+        // after another on a separate thread
+        let (sender, receiver) = mpsc::channel::<String>();
+        let server = self.to_string();
+        thread::spawn(move || {
+            for path in pending {
+
+                let name = path.file_name().and_then(|name| name.to_str()).unwrap_or("").to_string();
+                let result = transcribe_session(&*transcriber, &path).and_then(|transcript| {
+                    transcript.write(&path.join(TRANSCRIPT_FILENAME))
+                });
+
+                match result {
+                    Ok(_) => {
+                        sender.send(name).ok();
+                    },
+                    Err(err) => {
+                        warn!("{} Failed to transcribe recording \"{}\": {}", server, name, err);
+
+                        // Failed sessions are not retried automatically
+                        File::create(path.join(TRANSCRIPT_FAILED_FILENAME)).and_then(|mut file| {
+                            file.write_all(err.as_bytes())
+
+                        }).ok();
+                    }
+                }
+
+            }
+        });
+
+        self.transcription = Some(receiver);
+        transcribed
+
+    }
+
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn read_text(path: &Path) -> Result<String, String> {
+    let mut text = String::new();
+    let mut file = try!(File::open(path).map_err(|err| err.to_string()));
+    file.read_to_string(&mut text).map(|_| text).map_err(|err| err.to_string())
+}
+
+fn read_recording(path: &Path) -> Option<Recording> {
+
+    let name = match path.file_name().and_then(|name| name.to_str()) {
+        Some(name) => name.to_string(),
+        None => return None
+    };
+
+    let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok().and_then(|time| {
+        time.duration_since(UNIX_EPOCH).ok()
+
+    }).map(|duration| {
+        (duration.as_secs() * 1000) as i64 + i64::from(duration.subsec_nanos() / 1_000_000)
+
+    }).unwrap_or(0);
+
+    let mut size = 0;
+    let mut files = Vec::new();
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            if let Ok(meta) = entry.metadata() {
+                size += meta.len();
+            }
+            if let Some(file) = entry.file_name().to_str() {
+                files.push(file.to_string());
+            }
+        }
+    }
+
+    files.sort();
+
+    Some(Recording {
+        name: name,
+        size: size,
+        modified: modified,
+        files: files,
+        manifest: RecordingManifest::read(&path.join(RECORDING_MANIFEST_FILENAME)).ok()
+    })
+
+}
+
@@ -0,0 +1,165 @@
+// Discord Dependencies -------------------------------------------------------
+use discord::model::ChannelId;
+
+
+// External Dependencies ------------------------------------------------------
+use clock_ticks;
+
+
+// Internal Dependencies ------------------------------------------------------
+use ::action::{ActionGroup, MessageActions, RecordingActions};
+use super::Server;
+
+
+// Statics --------------------------------------------------------------------
+static START_TIMEOUT_MILLIS: u64 = 10000;
+
+
+// Automatic Recording Rules --------------------------------------------------
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordingRules {
+    pub channel: String,
+    pub min_members: Option<usize>,
+    pub silence_timeout_ms: Option<u64>,
+    pub max_length_ms: Option<u64>,
+    pub announce_channel: Option<String>
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AutoRecording {
+    Idle,
+    Starting(u64),
+    Active(u64),
+
+    // Stopped by a rule, waits for the channel to empty before recording again
+    Suspended
+}
+
+
+// Server Recording Rules Interface -------------------------------------------
+impl Server {
+
+    pub fn apply_recording_rules(&mut self) -> ActionGroup {
+
+        let rules = match self.config.recording_rules.clone() {
+            Some(rules) => rules,
+            None => return vec![]
+        };
+
+        let (channel_id, bot_id) = match (self.get_channel_id(&rules.channel), self.get_bot().map(|bot| bot.id)) {
+            (Some(channel_id), Some(bot_id)) => (channel_id, bot_id),
+            _ => return vec![]
+        };
+
+        let now = clock_ticks::precise_time_ms();
+        let min_members = rules.min_members.unwrap_or(1);
+        let members = self.channel_voice_members(&channel_id).iter().filter(|member| {
+            !member.is_bot
+
+        }).count();
+
+        let announce_channel_id = self.recording_announce_channel_id();
+        let channel_name = rules.channel.to_string();
+        let mut actions: ActionGroup = Vec::new();
+
+        let state = self.auto_recording;
+        self.auto_recording = match state {
+
+            AutoRecording::Idle => if members >= min_members && !self.is_recording_voice() {
+                info!("{} Automatically recording #{} with {} member(s)", self, channel_name, members);
+                actions.push(RecordingActions::Start::new(self.id, channel_id, bot_id, announce_channel_id));
+                AutoRecording::Starting(now)
+
+            } else {
+                AutoRecording::Idle
+            },
+
+            AutoRecording::Starting(started) => if self.is_recording_voice() {
+                self.recording_activity = now;
+                AutoRecording::Active(started)
+
+            } else if now.saturating_sub(started) > START_TIMEOUT_MILLIS {
+                warn!("{} Automatic recording of #{} failed to start", self, channel_name);
+                if let Some(announce_channel_id) = announce_channel_id {
+                    actions.push(MessageActions::Send::single_public_channel(
+                        &announce_channel_id,
+                        format!("Audio recording of the voice channel {} could not be started.", channel_name)
+                    ));
+                }
+                AutoRecording::Suspended
+
+            } else {
+                AutoRecording::Starting(started)
+            },
+
+            AutoRecording::Active(started) => if !self.is_recording_voice() {
+
+                // Stopped manually or because the bot left the channel
+                info!("{} Automatic recording of #{} ended", self, channel_name);
+                if let Some(announce_channel_id) = announce_channel_id {
+                    actions.push(MessageActions::Send::single_public_channel(
+                        &announce_channel_id,
+                        format!("Audio recording has **ended** in the voice channel {}.", channel_name)
+                    ));
+                }
+                AutoRecording::Suspended
+
+            } else if let Some(reason) = stop_reason(&rules, members, now.saturating_sub(started), now.saturating_sub(self.recording_activity)) {
+                info!("{} Automatically stopping recording of #{}: {}", self, channel_name, reason);
+                actions.push(RecordingActions::Stop::new(self.id, channel_id, bot_id, announce_channel_id));
+                if members < min_members {
+                    AutoRecording::Idle
+
+                } else {
+                    AutoRecording::Suspended
+                }
+
+            } else {
+                AutoRecording::Active(started)
+            },
+
+            AutoRecording::Suspended => if members < min_members {
+                AutoRecording::Idle
+
+            } else {
+                AutoRecording::Suspended
+            }
+
+        };
+
+        actions
+
+    }
+
+    pub fn recording_announce_channel_id(&self) -> Option<ChannelId> {
+        self.config.recording_rules.as_ref().and_then(|rules| {
+            rules.announce_channel.as_ref()
+
+        }).and_then(|name| self.get_channel_id(name.trim_left_matches('#')))
+    }
+
+}
+
+
+// Helpers --------------------------------------------------------------------
+fn stop_reason(
+    rules: &RecordingRules,
+    members: usize,
+    length: u64,
+    silence: u64
+
+) -> Option<&'static str> {
+    if members < rules.min_members.unwrap_or(1) {
+        Some("not enough members")
+
+    } else if rules.max_length_ms.map_or(false, |max| length >= max) {
+        Some("maximum length reached")
+
+    } else if rules.silence_timeout_ms.map_or(false, |timeout| silence >= timeout) {
+        Some("silence timeout")
+
+    } else {
+        None
+    }
+}
+
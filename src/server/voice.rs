@@ -1,16 +1,23 @@
 // STD Dependencies -----------------------------------------------------------
 use std::sync::mpsc;
+use std::collections::HashSet;
 
 
 // Discord Dependencies -------------------------------------------------------
-use discord::model::ChannelId;
+use discord::model::{ChannelId, UserId};
 use discord::model::permissions::{VOICE_CONNECT, VOICE_SPEAK};
+use discord::voice::AudioReceiver;
 
 
 // Internal Dependencies ------------------------------------------------------
-use ::audio::{Mixer, MixerCommand, MixerEvent, Recorder};
+use ::audio::{
+    ClipBuffer, Ducker, Mixer, MixerCommand, MixerEvent,
+    Recorder, RecorderCommand, RecorderEvent, RecordingSession
+};
+use ::db::UserFlag;
+use ::effect::Effect;
 use ::core::EventQueue;
-use super::{Server, ServerRecordingStatus, ServerVoiceStatus};
+use super::{PlaybackSource, Server, ServerRecordingStatus, ServerVoiceStatus};
 
 
 // Server Voice Interface -----------------------------------------------------
@@ -62,14 +69,23 @@ impl Server {
         }
 
         // Setup voice connection and mixer
+        let settings = self.get_mixer_settings();
         let (c_sender, c_receiver) = mpsc::channel::<MixerCommand>();
         let (e_sender, e_receiver) = mpsc::channel::<MixerEvent>();
-        queue.connect_server_voice(self.id, *channel_id, move |conn| {
-            conn.clear_receiver();
-            conn.play(Box::new(Mixer::new(c_receiver, e_sender)));
-        });
+        queue.connect_server_voice(
+            self.id,
+            *channel_id,
+            Box::new(Mixer::new(c_receiver, e_sender, settings))
+        );
 
         self.mixer_commands = Some(c_sender);
+
+        // Keep a fresh buffer of recent voice audio for clips
+        self.clip_buffer = self.config.clip_buffer_ms.map(ClipBuffer::new);
+
+        let receiver = self.voice_receiver(None);
+        queue.set_server_voice_receiver(self.id, receiver);
+
         self.mixer_events = Some(e_receiver);
         self.voice_status = ServerVoiceStatus::Pending;
 
@@ -126,10 +142,38 @@ impl Server {
         if self.voice_status != ServerVoiceStatus::Left {
             if self.recording_status == ServerRecordingStatus::Stopped {
 
-                let path = self.config.recordings_path.clone();
-                queue.with_server_voice(self.id, move |conn| {
-                    conn.set_receiver(Box::new(Recorder::new(path, 1000)));
-                });
+                let session = RecordingSession {
+                    channel_id: *channel_id,
+                    channel_name: self.channel_name(channel_id).unwrap_or_default(),
+                    nicknames: self.members.values().map(|member| {
+                        (member.id, member.nickname.to_string())
+
+                    }).collect(),
+                    opted_out: self.members.values().filter(|member| {
+                        member.is_recording_opt_out
+
+                    }).map(|member| member.id).collect()
+                };
+
+                let (c_sender, c_receiver) = mpsc::channel::<RecorderCommand>();
+                let (e_sender, e_receiver) = mpsc::channel::<RecorderEvent>();
+                let recorder = Recorder::new(
+                    self.config.recordings_path.clone(),
+                    1000,
+                    self.config.recording_jitter_buffer_ms,
+                    self.config.recording_encoder,
+                    session,
+                    c_receiver,
+                    e_sender
+                );
+
+                let name = recorder.name().to_string();
+                let receiver = self.voice_receiver(Some(Box::new(recorder)));
+                queue.set_server_voice_receiver(self.id, receiver);
+
+                self.recorder_commands = Some(c_sender);
+                self.recorder_events = Some(e_receiver);
+                self.recording_session = Some(name);
 
                 self.recording_status = ServerRecordingStatus::Recording;
 
@@ -149,9 +193,10 @@ impl Server {
 
     pub fn stop_recording_voice(&mut self, queue: &mut EventQueue) {
         if self.recording_status == ServerRecordingStatus::Recording {
-            queue.with_server_voice(self.id, move |conn| {
-                conn.clear_receiver();
-            });
+            let receiver = self.voice_receiver(None);
+            queue.set_server_voice_receiver(self.id, receiver);
+            self.recorder_commands = None;
+            self.recording_session = None;
             self.recording_status = ServerRecordingStatus::Stopped;
             info!("{} Voice recording stopped", self);
         }
@@ -161,5 +206,129 @@ impl Server {
         self.recording_status == ServerRecordingStatus::Recording
     }
 
+    pub fn set_member_recording_opt_out(
+        &mut self,
+        member_id: &UserId,
+        opt_out: bool
+
+    ) -> Result<(), String> {
+
+        let nickname = match self.members.get(member_id) {
+            Some(member) => member.nickname.to_string(),
+            None => return Err("Member not found.".to_string())
+        };
+
+        try!(::db::set_user_flag(&self.config, &nickname, UserFlag::RecordingOptOut, opt_out));
+
+        if let Some(member) = self.members.get_mut(member_id) {
+            member.is_recording_opt_out = opt_out;
+        }
+
+        // Apply the change to an active recording right away
+        if let Some(queue) = self.recorder_commands.as_ref() {
+            queue.send(if opt_out {
+                RecorderCommand::OptOut(*member_id)
+
+            } else {
+                RecorderCommand::OptIn(*member_id)
+
+            }).ok();
+        }
+
+        Ok(())
+
+    }
+
+    pub fn clip_buffer_ms(&self) -> Option<u64> {
+        self.clip_buffer.as_ref().map(|buffer| buffer.duration_ms())
+    }
+
+    pub fn clip_effect(
+        &mut self,
+        effect_name: &str,
+        duration_ms: u64,
+        uploader: &str
+
+    ) -> Result<(), String> {
+
+        // Members who opted out of recordings are never part of a clip
+        let excluded: HashSet<UserId> = self.members.values().filter(|member| {
+            member.is_recording_opt_out
+
+        }).map(|member| member.id).collect();
+
+        let samples = match self.clip_buffer.as_ref() {
+            Some(buffer) => buffer.capture(duration_ms, &excluded),
+            None => return Err("Clips are not enabled on this server.".to_string())
+        };
+
+        if let Some(samples) = samples {
+            self.effects.create_effect(&self.config, effect_name, uploader, &samples)
+
+        } else {
+            Err("No voice audio was captured in the requested time frame.".to_string())
+        }
+
+    }
+
+    pub fn play_recording_cue(
+        &mut self,
+        channel_id: &ChannelId,
+        started: bool,
+        user_id: UserId,
+        queue: &mut EventQueue
+    ) {
+
+        let pattern = if started {
+            self.config.recording_start_effect.clone()
+
+        } else {
+            self.config.recording_stop_effect.clone()
+        };
+
+        if let Some(pattern) = pattern {
+            let effects: Vec<Effect> = self.map_effects(&[pattern], false).into_iter().cloned().collect();
+            self.play_effects(
+                channel_id,
+                &effects,
+                false,
+                0,
+                PlaybackSource::Recording(user_id),
+                queue,
+                None
+            );
+        }
+
+    }
+
 }
 
+
+// Internal Interface ---------------------------------------------------------
+impl Server {
+
+    fn voice_receiver(
+        &self,
+        receiver: Option<Box<AudioReceiver>>
+
+    ) -> Option<Box<AudioReceiver>> {
+
+        // Buffer recent audio for clips ahead of any other receiver
+        let receiver = if let Some(buffer) = self.clip_buffer.as_ref() {
+            Some(Box::new(buffer.receiver(receiver)) as Box<AudioReceiver>)
+
+        } else {
+            receiver
+        };
+
+        // Wrap any other receiver when ducking is enabled for the server
+        if let (Some(level_db), Some(mixer_commands)) = (self.config.ducking_db, self.mixer_commands.as_ref()) {
+            Some(Box::new(Ducker::new(level_db, mixer_commands.clone(), receiver)))
+
+        } else {
+            receiver
+        }
+
+    }
+
+}
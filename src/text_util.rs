@@ -57,3 +57,130 @@ pub fn list_lines(
 
 }
 
+pub fn parse_duration(text: &str) -> Option<u64> {
+
+    let mut total: u64 = 0;
+    let mut value: Option<u64> = None;
+
+    for c in text.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            value = match value.unwrap_or(0).checked_mul(10).and_then(|value| {
+                value.checked_add(u64::from(digit))
+            }) {
+                Some(value) => Some(value),
+                None => return None
+            };
+
+        } else {
+            let unit = match c {
+                's' => 1000,
+                'm' => 60 * 1000,
+                'h' => 60 * 60 * 1000,
+                'd' => 24 * 60 * 60 * 1000,
+                _ => return None
+            };
+            match value.take().and_then(|value| value.checked_mul(unit)).and_then(|value| {
+                total.checked_add(value)
+            }) {
+                Some(value) => total = value,
+                None => return None
+            }
+        }
+    }
+
+    // Durations must end with a unit and cannot be empty
+    if value.is_some() || total == 0 {
+        None
+
+    } else {
+        Some(total)
+    }
+
+}
+
+pub fn format_duration(millis: u64) -> String {
+
+    let seconds = millis / 1000;
+    let parts: Vec<String> = vec![
+        (seconds / 86400, "d"),
+        ((seconds % 86400) / 3600, "h"),
+        ((seconds % 3600) / 60, "m"),
+        (seconds % 60, "s")
+
+    ].into_iter().filter(|&(value, _)| value > 0).map(|(value, unit)| {
+        format!("{}{}", value, unit)
+
+    }).collect();
+
+    if parts.is_empty() {
+        "0s".to_string()
+
+    } else {
+        parts.join("")
+    }
+
+}
+
+pub fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 * 1024 {
+        format!("{:.1} GiB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+
+    } else if bytes >= 1024 * 1024 {
+        format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+
+    } else if bytes >= 1024 {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+pub fn split_arguments(text: &str) -> Vec<String> {
+
+    let mut arguments: Vec<String> = Vec::new();
+    let mut quoted: Option<String> = None;
+
+    for part in text.split(' ').filter(|s| !s.is_empty()) {
+
+        // Keep quoted phrases together as a single argument
+        if let Some(mut phrase) = quoted.take() {
+            phrase.push(' ');
+            phrase.push_str(part);
+            if part.ends_with('"') {
+                arguments.push(phrase);
+
+            } else {
+                quoted = Some(phrase);
+            }
+
+        } else if part.starts_with('"') && (part.len() == 1 || !part.ends_with('"')) {
+            quoted = Some(part.to_string());
+
+        } else {
+            arguments.push(part.to_string());
+        }
+
+    }
+
+    // Unterminated quotes are passed on as is
+    if let Some(phrase) = quoted {
+        arguments.extend(phrase.split(' ').map(|s| s.to_string()));
+    }
+
+    arguments
+
+}
+
+pub fn normalize_words(text: &str) -> Vec<String> {
+    text.to_lowercase().split(|c: char| {
+        !(c.is_alphanumeric() || c == '\'')
+
+    }).map(|word| {
+        word.trim_matches('\'')
+
+    }).filter(|word| !word.is_empty()).map(|word| {
+        word.to_string()
+
+    }).collect()
+}
@@ -13,19 +13,25 @@ use discord::model::Attachment;
 // External Dependencies ------------------------------------------------------
 use hyper::Client;
 use hyper::header::{Connection, Range, ByteRangeSpec, ContentLength};
-use flac::{ByteStream, Stream};
 
 
 // Internal Dependencies ------------------------------------------------------
-use ::bot::BotConfig;
+use ::server::ServerConfig;
+use ::audio::{AudioFormat, probe_audio};
 use ::core::{Member, Message};
 use ::action::{ActionGroup, ServerActions, MessageActions};
 
 
+// Statics --------------------------------------------------------------------
+static SUPPORTED_SAMPLE_RATES: [u32; 8] = [8000, 11025, 16000, 22050, 24000, 32000, 44100, 48000];
+static SUPPORTED_BITS_PER_SAMPLE: [u8; 3] = [8, 16, 24];
+
+
 // Upload File Information ----------------------------------------------------
 #[derive(Debug)]
 enum FileInfo {
-    Flac {
+    Audio {
+        format: AudioFormat,
         file_size: u64,
         sample_rate: u32,
         bits_per_sample: u8
@@ -62,8 +68,8 @@ impl Upload {
         Upload {
             name: name.to_string(),
             url: attachment.url.to_string(),
-            info: if name.is_ascii() && name.len() >= 2 && ext == "flac" {
-                match retrieve_flac_info(attachment.url.as_str()) {
+            info: if name.is_ascii() && name.len() >= 2 && AudioFormat::from_extension(&ext).is_some() {
+                match retrieve_audio_info(attachment.url.as_str()) {
                     Ok(info) => Some(info),
                     Err(err) => Some(FileInfo::Error(err))
                 }
@@ -82,7 +88,7 @@ impl Upload {
     pub fn process(
         self,
         member: &Member,
-        config: &BotConfig
+        config: &ServerConfig
 
     ) -> ActionGroup {
 
@@ -95,13 +101,14 @@ impl Upload {
         } else if !self.message.has_unique_server() {
             MessageActions::Send::private(
                 &self.message,
-                "FLAC uploads require a unique server as their target.
+                "Sound effect uploads require a unique server as their target.
                 Since you are a member of at least two bot-enabled servers,
                 the command cannot be invoked from a private channel.
                 Please re-issue the command from a public channels of the target server.".to_string()
             )
 
-        } else if let Some(FileInfo::Flac {
+        } else if let Some(FileInfo::Audio {
+            format,
             file_size,
             sample_rate,
             bits_per_sample
@@ -110,32 +117,47 @@ impl Upload {
             if file_size > config.flac_max_file_size {
                 MessageActions::Send::private(
                     &self.message,
-                    "Uploaded FLAC file exceeds 2 MiB.".to_string()
+                    format!(
+                        "Uploaded {} file exceeds {} KiB.",
+                        format,
+                        config.flac_max_file_size / 1024
+                    )
                 )
 
-            } else if sample_rate != config.flac_sample_rate {
+            } else if !SUPPORTED_SAMPLE_RATES.contains(&sample_rate)
+                   || sample_rate > config.flac_sample_rate {
                 MessageActions::Send::private(
                     &self.message,
-                    "Uploaded FLAC file does not have a valid sample rate of 48000hz.".to_string()
+                    format!(
+                        "Uploaded {} file does not have a supported sample rate of at most {}hz.",
+                        format,
+                        config.flac_sample_rate
+                    )
                 )
 
-            } else if bits_per_sample != config.flac_bits_per_sample {
+            } else if !SUPPORTED_BITS_PER_SAMPLE.contains(&bits_per_sample)
+                   || bits_per_sample > config.flac_bits_per_sample {
                 MessageActions::Send::private(
                     &self.message,
-                    "Uploaded FLAC file does not feature 16 bits per sample.".to_string()
+                    format!(
+                        "Uploaded {} file does not feature 8, 16 or 24 bits per sample, up to a maximum of {}.",
+                        format,
+                        config.flac_bits_per_sample
+                    )
                 )
 
             } else {
                 vec![
                     MessageActions::Send::single_public(
                         &self.message,
-                        "FLAC download to server started...".to_string()
+                        format!("{} download to server started...", format)
                     ),
-                    ServerActions::DownloadFlac::new(
+                    ServerActions::DownloadEffect::new(
                         self.message,
                         self.name,
                         self.url,
-                        member.nickname.clone()
+                        member.nickname.clone(),
+                        format
                     )
                 ]
             }
@@ -173,10 +195,10 @@ impl Upload {
 // Traits  --------------------------------------------------------------------
 impl fmt::Display for Upload {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if let Some(FileInfo::Flac { .. }) = self.info {
+        if let Some(FileInfo::Audio { format, .. }) = self.info {
             write!(
-                f, "[FLAC upload \"{}\" from user #{} on server #{}]",
-                self.name, self.message.user_id, self.message.server_id
+                f, "[{} upload \"{}\" from user #{} on server #{}]",
+                format, self.name, self.message.user_id, self.message.server_id
             )
 
         } else if let Some(FileInfo::Text) = self.info {
@@ -204,7 +226,7 @@ fn os_str_to_string(os_str: Option<&OsStr>) -> String {
 }
 
 
-fn retrieve_flac_info(url: &str) -> Result<FileInfo, String> {
+fn retrieve_audio_info(url: &str) -> Result<FileInfo, String> {
 
     let client = Client::new();
     client.get(url)
@@ -220,17 +242,16 @@ fn retrieve_flac_info(url: &str) -> Result<FileInfo, String> {
                 .map(|_| (length, header))
         })
         .and_then(|(length, header)| {
-            println!("[Upload] [FlacHeader] {:?}", header);
-            Stream::<ByteStream>::from_buffer(&header[..])
-                .map_err(|err| format!("Failed to parse FLAC header: {:?}", err))
-                .map(|stream| {
-                    let stream_info = stream.info();
-                    FileInfo::Flac {
-                        file_size: length,
-                        sample_rate: stream_info.sample_rate,
-                        bits_per_sample: stream_info.bits_per_sample
-                    }
-                })
+            // The actual format is detected from the header, independent of the
+            // extension of the uploaded file
+            probe_audio(&header[..]).map(|info| {
+                FileInfo::Audio {
+                    format: info.format,
+                    file_size: length,
+                    sample_rate: info.sample_rate,
+                    bits_per_sample: info.bits_per_sample
+                }
+            })
         })
 
 }